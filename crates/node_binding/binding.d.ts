@@ -45,16 +45,18 @@ export class JsCompilation {
   addMissingDependencies(deps: Array<string>): void
   addBuildDependencies(deps: Array<string>): void
   rebuildModule(moduleIdentifiers: Array<string>, f: (...args: any[]) => any): void
+  importModule(request: string, context: string, importer: string, callback: (err: null | Error, result: JsImportModuleResult) => void): void
 }
 
 export class JsStats {
   getAssets(): JsStatsGetAssets
-  getModules(reasons: boolean, moduleAssets: boolean, nestedModules: boolean, source: boolean): Array<JsStatsModule>
-  getChunks(chunkModules: boolean, chunksRelations: boolean, reasons: boolean, moduleAssets: boolean, nestedModules: boolean, source: boolean): Array<JsStatsChunk>
+  getModules(options: JsStatsOptions): Array<JsStatsModule>
+  getChunks(options: JsStatsOptions): Array<JsStatsChunk>
   getEntrypoints(): Array<JsStatsChunkGroup>
   getNamedChunkGroups(): Array<JsStatsChunkGroup>
-  getErrors(): Array<JsStatsError>
-  getWarnings(): Array<JsStatsWarning>
+  getShared(): Array<JsStatsShared>
+  getErrors(options?: JsStatsOptions | undefined | null): Array<JsStatsError>
+  getWarnings(options?: JsStatsOptions | undefined | null): Array<JsStatsWarning>
   getLogging(acceptedTypes: number): Array<JsStatsLogging>
   getHash(): string | null
 }
@@ -180,6 +182,7 @@ export const enum BuiltinPluginName {
   InferAsyncModulesPlugin = 'InferAsyncModulesPlugin',
   JavascriptModulesPlugin = 'JavascriptModulesPlugin',
   AsyncWebAssemblyModulesPlugin = 'AsyncWebAssemblyModulesPlugin',
+  WebAssemblyModulesPlugin = 'WebAssemblyModulesPlugin',
   AssetModulesPlugin = 'AssetModulesPlugin',
   SourceMapDevToolPlugin = 'SourceMapDevToolPlugin',
   EvalSourceMapDevToolPlugin = 'EvalSourceMapDevToolPlugin',
@@ -187,11 +190,14 @@ export const enum BuiltinPluginName {
   FlagDependencyExportsPlugin = 'FlagDependencyExportsPlugin',
   FlagDependencyUsagePlugin = 'FlagDependencyUsagePlugin',
   MangleExportsPlugin = 'MangleExportsPlugin',
+  ModuleConcatenationPlugin = 'ModuleConcatenationPlugin',
   HttpExternalsRspackPlugin = 'HttpExternalsRspackPlugin',
   CopyRspackPlugin = 'CopyRspackPlugin',
   HtmlRspackPlugin = 'HtmlRspackPlugin',
   SwcJsMinimizerRspackPlugin = 'SwcJsMinimizerRspackPlugin',
-  SwcCssMinimizerRspackPlugin = 'SwcCssMinimizerRspackPlugin'
+  SwcCssMinimizerRspackPlugin = 'SwcCssMinimizerRspackPlugin',
+  CustomChunkLoadingPlugin = 'CustomChunkLoadingPlugin',
+  CustomWasmLoadingPlugin = 'CustomWasmLoadingPlugin'
 }
 
 export function cleanupGlobalTrace(): void
@@ -350,6 +356,14 @@ export interface JsHooks {
   executeModule: (...args: any[]) => any
 }
 
+export interface JsImportModuleResult {
+  id?: string
+  fileDependencies: Array<string>
+  contextDependencies: Array<string>
+  missingDependencies: Array<string>
+  buildDependencies: Array<string>
+}
+
 export interface JsLoaderContext {
   /** Content maybe empty in pitching stage */
   content?: Buffer
@@ -367,6 +381,8 @@ export interface JsLoaderContext {
   assetFilenames: Array<string>
   currentLoader: string
   isPitching: boolean
+  /** The identifier of the module being built */
+  moduleIdentifier: string
   /**
    * Loader index from JS.
    * If loaders are dispatched by JS loader runner,
@@ -400,6 +416,14 @@ export interface JsModule {
   nameForCondition?: string
 }
 
+export interface JsOriginRecord {
+  module: string
+  moduleIdentifier: string
+  moduleName: string
+  moduleId: string
+  request: string
+}
+
 export interface JsResolveForSchemeInput {
   resourceData: JsResourceData
   scheme: string
@@ -455,6 +479,7 @@ export interface JsStatsChunk {
   parents?: Array<string>
   children?: Array<string>
   siblings?: Array<string>
+  origins?: Array<JsOriginRecord>
 }
 
 export interface JsStatsChunkGroup {
@@ -475,6 +500,7 @@ export interface JsStatsError {
   moduleIdentifier?: string
   moduleName?: string
   moduleId?: string
+  moduleTrace: Array<JsStatsModuleTrace>
 }
 
 export interface JsStatsGetAssets {
@@ -512,6 +538,11 @@ export interface JsStatsModule {
   source?: string | Buffer
   profile?: JsStatsModuleProfile
   orphan: boolean
+  modules?: Array<JsStatsModule>
+  optimizationBailout: Array<string>
+  depth?: number
+  usedExports?: boolean | Array<string>
+  providedExports?: Array<string>
 }
 
 export interface JsStatsModuleIssuer {
@@ -534,12 +565,47 @@ export interface JsStatsModuleReason {
   userRequest?: string
 }
 
+export interface JsStatsModuleTrace {
+  origin: JsStatsModuleIssuer
+  module: JsStatsModuleIssuer
+}
+
+export interface JsStatsOptions {
+  modules: boolean
+  reasons: boolean
+  moduleAssets: boolean
+  nestedModules: boolean
+  source: boolean
+  chunkModules: boolean
+  chunkRelations: boolean
+  chunkOrigins: boolean
+  ids: boolean
+  usedExports: boolean
+  providedExports: boolean
+  optimizationBailout: boolean
+  depth: boolean
+  moduleTrace: boolean
+}
+
+export interface JsStatsShared {
+  shareScope: string
+  shareKey: string
+  versions: Array<JsStatsSharedVersion>
+}
+
+export interface JsStatsSharedVersion {
+  version?: string
+  request: string
+  chunks: Array<string>
+}
+
 export interface JsStatsWarning {
   message: string
   formatted: string
   moduleIdentifier?: string
   moduleName?: string
   moduleId?: string
+  moduleTrace: Array<JsStatsModuleTrace>
 }
 
 export interface NodeFS {
@@ -733,11 +799,30 @@ export interface RawCssModulesConfig {
 
 export interface RawCssPluginConfig {
   modules: RawCssModulesConfig
+  targets: Array<string>
+}
+
+export interface RawCustomChunkLoadingPluginOptions {
+  type: string
+  /** Names of the runtime globals used by `source`, e.g. `__webpack_require__.p` */
+  runtimeRequirements: Array<string>
+  /** Source of the runtime module, which should add a handler to `__webpack_require__.f` */
+  source: string
+}
+
+export interface RawCustomWasmLoadingPluginOptions {
+  type: string
+  /** Names of the runtime globals used by `generateLoadBinaryCode` */
+  runtimeRequirements: Array<string>
+  /** Expression which loads the binary of `$PATH` as a `Promise` of a `Response` like object */
+  generateLoadBinaryCode: string
+  supportsStreaming: boolean
 }
 
 export interface RawEntryOptions {
   name?: string
   runtime?: string
+  dependOn?: Array<string>
   chunkLoading?: string
   asyncChunks?: boolean
   publicPath?: string
@@ -823,7 +908,7 @@ export interface RawHtmlRspackPluginOptions {
   /** template html file */
   template?: string
   templateContent?: string
-  templateParameters?: Record<string, string>
+  templateParameters?: Record<string, any>
   /** "head", "body" or "false" */
   inject: "head" | "body" | "false"
   /** path or `auto` */
@@ -838,6 +923,13 @@ export interface RawHtmlRspackPluginOptions {
   title?: string
   favicon?: string
   meta?: Record<string, Record<string, string>>
+  preload?: RawHtmlResourceHintsOptions
+  prefetch?: RawHtmlResourceHintsOptions
+}
+
+export interface RawHtmlResourceHintsOptions {
+  include?: Array<string>
+  exclude?: Array<string>
 }
 
 export interface RawHttpExternalsRspackPluginOptions {
@@ -971,6 +1063,7 @@ export interface RawOptimizationOptions {
   providedExports: boolean
   innerGraph: boolean
   mangleExports: string
+  concatenateModules?: boolean
 }
 
 export interface RawOptions {
@@ -990,6 +1083,8 @@ export interface RawOptions {
   node?: RawNodeOption
   profile: boolean
   bail: boolean
+  recordsInputPath?: string
+  recordsOutputPath?: string
   builtins: RawBuiltins
 }
 
@@ -1112,6 +1207,11 @@ export interface RawResolveOptions {
   fullySpecified?: boolean
   exportsFields?: Array<string>
   extensionAlias?: Record<string, Array<string>>
+  importsFields?: Array<string>
+  aliasFields?: Array<string>
+  restrictions?: Array<string>
+  roots?: Array<string>
+  descriptionFiles?: Array<string>
 }
 
 export interface RawResolveTsconfigOptions {
@@ -1142,6 +1242,16 @@ export interface RawRuleSetLogicalConditions {
 export interface RawSnapshotOptions {
   resolve: RawSnapshotStrategy
   module: RawSnapshotStrategy
+  buildDependencies?: RawSnapshotStrategy
+  resolveBuildDependencies?: RawSnapshotStrategy
+  immutablePaths?: Array<RawSnapshotPath>
+  managedPaths?: Array<RawSnapshotPath>
+}
+
+export interface RawSnapshotPath {
+  type: "string" | "regexp"
+  stringMatcher?: string
+  regexpMatcher?: RawRegexMatcher
 }
 
 export interface RawSnapshotStrategy {
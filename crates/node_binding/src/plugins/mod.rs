@@ -823,7 +823,7 @@ impl rspack_core::Plugin for JsHooksAdapter {
       .unwrap_or_else(|err| panic!("Failed to call still_valid_module hook: {err}"))
   }
 
-  fn execute_module(
+  async fn execute_module(
     &self,
     entry: ModuleIdentifier,
     runtime_modules: Vec<ModuleIdentifier>,
@@ -847,8 +847,8 @@ impl rspack_core::Plugin for JsHooksAdapter {
         ThreadsafeFunctionCallMode::NonBlocking,
       )
       .into_rspack_result()?
-      .blocking_recv()
-      .unwrap_or_else(|recv_err| panic!("{}", recv_err.to_string()))
+      .await
+      .unwrap_or_else(|err| panic!("Failed to call execute_module hook: {err}"))
   }
 }
 
@@ -0,0 +1,7 @@
+const local = "a";
+
+export const a = local;
+
+export function b() {
+	return local;
+}
@@ -0,0 +1,3 @@
+const local = "b";
+
+export default local + "!";
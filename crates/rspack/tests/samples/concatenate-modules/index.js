@@ -0,0 +1,8 @@
+import { a, b as renamed } from "./a";
+import value from "./b";
+import * as ns from "./c";
+import cjs from "./cjs";
+
+const local = "index";
+
+console.log(a, renamed(), value, ns.c, cjs, local);
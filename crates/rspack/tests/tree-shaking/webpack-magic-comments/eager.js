@@ -0,0 +1,2 @@
+export const c = 3;
+export const d = 4;
@@ -0,0 +1,9 @@
+import(/* webpackExports: ["a", "b"] */ "./lib");
+import(/* webpackMode: "eager", webpackExports: "c" */ "./eager");
+import(/* webpackFetchPriority: "high" */ "./high");
+
+const locale = window.locale;
+import(
+	/* webpackInclude: /\.js$/, webpackExclude: /excluded/, webpackMode: "lazy-once" */
+	`./locale/${locale}`
+);
@@ -0,0 +1,64 @@
+use std::ops::ControlFlow;
+use std::time::Duration;
+
+use rspack_core::{Compiler, WatchEvent, WatchOptions};
+use rspack_fs::AsyncNativeFileSystem;
+use rspack_testing::apply_from_fixture;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn rebuild_on_change() {
+  let context = std::env::temp_dir().join(format!("rspack-watch-{}", std::process::id()));
+  std::fs::create_dir_all(&context).expect("should create the context");
+  // the paths reported by the watcher are resolved
+  let context = context.canonicalize().expect("should resolve the context");
+  let index = context.join("index.js");
+  std::fs::write(&index, "console.log('v1');").expect("should write index.js");
+
+  let (options, plugins) = apply_from_fixture(&context);
+  let mut compiler = Compiler::new(options, plugins, AsyncNativeFileSystem);
+
+  let mut outputs = vec![];
+  let mut changed = vec![];
+  let watching = compiler.watch(WatchOptions::default(), |event| match event {
+    WatchEvent::Done(compilation) => {
+      let output = compilation
+        .assets()
+        .get("main.js")
+        .and_then(|asset| asset.get_source())
+        .map(|source| source.source().to_string())
+        .unwrap_or_default();
+      outputs.push(output);
+      match outputs.len() {
+        // the watcher is created after the first build
+        1 => {
+          let index = index.clone();
+          std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(500));
+            std::fs::write(index, "console.log('v2');").expect("should write index.js");
+          });
+          ControlFlow::Continue(())
+        }
+        // the change right after a rebuild is not lost
+        2 => {
+          std::fs::write(&index, "console.log('v3');").expect("should write index.js");
+          ControlFlow::Continue(())
+        }
+        _ => ControlFlow::Break(()),
+      }
+    }
+    WatchEvent::Invalid { changed_files, .. } => {
+      changed.push(changed_files.clone());
+      ControlFlow::Continue(())
+    }
+  });
+  let result = tokio::time::timeout(Duration::from_secs(30), watching).await;
+  std::fs::remove_dir_all(&context).expect("should remove the context");
+
+  result
+    .expect("should rebuild on changes")
+    .expect("should watch");
+  assert!(outputs[0].contains("v1"));
+  assert!(outputs[1].contains("v2"));
+  assert!(outputs[2].contains("v3"));
+  assert!(changed.iter().all(|files| files.contains(&index)));
+}
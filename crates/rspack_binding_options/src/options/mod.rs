@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use napi_derive::napi;
 use rspack_core::{
   CompilerOptions, Context, Devtool, Experiments, IncrementalRebuild, IncrementalRebuildMakeState,
@@ -63,6 +65,8 @@ pub struct RawOptions {
   pub node: Option<RawNodeOption>,
   pub profile: bool,
   pub bail: bool,
+  pub records_input_path: Option<String>,
+  pub records_output_path: Option<String>,
   pub builtins: RawBuiltins,
 }
 
@@ -94,7 +98,7 @@ impl RawOptions {
         self.optimization.try_into()
       })?;
     let stats = self.stats.into();
-    let snapshot = self.snapshot.into();
+    let snapshot = self.snapshot.try_into()?;
     let node = self.node.map(|n| n.into());
 
     let mut builtins = self.builtins.apply(plugins)?;
@@ -120,6 +124,8 @@ impl RawOptions {
       dev_server: Default::default(),
       profile: self.profile,
       bail: self.bail,
+      records_input_path: self.records_input_path.map(PathBuf::from),
+      records_output_path: self.records_output_path.map(PathBuf::from),
       builtins,
     })
   }
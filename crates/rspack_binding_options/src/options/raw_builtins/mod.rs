@@ -1,5 +1,6 @@
 mod raw_banner;
 mod raw_copy;
+mod raw_custom_loading;
 mod raw_html;
 mod raw_limit_chunk_count;
 mod raw_mf;
@@ -12,8 +13,11 @@ use napi::{
   JsUnknown,
 };
 use napi_derive::napi;
-use rspack_core::{BoxPlugin, Define, DefinePlugin, PluginExt, Provide, ProvidePlugin};
-use rspack_error::Result;
+use rspack_core::{
+  BoxPlugin, ChunkLoadingType, Define, DefinePlugin, PluginExt, Provide, ProvidePlugin,
+  WasmLoadingType,
+};
+use rspack_error::{error, Result};
 use rspack_ids::{
   DeterministicChunkIdsPlugin, DeterministicModuleIdsPlugin, NamedChunkIdsPlugin,
   NamedModuleIdsPlugin,
@@ -32,7 +36,7 @@ use rspack_plugin_hmr::HotModuleReplacementPlugin;
 use rspack_plugin_html::HtmlRspackPlugin;
 use rspack_plugin_javascript::{
   FlagDependencyExportsPlugin, FlagDependencyUsagePlugin, InferAsyncModulesPlugin, JsPlugin,
-  MangleExportsPlugin, SideEffectsFlagPlugin,
+  MangleExportsPlugin, ModuleConcatenationPlugin, SideEffectsFlagPlugin,
 };
 use rspack_plugin_json::JsonPlugin;
 use rspack_plugin_library::enable_library_plugin;
@@ -47,16 +51,21 @@ use rspack_plugin_real_content_hash::RealContentHashPlugin;
 use rspack_plugin_remove_empty_chunks::RemoveEmptyChunksPlugin;
 use rspack_plugin_runtime::{
   enable_chunk_loading_plugin, ArrayPushCallbackChunkFormatPlugin, ChunkPrefetchPreloadPlugin,
-  CommonJsChunkFormatPlugin, ModuleChunkFormatPlugin, RuntimePlugin,
+  CommonJsChunkFormatPlugin, CustomChunkLoadingPlugin, ModuleChunkFormatPlugin, RuntimePlugin,
 };
 use rspack_plugin_schemes::{DataUriPlugin, FileUriPlugin};
 use rspack_plugin_swc_css_minimizer::SwcCssMinimizerRspackPlugin;
 use rspack_plugin_swc_js_minimizer::SwcJsMinimizerRspackPlugin;
 use rspack_plugin_warn_sensitive_module::WarnCaseSensitiveModulesPlugin;
-use rspack_plugin_wasm::{enable_wasm_loading_plugin, AsyncWasmPlugin};
+use rspack_plugin_wasm::{
+  enable_wasm_loading_plugin, AsyncWasmPlugin, CustomWasmLoadingPlugin, SyncWasmPlugin,
+};
 use rspack_plugin_web_worker_template::web_worker_template_plugin;
 use rspack_plugin_worker::WorkerPlugin;
 
+pub use self::raw_custom_loading::{
+  RawCustomChunkLoadingPluginOptions, RawCustomWasmLoadingPluginOptions,
+};
 use self::raw_mf::{
   RawConsumeSharedPluginOptions, RawContainerReferencePluginOptions, RawProvideOptions,
 };
@@ -116,6 +125,7 @@ pub enum BuiltinPluginName {
   InferAsyncModulesPlugin,
   JavascriptModulesPlugin,
   AsyncWebAssemblyModulesPlugin,
+  WebAssemblyModulesPlugin,
   AssetModulesPlugin,
   SourceMapDevToolPlugin,
   EvalSourceMapDevToolPlugin,
@@ -123,6 +133,7 @@ pub enum BuiltinPluginName {
   FlagDependencyExportsPlugin,
   FlagDependencyUsagePlugin,
   MangleExportsPlugin,
+  ModuleConcatenationPlugin,
 
   // rspack specific plugins
   HttpExternalsRspackPlugin,
@@ -130,6 +141,8 @@ pub enum BuiltinPluginName {
   HtmlRspackPlugin,
   SwcJsMinimizerRspackPlugin,
   SwcCssMinimizerRspackPlugin,
+  CustomChunkLoadingPlugin,
+  CustomWasmLoadingPlugin,
 }
 
 #[napi(object)]
@@ -188,7 +201,13 @@ impl BuiltinPlugin {
       }
       BuiltinPluginName::EnableChunkLoadingPlugin => {
         let chunk_loading_type = downcast_into::<String>(self.options)?;
-        enable_chunk_loading_plugin(chunk_loading_type.as_str().into(), plugins);
+        let chunk_loading_type = ChunkLoadingType::from(chunk_loading_type.as_str());
+        if let ChunkLoadingType::Custom(custom) = &chunk_loading_type {
+          return Err(error!(
+            "Unsupported chunk loading type {custom}. Plugins which provide custom chunk loading types must be registered by CustomChunkLoadingPlugin."
+          ));
+        }
+        enable_chunk_loading_plugin(chunk_loading_type, plugins);
       }
       BuiltinPluginName::EnableLibraryPlugin => {
         let library_type = downcast_into::<String>(self.options)?;
@@ -196,9 +215,13 @@ impl BuiltinPlugin {
       }
       BuiltinPluginName::EnableWasmLoadingPlugin => {
         let wasm_loading_type = downcast_into::<String>(self.options)?;
-        plugins.push(enable_wasm_loading_plugin(
-          wasm_loading_type.as_str().into(),
-        ));
+        let wasm_loading_type = WasmLoadingType::from(wasm_loading_type.as_str());
+        if let WasmLoadingType::Custom(custom) = &wasm_loading_type {
+          return Err(error!(
+            "Unsupported wasm loading type {custom}. Plugins which provide custom wasm loading types must be registered by CustomWasmLoadingPlugin."
+          ));
+        }
+        enable_wasm_loading_plugin(wasm_loading_type, plugins);
       }
       BuiltinPluginName::ChunkPrefetchPreloadPlugin => {
         plugins.push(ChunkPrefetchPreloadPlugin.boxed());
@@ -296,6 +319,7 @@ impl BuiltinPlugin {
       BuiltinPluginName::AsyncWebAssemblyModulesPlugin => {
         plugins.push(AsyncWasmPlugin::new().boxed())
       }
+      BuiltinPluginName::WebAssemblyModulesPlugin => plugins.push(SyncWasmPlugin::new().boxed()),
       BuiltinPluginName::AssetModulesPlugin => plugins.push(AssetPlugin.boxed()),
       BuiltinPluginName::SourceMapDevToolPlugin => plugins.push(
         SourceMapDevToolPlugin::new(
@@ -321,6 +345,9 @@ impl BuiltinPlugin {
       BuiltinPluginName::MangleExportsPlugin => {
         plugins.push(MangleExportsPlugin::new(downcast_into::<bool>(self.options)?).boxed())
       }
+      BuiltinPluginName::ModuleConcatenationPlugin => {
+        plugins.push(ModuleConcatenationPlugin.boxed())
+      }
 
       // rspack specific plugins
       BuiltinPluginName::HttpExternalsRspackPlugin => {
@@ -347,11 +374,22 @@ impl BuiltinPlugin {
         plugins.push(plugin);
       }
       BuiltinPluginName::HtmlRspackPlugin => {
-        let plugin =
-          HtmlRspackPlugin::new(downcast_into::<RawHtmlRspackPluginOptions>(self.options)?.into())
-            .boxed();
+        let plugin = HtmlRspackPlugin::new(
+          downcast_into::<RawHtmlRspackPluginOptions>(self.options)?.try_into()?,
+        )
+        .boxed();
         plugins.push(plugin);
       }
+      BuiltinPluginName::CustomChunkLoadingPlugin => {
+        let plugin: CustomChunkLoadingPlugin =
+          downcast_into::<RawCustomChunkLoadingPluginOptions>(self.options)?.try_into()?;
+        plugins.push(plugin.boxed());
+      }
+      BuiltinPluginName::CustomWasmLoadingPlugin => {
+        let plugin: CustomWasmLoadingPlugin =
+          downcast_into::<RawCustomWasmLoadingPluginOptions>(self.options)?.try_into()?;
+        plugins.push(plugin.boxed());
+      }
     }
     Ok(())
   }
@@ -0,0 +1,66 @@
+use napi_derive::napi;
+use rspack_core::{RuntimeGlobals, RuntimeModuleExt};
+use rspack_error::{error, Error, Result};
+use rspack_plugin_runtime::{CustomChunkLoadingPlugin, CustomChunkLoadingRuntimeModule};
+use rspack_plugin_wasm::CustomWasmLoadingPlugin;
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawCustomChunkLoadingPluginOptions {
+  pub r#type: String,
+  /// Names of the runtime globals used by `source`, e.g. `__webpack_require__.p`
+  pub runtime_requirements: Vec<String>,
+  /// Source of the runtime module, which should add a handler to `__webpack_require__.f`
+  pub source: String,
+}
+
+impl TryFrom<RawCustomChunkLoadingPluginOptions> for CustomChunkLoadingPlugin {
+  type Error = Error;
+
+  fn try_from(value: RawCustomChunkLoadingPluginOptions) -> Result<Self> {
+    let runtime_requirements = to_runtime_globals(&value.runtime_requirements)?;
+    let chunk_loading_type = value.r#type.clone();
+    let source = value.source;
+    Ok(Self::new(
+      value.r#type,
+      runtime_requirements,
+      Box::new(move || {
+        CustomChunkLoadingRuntimeModule::new(&chunk_loading_type, source.clone()).boxed()
+      }),
+    ))
+  }
+}
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawCustomWasmLoadingPluginOptions {
+  pub r#type: String,
+  /// Names of the runtime globals used by `generateLoadBinaryCode`
+  pub runtime_requirements: Vec<String>,
+  /// Expression which loads the binary of `$PATH` as a `Promise` of a `Response` like object
+  pub generate_load_binary_code: String,
+  pub supports_streaming: bool,
+}
+
+impl TryFrom<RawCustomWasmLoadingPluginOptions> for CustomWasmLoadingPlugin {
+  type Error = Error;
+
+  fn try_from(value: RawCustomWasmLoadingPluginOptions) -> Result<Self> {
+    Ok(Self::new(
+      value.r#type,
+      value.generate_load_binary_code,
+      value.supports_streaming,
+      to_runtime_globals(&value.runtime_requirements)?,
+    ))
+  }
+}
+
+fn to_runtime_globals(names: &[String]) -> Result<RuntimeGlobals> {
+  names
+    .iter()
+    .try_fold(RuntimeGlobals::default(), |acc, name| {
+      RuntimeGlobals::from_name(name)
+        .map(|runtime_global| acc | runtime_global)
+        .ok_or_else(|| error!("Unknown runtime requirement \"{name}\""))
+    })
+}
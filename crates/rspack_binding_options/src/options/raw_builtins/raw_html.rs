@@ -2,7 +2,9 @@ use std::collections::HashMap;
 use std::str::FromStr;
 
 use napi_derive::napi;
+use rspack_error::error;
 use rspack_plugin_html::config::HtmlInject;
+use rspack_plugin_html::config::HtmlResourceHintsOptions;
 use rspack_plugin_html::config::HtmlRspackPluginOptions;
 use rspack_plugin_html::config::HtmlScriptLoading;
 use rspack_plugin_html::sri::HtmlSriHashFunction;
@@ -24,7 +26,8 @@ pub struct RawHtmlRspackPluginOptions {
   /// template html file
   pub template: Option<String>,
   pub template_content: Option<String>,
-  pub template_parameters: Option<HashMap<String, String>>,
+  #[napi(ts_type = "Record<string, any>")]
+  pub template_parameters: Option<HashMap<String, serde_json::Value>>,
   /// "head", "body" or "false"
   #[napi(ts_type = "\"head\" | \"body\" | \"false\"")]
   pub inject: RawHtmlInject,
@@ -43,20 +46,43 @@ pub struct RawHtmlRspackPluginOptions {
   pub title: Option<String>,
   pub favicon: Option<String>,
   pub meta: Option<HashMap<String, HashMap<String, String>>>,
+  pub preload: Option<RawHtmlResourceHintsOptions>,
+  pub prefetch: Option<RawHtmlResourceHintsOptions>,
 }
 
-impl From<RawHtmlRspackPluginOptions> for HtmlRspackPluginOptions {
-  fn from(value: RawHtmlRspackPluginOptions) -> Self {
-    let inject = HtmlInject::from_str(&value.inject).expect("Invalid inject value");
+#[derive(Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawHtmlResourceHintsOptions {
+  pub include: Option<Vec<String>>,
+  pub exclude: Option<Vec<String>>,
+}
+
+impl From<RawHtmlResourceHintsOptions> for HtmlResourceHintsOptions {
+  fn from(value: RawHtmlResourceHintsOptions) -> Self {
+    Self {
+      include: value.include,
+      exclude: value.exclude,
+    }
+  }
+}
+
+impl TryFrom<RawHtmlRspackPluginOptions> for HtmlRspackPluginOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawHtmlRspackPluginOptions) -> rspack_error::Result<Self> {
+    let inject = HtmlInject::from_str(&value.inject).map_err(|e| error!("{e}"))?;
 
     let script_loading =
-      HtmlScriptLoading::from_str(&value.script_loading).expect("Invalid script_loading value");
+      HtmlScriptLoading::from_str(&value.script_loading).map_err(|e| error!("{e}"))?;
 
-    let sri = value.sri.as_ref().map(|s| {
-      HtmlSriHashFunction::from_str(s).unwrap_or_else(|_| panic!("Invalid sri value: {s}"))
-    });
+    let sri = value
+      .sri
+      .as_ref()
+      .map(|s| HtmlSriHashFunction::from_str(s).map_err(|e| error!("{e}")))
+      .transpose()?;
 
-    HtmlRspackPluginOptions {
+    Ok(HtmlRspackPluginOptions {
       filename: value.filename.unwrap_or_else(|| String::from("index.html")),
       template: value.template,
       template_content: value.template_content,
@@ -71,6 +97,8 @@ impl From<RawHtmlRspackPluginOptions> for HtmlRspackPluginOptions {
       title: value.title,
       favicon: value.favicon,
       meta: value.meta,
-    }
+      preload: value.preload.map(Into::into),
+      prefetch: value.prefetch.map(Into::into),
+    })
   }
 }
@@ -19,6 +19,7 @@ pub struct RawEntryPluginOptions {
 pub struct RawEntryOptions {
   pub name: Option<String>,
   pub runtime: Option<String>,
+  pub depend_on: Option<Vec<String>>,
   pub chunk_loading: Option<String>,
   pub async_chunks: Option<bool>,
   pub public_path: Option<String>,
@@ -32,6 +33,7 @@ impl From<RawEntryOptions> for EntryOptions {
     Self {
       name: value.name,
       runtime: value.runtime,
+      depend_on: value.depend_on,
       chunk_loading: value.chunk_loading.as_deref().map(Into::into),
       async_chunks: value.async_chunks,
       public_path: value.public_path.map(Into::into),
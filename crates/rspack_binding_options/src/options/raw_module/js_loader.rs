@@ -238,6 +238,8 @@ pub struct JsLoaderContext {
 
   pub current_loader: String,
   pub is_pitching: bool,
+  /// The identifier of the module being built
+  pub module_identifier: String,
   /// Loader index from JS.
   /// If loaders are dispatched by JS loader runner,
   /// then, this field is correspondence with loader index in JS side.
@@ -311,6 +313,7 @@ impl TryFrom<&mut rspack_core::LoaderContext<'_, rspack_core::LoaderRunnerContex
 
       current_loader: cx.current_loader().to_string(),
       is_pitching: true,
+      module_identifier: cx.context.module.to_string(),
       loader_index_from_js: None,
 
       additional_data_external: External::new(cx.additional_data.clone()),
@@ -44,6 +44,11 @@ pub struct RawResolveOptions {
   #[serde(serialize_with = "ordered_map")]
   #[napi(ts_type = "Record<string, Array<string>>")]
   pub extension_alias: Option<HashMap<String, Vec<String>>>,
+  pub imports_fields: Option<Vec<String>>,
+  pub alias_fields: Option<Vec<String>>,
+  pub restrictions: Option<Vec<String>>,
+  pub roots: Option<Vec<String>>,
+  pub description_files: Option<Vec<String>>,
 }
 
 fn normalize_alias(alias: Option<RawAliasOption>) -> rspack_error::Result<Option<Alias>> {
@@ -106,6 +111,15 @@ impl TryFrom<RawResolveOptions> for Resolve {
       .exports_fields
       .map(|v| v.into_iter().map(|s| vec![s]).collect());
     let extension_alias = value.extension_alias.map(|v| v.into_iter().collect());
+    let imports_field = value
+      .imports_fields
+      .map(|v| v.into_iter().map(|s| vec![s]).collect());
+    let alias_fields = value
+      .alias_fields
+      .map(|v| v.into_iter().map(|s| vec![s]).collect());
+    let restrictions = value.restrictions;
+    let roots = value.roots;
+    let description_files = value.description_files;
     Ok(Resolve {
       modules,
       prefer_relative,
@@ -122,6 +136,11 @@ impl TryFrom<RawResolveOptions> for Resolve {
       fully_specified,
       exports_field,
       extension_alias,
+      imports_field,
+      alias_fields,
+      restrictions,
+      roots,
+      description_files,
     })
   }
 }
@@ -1,7 +1,11 @@
 use napi_derive::napi;
-use rspack_core::{SnapshotOptions, SnapshotStrategy};
+use rspack_core::{SnapshotOptions, SnapshotPath, SnapshotStrategy};
+use rspack_error::error;
+use rspack_regex::RspackRegex;
 use serde::Deserialize;
 
+use crate::RawRegexMatcher;
+
 #[derive(Deserialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
 #[napi(object)]
@@ -19,21 +23,98 @@ impl From<RawSnapshotStrategy> for SnapshotStrategy {
   }
 }
 
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawSnapshotPath {
+  #[napi(ts_type = r#""string" | "regexp""#)]
+  pub r#type: String,
+  pub string_matcher: Option<String>,
+  pub regexp_matcher: Option<RawRegexMatcher>,
+}
+
+impl TryFrom<RawSnapshotPath> for SnapshotPath {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawSnapshotPath) -> rspack_error::Result<Self> {
+    match value.r#type.as_str() {
+      "string" => Ok(Self::String(value.string_matcher.ok_or_else(|| {
+        error!("should have a string_matcher when RawSnapshotPath.type is \"string\"")
+      })?)),
+      "regexp" => {
+        let matcher = value.regexp_matcher.ok_or_else(|| {
+          error!("should have a regexp_matcher when RawSnapshotPath.type is \"regexp\"")
+        })?;
+        Ok(Self::Regexp(RspackRegex::with_flags(
+          &matcher.source,
+          &matcher.flags,
+        )?))
+      }
+      _ => Err(error!(
+        "Failed to resolve the RawSnapshotPath type {}.",
+        value.r#type
+      )),
+    }
+  }
+}
+
 #[derive(Deserialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
 #[napi(object)]
 pub struct RawSnapshotOptions {
   pub resolve: RawSnapshotStrategy,
   pub module: RawSnapshotStrategy,
+  pub build_dependencies: Option<RawSnapshotStrategy>,
+  pub resolve_build_dependencies: Option<RawSnapshotStrategy>,
+  pub immutable_paths: Option<Vec<RawSnapshotPath>>,
+  pub managed_paths: Option<Vec<RawSnapshotPath>>,
+}
+
+fn default_build_dependencies_strategy() -> SnapshotStrategy {
+  SnapshotStrategy {
+    hash: true,
+    timestamp: true,
+  }
 }
 
-impl From<RawSnapshotOptions> for SnapshotOptions {
-  fn from(value: RawSnapshotOptions) -> Self {
-    let RawSnapshotOptions { resolve, module } = value;
+fn into_snapshot_paths(
+  paths: Option<Vec<RawSnapshotPath>>,
+) -> rspack_error::Result<Option<Vec<SnapshotPath>>> {
+  paths
+    .map(|paths| paths.into_iter().map(TryInto::try_into).collect())
+    .transpose()
+}
+
+impl TryFrom<RawSnapshotOptions> for SnapshotOptions {
+  type Error = rspack_error::Error;
 
-    SnapshotOptions {
+  fn try_from(value: RawSnapshotOptions) -> rspack_error::Result<Self> {
+    let RawSnapshotOptions {
+      resolve,
+      module,
+      build_dependencies,
+      resolve_build_dependencies,
+      immutable_paths,
+      managed_paths,
+    } = value;
+
+    Ok(SnapshotOptions {
       resolve: resolve.into(),
       module: module.into(),
-    }
+      build_dependencies: build_dependencies
+        .map(Into::into)
+        .unwrap_or_else(default_build_dependencies_strategy),
+      resolve_build_dependencies: resolve_build_dependencies
+        .map(Into::into)
+        .unwrap_or_else(default_build_dependencies_strategy),
+      immutable_paths: into_snapshot_paths(immutable_paths)?.unwrap_or_default(),
+      // packages in node_modules are validated by their package.json version by default
+      managed_paths: match into_snapshot_paths(managed_paths)? {
+        Some(paths) => paths,
+        None => vec![SnapshotPath::Regexp(RspackRegex::new(
+          r"^(.+?[\\/]node_modules[\\/])",
+        )?)],
+      },
+    })
   }
 }
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use napi_derive::napi;
-use rspack_core::{CodeGenerationResult, CodeGenerationResults};
+use rspack_core::{get_runtime_key, CodeGenerationResult, CodeGenerationResults, RuntimeMode};
 
 #[napi(object)]
 #[derive(Debug)]
@@ -36,10 +36,19 @@ impl From<CodeGenerationResults> for JsCodegenerationResults {
         .map
         .into_iter()
         .map(|(module_id, runtime_result_map)| {
+          let runtime_result_map = match runtime_result_map.mode {
+            RuntimeMode::Empty => Default::default(),
+            RuntimeMode::SingleEntry => runtime_result_map
+              .single_runtime
+              .zip(runtime_result_map.single_value)
+              .map(|(runtime, result_id)| (get_runtime_key(runtime), result_id))
+              .into_iter()
+              .collect(),
+            RuntimeMode::Map => runtime_result_map.map,
+          };
           (
             module_id.to_string(),
             runtime_result_map
-              .map
               .into_iter()
               .map(|(k, result_id)| {
                 (
@@ -429,6 +429,48 @@ impl JsCompilation {
       )
     })
   }
+
+  #[napi(
+    ts_args_type = r#"request: string, context: string, importer: string, callback: (err: null | Error, result: JsImportModuleResult) => void"#
+  )]
+  pub fn import_module(
+    &'static self,
+    env: Env,
+    request: String,
+    context: String,
+    importer: String,
+    f: JsFunction,
+  ) -> Result<()> {
+    callbackify(env, f, async {
+      let result = self
+        .inner
+        .import_module(request, context.into(), Some(importer.into()))
+        .await
+        .map_err(|e| Error::new(napi::Status::GenericFailure, format!("{e}")))?;
+      let to_strings = |paths: rustc_hash::FxHashSet<PathBuf>| {
+        paths
+          .into_iter()
+          .map(|path| path.to_string_lossy().to_string())
+          .collect::<Vec<_>>()
+      };
+      Ok(JsImportModuleResult {
+        id: result.id,
+        file_dependencies: to_strings(result.file_dependencies),
+        context_dependencies: to_strings(result.context_dependencies),
+        missing_dependencies: to_strings(result.missing_dependencies),
+        build_dependencies: to_strings(result.build_dependencies),
+      })
+    })
+  }
+}
+
+#[napi(object)]
+pub struct JsImportModuleResult {
+  pub id: Option<String>,
+  pub file_dependencies: Vec<String>,
+  pub context_dependencies: Vec<String>,
+  pub missing_dependencies: Vec<String>,
+  pub build_dependencies: Vec<String>,
 }
 
 impl JsCompilation {
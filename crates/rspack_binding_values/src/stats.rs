@@ -4,7 +4,7 @@ use napi::{
   Either,
 };
 use napi_derive::napi;
-use rspack_core::Stats;
+use rspack_core::{Stats, StatsOptions};
 
 use super::{JsCompilation, ToJsCompatSource};
 
@@ -16,6 +16,7 @@ pub struct JsStatsError {
   pub module_identifier: Option<String>,
   pub module_name: Option<String>,
   pub module_id: Option<String>,
+  pub module_trace: Vec<JsStatsModuleTrace>,
 }
 
 impl From<rspack_core::StatsError> for JsStatsError {
@@ -26,6 +27,7 @@ impl From<rspack_core::StatsError> for JsStatsError {
       module_identifier: stats.module_identifier,
       module_name: stats.module_name,
       module_id: stats.module_id,
+      module_trace: stats.module_trace.into_iter().map(Into::into).collect(),
     }
   }
 }
@@ -37,6 +39,7 @@ pub struct JsStatsWarning {
   pub module_identifier: Option<String>,
   pub module_name: Option<String>,
   pub module_id: Option<String>,
+  pub module_trace: Vec<JsStatsModuleTrace>,
 }
 
 impl From<rspack_core::StatsWarning> for JsStatsWarning {
@@ -47,6 +50,22 @@ impl From<rspack_core::StatsWarning> for JsStatsWarning {
       module_identifier: stats.module_identifier,
       module_name: stats.module_name,
       module_id: stats.module_id,
+      module_trace: stats.module_trace.into_iter().map(Into::into).collect(),
+    }
+  }
+}
+
+#[napi(object)]
+pub struct JsStatsModuleTrace {
+  pub origin: JsStatsModuleIssuer,
+  pub module: JsStatsModuleIssuer,
+}
+
+impl From<rspack_core::StatsModuleTrace> for JsStatsModuleTrace {
+  fn from(stats: rspack_core::StatsModuleTrace) -> Self {
+    Self {
+      origin: stats.origin.into(),
+      module: stats.module.into(),
     }
   }
 }
@@ -236,6 +255,11 @@ pub struct JsStatsModule {
   pub source: Option<Either<String, Buffer>>,
   pub profile: Option<JsStatsModuleProfile>,
   pub orphan: bool,
+  pub modules: Option<Vec<JsStatsModule>>,
+  pub optimization_bailout: Vec<String>,
+  pub depth: Option<u32>,
+  pub used_exports: Option<Either<bool, Vec<String>>>,
+  pub provided_exports: Option<Vec<String>>,
 }
 
 impl TryFrom<rspack_core::StatsModule<'_>> for JsStatsModule {
@@ -276,6 +300,20 @@ impl TryFrom<rspack_core::StatsModule<'_>> for JsStatsModule {
       source,
       profile: stats.profile.map(|p| p.into()),
       orphan: stats.orphan,
+      modules: stats
+        .modules
+        .map(|modules| modules.into_iter().map(TryInto::try_into).collect())
+        .transpose()?,
+      optimization_bailout: stats.optimization_bailout,
+      depth: stats.depth.map(|depth| depth as u32),
+      used_exports: stats
+        .used_exports
+        .and_then(|used_exports| match used_exports {
+          rspack_core::StatsUsedExports::Null => None,
+          rspack_core::StatsUsedExports::Bool(used) => Some(Either::A(used)),
+          rspack_core::StatsUsedExports::Vec(exports) => Some(Either::B(exports)),
+        }),
+      provided_exports: stats.provided_exports,
     })
   }
 }
@@ -364,6 +402,7 @@ pub struct JsStatsChunk {
   pub parents: Option<Vec<String>>,
   pub children: Option<Vec<String>>,
   pub siblings: Option<Vec<String>>,
+  pub origins: Option<Vec<JsOriginRecord>>,
 }
 
 impl TryFrom<rspack_core::StatsChunk<'_>> for JsStatsChunk {
@@ -385,10 +424,34 @@ impl TryFrom<rspack_core::StatsChunk<'_>> for JsStatsChunk {
       parents: stats.parents,
       children: stats.children,
       siblings: stats.siblings,
+      origins: stats
+        .origins
+        .map(|origins| origins.into_iter().map(Into::into).collect()),
     })
   }
 }
 
+#[napi(object)]
+pub struct JsOriginRecord {
+  pub module: String,
+  pub module_identifier: String,
+  pub module_name: String,
+  pub module_id: String,
+  pub request: String,
+}
+
+impl From<rspack_core::StatsOriginRecord> for JsOriginRecord {
+  fn from(stats: rspack_core::StatsOriginRecord) -> Self {
+    Self {
+      module: stats.module,
+      module_identifier: stats.module_identifier,
+      module_name: stats.module_name,
+      module_id: stats.module_id,
+      request: stats.request,
+    }
+  }
+}
+
 #[napi(object)]
 pub struct JsStatsChunkGroupAsset {
   pub name: String,
@@ -423,6 +486,40 @@ impl From<rspack_core::StatsChunkGroup> for JsStatsChunkGroup {
   }
 }
 
+#[napi(object)]
+pub struct JsStatsShared {
+  pub share_scope: String,
+  pub share_key: String,
+  pub versions: Vec<JsStatsSharedVersion>,
+}
+
+impl From<rspack_core::StatsShared> for JsStatsShared {
+  fn from(stats: rspack_core::StatsShared) -> Self {
+    Self {
+      share_scope: stats.share_scope,
+      share_key: stats.share_key,
+      versions: stats.versions.into_iter().map(Into::into).collect(),
+    }
+  }
+}
+
+#[napi(object)]
+pub struct JsStatsSharedVersion {
+  pub version: Option<String>,
+  pub request: String,
+  pub chunks: Vec<String>,
+}
+
+impl From<rspack_core::StatsSharedVersion> for JsStatsSharedVersion {
+  fn from(stats: rspack_core::StatsSharedVersion) -> Self {
+    Self {
+      version: stats.version,
+      request: stats.request,
+      chunks: stats.chunks,
+    }
+  }
+}
+
 #[napi(object)]
 pub struct JsStatsAssetsByChunkName {
   pub name: String,
@@ -438,6 +535,46 @@ impl From<rspack_core::StatsAssetsByChunkName> for JsStatsAssetsByChunkName {
   }
 }
 
+#[napi(object)]
+pub struct JsStatsOptions {
+  pub modules: bool,
+  pub reasons: bool,
+  pub module_assets: bool,
+  pub nested_modules: bool,
+  pub source: bool,
+  pub chunk_modules: bool,
+  pub chunk_relations: bool,
+  pub chunk_origins: bool,
+  pub ids: bool,
+  pub used_exports: bool,
+  pub provided_exports: bool,
+  pub optimization_bailout: bool,
+  pub depth: bool,
+  pub module_trace: bool,
+}
+
+impl From<JsStatsOptions> for StatsOptions {
+  fn from(options: JsStatsOptions) -> Self {
+    Self {
+      modules: options.modules,
+      reasons: options.reasons,
+      module_assets: options.module_assets,
+      nested_modules: options.nested_modules,
+      source: options.source,
+      chunk_modules: options.chunk_modules,
+      chunk_relations: options.chunk_relations,
+      chunk_origins: options.chunk_origins,
+      ids: options.ids,
+      used_exports: options.used_exports,
+      provided_exports: options.provided_exports,
+      optimization_bailout: options.optimization_bailout,
+      depth: options.depth,
+      module_trace: options.module_trace,
+      ..Default::default()
+    }
+  }
+}
+
 #[napi]
 pub struct JsStats {
   inner: SharedReference<JsCompilation, Stats<'static>>,
@@ -469,16 +606,10 @@ impl JsStats {
   }
 
   #[napi]
-  pub fn get_modules(
-    &self,
-    reasons: bool,
-    module_assets: bool,
-    nested_modules: bool,
-    source: bool,
-  ) -> Result<Vec<JsStatsModule>> {
+  pub fn get_modules(&self, options: JsStatsOptions) -> Result<Vec<JsStatsModule>> {
     self
       .inner
-      .get_modules(reasons, module_assets, nested_modules, source)
+      .get_modules(&options.into())
       .map_err(|e| napi::Error::from_reason(e.to_string()))?
       .into_iter()
       .map(TryInto::try_into)
@@ -486,25 +617,10 @@ impl JsStats {
   }
 
   #[napi]
-  pub fn get_chunks(
-    &self,
-    chunk_modules: bool,
-    chunks_relations: bool,
-    reasons: bool,
-    module_assets: bool,
-    nested_modules: bool,
-    source: bool,
-  ) -> Result<Vec<JsStatsChunk>> {
+  pub fn get_chunks(&self, options: JsStatsOptions) -> Result<Vec<JsStatsChunk>> {
     self
       .inner
-      .get_chunks(
-        chunk_modules,
-        chunks_relations,
-        reasons,
-        module_assets,
-        nested_modules,
-        source,
-      )
+      .get_chunks(&options.into())
       .map_err(|e| napi::Error::from_reason(e.to_string()))?
       .into_iter()
       .map(TryInto::try_into)
@@ -532,20 +648,33 @@ impl JsStats {
   }
 
   #[napi]
-  pub fn get_errors(&self) -> Vec<JsStatsError> {
+  pub fn get_shared(&self) -> Result<Vec<JsStatsShared>> {
+    Ok(
+      self
+        .inner
+        .get_shared()
+        .map_err(|e| napi::Error::from_reason(e.to_string()))?
+        .into_iter()
+        .map(Into::into)
+        .collect(),
+    )
+  }
+
+  #[napi]
+  pub fn get_errors(&self, options: Option<JsStatsOptions>) -> Vec<JsStatsError> {
     self
       .inner
-      .get_errors()
+      .get_errors(&options.map(Into::into).unwrap_or_default())
       .into_iter()
       .map(Into::into)
       .collect()
   }
 
   #[napi]
-  pub fn get_warnings(&self) -> Vec<JsStatsWarning> {
+  pub fn get_warnings(&self, options: Option<JsStatsOptions>) -> Vec<JsStatsWarning> {
     self
       .inner
-      .get_warnings()
+      .get_warnings(&options.map(Into::into).unwrap_or_default())
       .into_iter()
       .map(Into::into)
       .collect()
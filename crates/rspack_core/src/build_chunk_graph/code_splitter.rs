@@ -54,6 +54,11 @@ fn add_chunk_in_group(group_options: Option<&GroupOptions>, info: ChunkGroupInfo
     group_options
       .and_then(|x| x.normal_options())
       .and_then(|x| x.prefetch_order),
+  )
+  .fetch_priority_optional(
+    group_options
+      .and_then(|x| x.normal_options())
+      .and_then(|x| x.fetch_priority),
   );
   let kind = ChunkGroupKind::Normal { options };
   ChunkGroup::new(kind, info)
@@ -109,16 +114,18 @@ impl<'me> CodeSplitter<'me> {
         chunk.filename_template = Some(filename.clone());
       }
       chunk.chunk_reasons.push(format!("Entrypoint({name})",));
-      self
-        .remove_parent_modules_context
-        .add_root_chunk(chunk.ukey);
+      if options.depend_on.is_none() {
+        self
+          .remove_parent_modules_context
+          .add_root_chunk(chunk.ukey);
+      }
 
       compilation.chunk_graph.add_chunk(chunk.ukey);
 
       let mut entrypoint = ChunkGroup::new(
         ChunkGroupKind::new_entrypoint(true, Box::new(options.clone())),
         ChunkGroupInfo {
-          runtime: get_entry_runtime(name, options),
+          runtime: get_entry_runtime(name, options, &compilation.entries),
           chunk_loading: !matches!(
             options
               .chunk_loading
@@ -131,7 +138,7 @@ impl<'me> CodeSplitter<'me> {
             .unwrap_or(compilation.options.output.async_chunks),
         },
       );
-      if options.runtime.is_none() {
+      if options.runtime.is_none() && options.depend_on.is_none() {
         entrypoint.set_runtime_chunk(chunk.ukey);
       }
       entrypoint.set_entry_point_chunk(chunk.ukey);
@@ -201,11 +208,65 @@ impl<'me> CodeSplitter<'me> {
     }
 
     let mut runtime_chunks = HashSet::default();
-    let mut runtime_error = None;
+    let mut runtime_errors = vec![];
     for (name, entry_data) in &compilation.entries {
       let options = &entry_data.options;
 
-      if let Some(runtime) = &options.runtime {
+      if let Some(depend_on) = &options.depend_on {
+        if options.runtime.is_some() {
+          runtime_errors.push(error!(
+            "Entrypoint '{name}' has 'dependOn' and 'runtime' specified. This is not valid.
+Entrypoints that depend on other entrypoints do not have their own runtime.
+They will use the runtime(s) from referenced entrypoints instead.
+Remove the 'runtime' option from the entrypoint."
+          ));
+        }
+        let ukey = *compilation
+          .entrypoints
+          .get(name)
+          .ok_or_else(|| error!("no entrypoints found"))?;
+        let entry_chunk = compilation
+          .chunk_group_by_ukey
+          .expect_get(&ukey)
+          .get_entry_point_chunk();
+        let referenced_chunks = compilation
+          .chunk_by_ukey
+          .expect_get(&entry_chunk)
+          .get_all_referenced_chunks(&compilation.chunk_group_by_ukey);
+
+        let mut depend_on_entries = vec![];
+        for dep in depend_on {
+          let dep_ukey = *compilation
+            .entrypoints
+            .get(dep)
+            .ok_or_else(|| error!("Entry {name} depends on {dep}, but this entry was not found"))?;
+          let dependency = compilation.chunk_group_by_ukey.expect_get(&dep_ukey);
+          if referenced_chunks.contains(&dependency.get_entry_point_chunk()) {
+            runtime_errors.push(error!(
+              "Entrypoints '{name}' and '{dep}' use 'dependOn' to depend on each other in a circular way."
+            ));
+            compilation
+              .chunk_group_by_ukey
+              .expect_get_mut(&ukey)
+              .set_runtime_chunk(entry_chunk);
+            continue;
+          }
+          depend_on_entries.push(dep_ukey);
+        }
+        for dep_ukey in depend_on_entries {
+          let dependency = compilation.chunk_group_by_ukey.expect_get_mut(&dep_ukey);
+          dependency.children.insert(ukey);
+          let parent_chunk = dependency.get_entry_point_chunk();
+          compilation
+            .chunk_group_by_ukey
+            .expect_get_mut(&ukey)
+            .parents
+            .insert(dep_ukey);
+          self
+            .remove_parent_modules_context
+            .add_chunk_relation(parent_chunk, entry_chunk);
+        }
+      } else if let Some(runtime) = &options.runtime {
         let ukey = compilation
           .entrypoints
           .get(name)
@@ -216,11 +277,10 @@ impl<'me> CodeSplitter<'me> {
         let chunk = match compilation.named_chunks.get(runtime) {
           Some(ukey) => {
             if !runtime_chunks.contains(ukey) {
-              // TODO: add dependOn error message once we implement dependeOn
-              // Did you mean to use 'dependOn: {}' instead to allow using entrypoint '{name}' within the runtime of entrypoint '{runtime}'? For this '{runtime}' must always be loaded when '{name}' is used.
-              runtime_error = Some(error!(
+              runtime_errors.push(error!(
 "Entrypoint '{name}' has a 'runtime' option which points to another entrypoint named '{runtime}'.
 It's not valid to use other entrypoints as runtime chunk.
+Did you mean to use 'dependOn: \"{runtime}\"' instead to allow using entrypoint '{name}' within the runtime of entrypoint '{runtime}'? For this '{runtime}' must always be loaded when '{name}' is used.
 Or do you want to use the entrypoints '{name}' and '{runtime}' independently on the same page with a shared runtime? In this case give them both the same value for the 'runtime' option. It must be a name not already used by an entrypoint."
               ));
               let entry_chunk = entry_point.get_entry_point_chunk();
@@ -250,7 +310,56 @@ Or do you want to use the entrypoints '{name}' and '{runtime}' independently on
       }
     }
 
-    if let Some(err) = runtime_error {
+    // entrypoints with `dependOn` use the runtime chunk of the entrypoints they depend on
+    fn get_runtime_chunk<'a>(
+      name: &'a str,
+      compilation: &'a Compilation,
+      visited: &mut HashSet<&'a str>,
+    ) -> Option<ChunkUkey> {
+      let ukey = compilation.entrypoints.get(name)?;
+      if let Some(runtime_chunk) = compilation
+        .chunk_group_by_ukey
+        .expect_get(ukey)
+        .runtime_chunk
+      {
+        return Some(runtime_chunk);
+      }
+      if !visited.insert(name) {
+        return None;
+      }
+      compilation
+        .entries
+        .get(name)?
+        .options
+        .depend_on
+        .as_ref()?
+        .iter()
+        .find_map(|dep| get_runtime_chunk(dep, compilation, visited))
+    }
+    let depend_on_runtime_chunks = compilation
+      .entries
+      .iter()
+      .filter(|(_, entry_data)| entry_data.options.depend_on.is_some())
+      .filter_map(|(name, _)| {
+        let ukey = *compilation.entrypoints.get(name)?;
+        let runtime_chunk = get_runtime_chunk(name, compilation, &mut HashSet::default())
+          .unwrap_or_else(|| {
+            compilation
+              .chunk_group_by_ukey
+              .expect_get(&ukey)
+              .get_entry_point_chunk()
+          });
+        Some((ukey, runtime_chunk))
+      })
+      .collect::<Vec<_>>();
+    for (ukey, runtime_chunk) in depend_on_runtime_chunks {
+      compilation
+        .chunk_group_by_ukey
+        .expect_get_mut(&ukey)
+        .set_runtime_chunk(runtime_chunk);
+    }
+
+    for err in runtime_errors {
       compilation.push_diagnostic(err.into());
     }
     Ok(input_entrypoints_and_modules)
@@ -565,6 +674,14 @@ Or do you want to use the entrypoints '{name}' and '{runtime}' independently on
     };
     self.compilation.chunk_graph.add_chunk(chunk_ukey);
 
+    let origin_loc = block.loc().copied();
+    let origin_request = block
+      .get_dependencies()
+      .first()
+      .and_then(|dep_id| self.compilation.module_graph.dependency_by_id(dep_id))
+      .and_then(|dep| dep.as_module_dependency())
+      .map(|dep| dep.user_request().to_string());
+
     let entry_options = block.get_group_options().and_then(|o| o.entry_options());
     if let Some(cgi) = cgi {
       if entry_options.is_some() {
@@ -721,6 +838,14 @@ Or do you want to use the entrypoints '{name}' and '{runtime}' independently on
       self.block_chunk_groups.insert(block_id, cgi);
     }
 
+    if let Some(cgi) = c.or(entrypoint) {
+      self
+        .compilation
+        .chunk_group_by_ukey
+        .expect_get_mut(&cgi)
+        .add_origin(Some(block_id.from), origin_loc, origin_request);
+    }
+
     if let Some(c) = c {
       let connect_list = self.queue_connect.entry(item_chunk_group_ukey).or_default();
       connect_list.insert(c);
@@ -780,10 +905,17 @@ Or do you want to use the entrypoints '{name}' and '{runtime}' independently on
       .block_modules_runtime_map
       .entry(runtime.cloned().into())
       .or_default();
-    let block = module.into();
-    map.insert(block, Vec::new());
-    for b in block.get_blocks(self.compilation) {
-      map.insert(b.into(), Vec::new());
+    // blocks can be nested, e.g. `import()` inside an async block, every block of the module
+    // should have an entry before the modules are grouped by their parent block
+    let mut queue: Vec<DependenciesBlockIdentifier> = vec![module.into()];
+    while let Some(block) = queue.pop() {
+      map.insert(block, Vec::new());
+      queue.extend(
+        block
+          .get_blocks(self.compilation)
+          .into_iter()
+          .map(DependenciesBlockIdentifier::from),
+      );
     }
 
     let dependencies: Vec<(&BoxDependency, ConnectionState)> =
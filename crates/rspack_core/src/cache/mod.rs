@@ -13,11 +13,13 @@ mod occasion;
 mod snapshot;
 mod storage;
 pub use local::*;
+pub use occasion::MinifyResult;
 use occasion::{
-  BuildModuleOccasion, CodeGenerateOccasion, CreateChunkAssetsOccasion, ResolveModuleOccasion,
+  BuildModuleOccasion, CodeGenerateOccasion, CreateChunkAssetsOccasion, MinifyOccasion,
+  ResolveModuleOccasion,
 };
 use snapshot::SnapshotManager;
-use storage::new_storage;
+use storage::{new_persistent_storage, new_storage};
 
 #[derive(Debug)]
 pub struct Cache {
@@ -27,6 +29,7 @@ pub struct Cache {
   pub build_module_occasion: BuildModuleOccasion,
   pub code_generate_occasion: CodeGenerateOccasion,
   pub create_chunk_assets_occasion: CreateChunkAssetsOccasion,
+  pub minify_occasion: MinifyOccasion,
 }
 
 impl Cache {
@@ -36,15 +39,27 @@ impl Cache {
       is_idle: true.into(),
       snapshot_manager: snapshot_manager.clone(),
       resolve_module_occasion: ResolveModuleOccasion::new(
-        new_storage(&options.cache),
+        new_persistent_storage(&options.cache, "resolve_module", &snapshot_manager),
         snapshot_manager.clone(),
       ),
+      // TODO: persist build results. They hold dependencies and blocks as trait objects which
+      // have no serialized form yet, so they are only cached in memory even when the
+      // filesystem cache is enabled and every cold start builds all modules again.
       build_module_occasion: BuildModuleOccasion::new(
         new_storage(&options.cache),
-        snapshot_manager,
+        snapshot_manager.clone(),
       ),
-      code_generate_occasion: CodeGenerateOccasion::new(new_storage(&options.cache)),
+      code_generate_occasion: CodeGenerateOccasion::new(new_persistent_storage(
+        &options.cache,
+        "code_generate",
+        &snapshot_manager,
+      )),
       create_chunk_assets_occasion: CreateChunkAssetsOccasion::new(new_storage(&options.cache)),
+      minify_occasion: MinifyOccasion::new(new_persistent_storage(
+        &options.cache,
+        "minify",
+        &snapshot_manager,
+      )),
     }
   }
 
@@ -59,6 +74,9 @@ impl Cache {
       .is_ok()
     {
       self.snapshot_manager.clear();
+      self.resolve_module_occasion.begin_idle();
+      self.code_generate_occasion.begin_idle();
+      self.minify_occasion.begin_idle();
     }
   }
 
@@ -1,4 +1,4 @@
-use std::{collections::HashMap, path::Path, sync::Arc};
+use std::{collections::HashMap, path::PathBuf, sync::Arc};
 
 use futures::Future;
 use rkyv::AlignedVec;
@@ -79,7 +79,7 @@ impl BuildModuleOccasion {
         let valid = if let Some(snapshot) = snapshot {
           self
             .snapshot_manager
-            .check_snapshot_valid(&snapshot)
+            .check_snapshot_valid(snapshot)
             .await
             .unwrap_or(false)
         } else {
@@ -126,43 +126,15 @@ impl BuildModuleOccasion {
       }
 
       if data.inner.build_info.cacheable {
-        let mut paths: Vec<&Path> = Vec::new();
-        paths.extend(
-          data
-            .inner
-            .build_info
-            .file_dependencies
-            .iter()
-            .map(|i| i.as_path()),
-        );
-        paths.extend(
-          data
-            .inner
-            .build_info
-            .context_dependencies
-            .iter()
-            .map(|i| i.as_path()),
-        );
-        paths.extend(
-          data
-            .inner
-            .build_info
-            .missing_dependencies
-            .iter()
-            .map(|i| i.as_path()),
-        );
-        paths.extend(
-          data
-            .inner
-            .build_info
-            .build_dependencies
-            .iter()
-            .map(|i| i.as_path()),
-        );
+        let mut paths: Vec<PathBuf> = Vec::new();
+        paths.extend(data.inner.build_info.file_dependencies.iter().cloned());
+        paths.extend(data.inner.build_info.context_dependencies.iter().cloned());
+        paths.extend(data.inner.build_info.missing_dependencies.iter().cloned());
+        paths.extend(data.inner.build_info.build_dependencies.iter().cloned());
 
         let snapshot = self
           .snapshot_manager
-          .create_snapshot(&paths, |option| &option.module)
+          .create_snapshot(paths, |option| &option.module)
           .await?;
         let mut extra_data = HashMap::new();
         module.parser_and_generator().store(&mut extra_data);
@@ -1,11 +1,92 @@
+use std::{
+  collections::hash_map::DefaultHasher,
+  hash::{Hash, Hasher},
+};
+
 use rspack_error::Result;
+use rspack_hash::RspackHashDigest;
 use rspack_identifier::Identifier;
+use rspack_sources::{
+  MapOptions, RawSource, SourceExt, SourceMap, SourceMapSource, WithoutOriginalOptions,
+};
+use serde::{Deserialize, Serialize};
 
-use crate::{cache::storage, BoxModule, CodeGenerationResult, Compilation, NormalModuleSource};
+use crate::{
+  cache::storage::{self, PersistentItem},
+  BoxModule, CodeGenerationResult, Compilation, DependenciesBlock, ModuleIdentifier,
+  NormalModuleSource, RuntimeGlobals, SourceType,
+};
 use crate::{RuntimeSpec, RuntimeSpecSet};
 
 type Storage = dyn storage::Storage<Vec<(CodeGenerationResult, RuntimeSpec)>>;
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CodeGenerationStorageData {
+  /// source type, code and source map
+  sources: Vec<(SourceType, String, Option<String>)>,
+  runtime_requirements: u64,
+  hash: Option<String>,
+  runtime: Vec<String>,
+}
+
+impl PersistentItem for Vec<(CodeGenerationResult, RuntimeSpec)> {
+  type Data = Vec<CodeGenerationStorageData>;
+
+  fn to_data(&self) -> Option<Self::Data> {
+    self
+      .iter()
+      .map(|(result, runtime)| {
+        // data and chunk init fragments are type erased, results carrying them are reported
+        // as non-persistable by the storage and stay in memory
+        if !result.data.is_empty() || !result.chunk_init_fragments.is_empty() {
+          return None;
+        }
+        let sources = result
+          .inner
+          .iter()
+          .map(|(source_type, source)| {
+            let map = source
+              .map(&MapOptions::default())
+              .and_then(|map| map.to_json().ok());
+            (*source_type, source.source().into_owned(), map)
+          })
+          .collect();
+        Some(CodeGenerationStorageData {
+          sources,
+          runtime_requirements: result.runtime_requirements.bits(),
+          hash: result.hash.as_ref().map(|hash| hash.encoded().to_string()),
+          runtime: runtime.iter().map(|r| r.to_string()).collect(),
+        })
+      })
+      .collect()
+  }
+
+  fn from_data(data: Self::Data) -> Option<Self> {
+    data
+      .into_iter()
+      .map(|data| {
+        let mut result = CodeGenerationResult::default();
+        for (source_type, code, map) in data.sources {
+          let source = match map {
+            Some(map) => SourceMapSource::new(WithoutOriginalOptions {
+              value: code,
+              name: source_type.to_string(),
+              source_map: SourceMap::from_json(&map).ok()?,
+            })
+            .boxed(),
+            None => RawSource::from(code).boxed(),
+          };
+          result.add(source_type, source);
+        }
+        result.runtime_requirements = RuntimeGlobals::from_bits(data.runtime_requirements)?;
+        result.hash = data.hash.as_deref().map(RspackHashDigest::from);
+        let runtime = data.runtime.into_iter().map(Into::into).collect();
+        Some((result, runtime))
+      })
+      .collect()
+  }
+}
+
 #[derive(Debug)]
 pub struct CodeGenerateOccasion {
   storage: Option<Box<Storage>>,
@@ -16,6 +97,12 @@ impl CodeGenerateOccasion {
     Self { storage }
   }
 
+  pub fn begin_idle(&self) {
+    if let Some(s) = self.storage.as_ref() {
+      s.begin_idle();
+    }
+  }
+
   #[allow(clippy::unwrap_in_result)]
   pub fn use_cache<'a, G>(
     &self,
@@ -38,10 +125,12 @@ impl CodeGenerateOccasion {
     if let Some(normal_module) = module.as_normal_module() {
       // only cache normal module
       // TODO: cache all module type
-      let id = Identifier::from(compilation.chunk_graph.get_module_graph_hash(
-        module,
-        &compilation.module_graph,
-        true,
+      let id = Identifier::from(format!(
+        "{}|{:016x}",
+        compilation
+          .chunk_graph
+          .get_module_graph_hash(module, &compilation.module_graph, true),
+        referenced_ids_hash(module, compilation)
       ));
 
       // currently no need to separate module hash by runtime
@@ -64,3 +153,50 @@ impl CodeGenerateOccasion {
     Ok((data, false))
   }
 }
+
+/// Hash of the module ids and chunk ids the generated code may refer to. They are not covered by
+/// the module graph hash and change between processes, e.g. when modules are added or removed.
+fn referenced_ids_hash(module: &BoxModule, compilation: &Compilation) -> u64 {
+  let module_id = |identifier: &ModuleIdentifier| {
+    compilation
+      .chunk_graph
+      .chunk_graph_module_by_module_identifier
+      .get(identifier)
+      .and_then(|cgm| cgm.id.clone())
+  };
+  let mut hasher = DefaultHasher::new();
+  module_id(&module.identifier()).hash(&mut hasher);
+
+  let mut connections = compilation
+    .module_graph
+    .get_outgoing_connections(module)
+    .into_iter()
+    .map(|connection| connection.module_identifier)
+    .collect::<Vec<_>>();
+  connections.sort();
+  connections.dedup();
+  for identifier in &connections {
+    identifier.hash(&mut hasher);
+    module_id(identifier).hash(&mut hasher);
+  }
+
+  let mut blocks = module.get_blocks().to_vec();
+  while let Some(block) = blocks.pop() {
+    if let Some(chunk_group) = compilation
+      .chunk_graph
+      .get_block_chunk_group(&block, &compilation.chunk_group_by_ukey)
+    {
+      for chunk in &chunk_group.chunks {
+        compilation
+          .chunk_by_ukey
+          .expect_get(chunk)
+          .id
+          .hash(&mut hasher);
+      }
+    }
+    if let Some(block) = block.get(compilation) {
+      blocks.extend(block.get_blocks().iter().copied());
+    }
+  }
+  hasher.finish()
+}
@@ -0,0 +1,82 @@
+use std::hash::BuildHasherDefault;
+
+use dashmap::DashSet;
+use rspack_identifier::{Identifier, IdentifierHasher};
+use serde::{Deserialize, Serialize};
+
+use crate::cache::storage::{self, PersistentItem};
+
+type Storage = dyn storage::Storage<MinifyResult>;
+
+/// The result of minifying an asset, which only depends on the source of the asset,
+/// its filename and the minify options.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MinifyResult {
+  pub code: String,
+  pub map: Option<String>,
+  /// The extracted comments and the filename of the file they are written to
+  pub extracted_comments: Option<(String, String)>,
+}
+
+impl PersistentItem for MinifyResult {
+  type Data = MinifyResult;
+
+  fn to_data(&self) -> Option<Self::Data> {
+    Some(self.clone())
+  }
+
+  fn from_data(data: Self::Data) -> Option<Self> {
+    Some(data)
+  }
+}
+
+#[derive(Debug)]
+pub struct MinifyOccasion {
+  storage: Option<Box<Storage>>,
+  /// Ids of the results used in the current compilation, the others are outdated as the assets
+  /// have changed and are dropped when the compiler becomes idle.
+  used: DashSet<Identifier, BuildHasherDefault<IdentifierHasher>>,
+}
+
+impl MinifyOccasion {
+  pub fn new(storage: Option<Box<Storage>>) -> Self {
+    Self {
+      storage,
+      used: Default::default(),
+    }
+  }
+
+  pub fn begin_idle(&self) {
+    if let Some(s) = self.storage.as_ref() {
+      // nothing is minified when the compilation fails before the minimizer runs, keep the cache
+      if !self.used.is_empty() {
+        s.retain(&|id| self.used.contains(id));
+        self.used.clear();
+      }
+      s.begin_idle();
+    }
+  }
+
+  /// `id` should be a hash of everything the minification depends on, so unchanged assets
+  /// are not minified again in rebuilds.
+  pub fn use_cache<G, E>(&self, id: Identifier, generator: G) -> Result<(MinifyResult, bool), E>
+  where
+    G: FnOnce() -> Result<MinifyResult, E>,
+  {
+    let storage = match &self.storage {
+      Some(s) => s,
+      // no cache return directly
+      None => return Ok((generator()?, false)),
+    };
+
+    self.used.insert(id);
+    if let Some(data) = storage.get(&id) {
+      return Ok((data, true));
+    }
+
+    // run generator and save to cache
+    let data = generator()?;
+    storage.set(id, data.clone());
+    Ok((data, false))
+  }
+}
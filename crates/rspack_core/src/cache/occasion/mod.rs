@@ -6,3 +6,5 @@ mod code_generate;
 pub use code_generate::*;
 mod create_chunk_assets;
 pub use create_chunk_assets::*;
+mod minify;
+pub use minify::*;
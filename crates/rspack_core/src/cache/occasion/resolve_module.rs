@@ -1,17 +1,73 @@
-use std::sync::Arc;
+use std::{path::PathBuf, sync::Arc};
 
 use futures::Future;
 use rspack_error::Error;
 use rspack_identifier::Identifier;
+use rspack_loader_runner::DescriptionData;
+use serde::{Deserialize, Serialize};
 
 use crate::{
   cache::snapshot::{Snapshot, SnapshotManager},
-  cache::storage,
-  ModuleIdentifier, ResolveArgs, ResolveResult,
+  cache::storage::{self, PersistentItem},
+  ModuleIdentifier, ResolveArgs, ResolveResult, Resource,
 };
 
 type Storage = dyn storage::Storage<(Snapshot, ResolveResult)>;
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ResolveModuleStorageData {
+  snapshot: Snapshot,
+  /// None when the request is ignored
+  resource: Option<ResourceStorageData>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ResourceStorageData {
+  path: PathBuf,
+  query: Option<String>,
+  fragment: Option<String>,
+  description_data: Option<(PathBuf, serde_json::Value)>,
+}
+
+impl PersistentItem for (Snapshot, ResolveResult) {
+  type Data = ResolveModuleStorageData;
+
+  fn to_data(&self) -> Option<Self::Data> {
+    let (snapshot, result) = self;
+    let resource = match result {
+      ResolveResult::Resource(resource) => Some(ResourceStorageData {
+        path: resource.path.clone(),
+        query: resource.query.clone(),
+        fragment: resource.fragment.clone(),
+        description_data: resource
+          .description_data
+          .as_ref()
+          .map(|d| (d.path().to_path_buf(), d.json().clone())),
+      }),
+      ResolveResult::Ignored => None,
+    };
+    Some(ResolveModuleStorageData {
+      snapshot: snapshot.clone(),
+      resource,
+    })
+  }
+
+  fn from_data(data: Self::Data) -> Option<Self> {
+    let result = match data.resource {
+      Some(resource) => ResolveResult::Resource(Resource {
+        path: resource.path,
+        query: resource.query,
+        fragment: resource.fragment,
+        description_data: resource
+          .description_data
+          .map(|(path, json)| DescriptionData::new(path, Arc::new(json))),
+      }),
+      None => ResolveResult::Ignored,
+    };
+    Some((data.snapshot, result))
+  }
+}
+
 #[derive(Debug)]
 pub struct ResolveModuleOccasion {
   storage: Option<Box<Storage>>,
@@ -26,6 +82,12 @@ impl ResolveModuleOccasion {
     }
   }
 
+  pub fn begin_idle(&self) {
+    if let Some(s) = self.storage.as_ref() {
+      s.begin_idle();
+    }
+  }
+
   pub async fn use_cache<'a, G, F>(
     &self,
     args: ResolveArgs<'a>,
@@ -56,7 +118,7 @@ impl ResolveModuleOccasion {
       if let Some((snapshot, data)) = storage.get(&id) {
         let valid = self
           .snapshot_manager
-          .check_snapshot_valid(&snapshot)
+          .check_snapshot_valid(snapshot)
           .await
           .unwrap_or(false);
 
@@ -70,12 +132,12 @@ impl ResolveModuleOccasion {
     let data = generator(args).await?;
     let mut paths = Vec::new();
     if let ResolveResult::Resource(resource) = &data {
-      paths.push(resource.path.as_path());
+      paths.push(resource.path.clone());
     }
 
     let snapshot = self
       .snapshot_manager
-      .create_snapshot(&paths, |option| &option.resolve)
+      .create_snapshot(paths, |option| &option.resolve)
       .await?;
     storage.set(id, (snapshot, data.clone()));
     Ok((Ok(data), false))
@@ -1,12 +1,13 @@
 use std::{
   hash::BuildHasherDefault,
   path::{Path, PathBuf},
+  sync::Arc,
   time::SystemTime,
 };
 
 use dashmap::{DashMap, DashSet};
 use rspack_error::{miette::IntoDiagnostic, Result};
-use rustc_hash::{FxHashMap as HashMap, FxHasher};
+use rustc_hash::FxHasher;
 
 use super::Snapshot;
 use crate::{calc_hash, SnapshotOptions, SnapshotStrategy};
@@ -23,8 +24,12 @@ pub struct SnapshotManager {
   update_time_cache: DashMap<PathBuf, SystemTime, BuildHasherDefault<FxHasher>>,
   /// cache file hash
   hash_cache: DashMap<PathBuf, u64, BuildHasherDefault<FxHasher>>,
+  /// cache managed item info, None when the package.json has no version
+  managed_item_cache: DashMap<PathBuf, Option<String>, BuildHasherDefault<FxHasher>>,
   /// has modified file
   modified_files: DashSet<PathBuf>,
+  /// ancestor directories of modified files, used to check managed items
+  modified_directories: DashSet<PathBuf>,
 }
 
 impl SnapshotManager {
@@ -33,122 +38,197 @@ impl SnapshotManager {
       options,
       update_time_cache: Default::default(),
       hash_cache: Default::default(),
+      managed_item_cache: Default::default(),
       modified_files: Default::default(),
+      modified_directories: Default::default(),
     }
   }
 
-  pub async fn create_snapshot<F>(&self, paths: &[&Path], f: F) -> Result<Snapshot>
+  /// Create a snapshot on the blocking thread pool, use it in async contexts.
+  pub async fn create_snapshot(
+    self: &Arc<Self>,
+    paths: Vec<PathBuf>,
+    f: fn(&SnapshotOptions) -> &SnapshotStrategy,
+  ) -> Result<Snapshot> {
+    let manager = self.clone();
+    tokio::task::spawn_blocking(move || {
+      let paths = paths.iter().map(|p| p.as_path()).collect::<Vec<_>>();
+      manager.create_snapshot_blocking(&paths, f)
+    })
+    .await
+    .into_diagnostic()?
+  }
+
+  /// Check a snapshot on the blocking thread pool, use it in async contexts.
+  pub async fn check_snapshot_valid(self: &Arc<Self>, snapshot: Snapshot) -> Result<bool> {
+    let manager = self.clone();
+    tokio::task::spawn_blocking(move || manager.check_snapshot_valid_blocking(&snapshot))
+      .await
+      .into_diagnostic()?
+  }
+
+  pub fn create_snapshot_blocking<F>(&self, paths: &[&Path], f: F) -> Result<Snapshot>
   where
     F: FnOnce(&SnapshotOptions) -> &SnapshotStrategy,
   {
-    // TODO file_paths deduplication && calc immutable path
     let strategy = f(&self.options);
-    let mut file_update_times = HashMap::default();
-    file_update_times.reserve(paths.len());
-    let mut file_hashes = HashMap::default();
-    file_hashes.reserve(paths.len());
-    if strategy.timestamp {
-      for &path in paths {
-        file_update_times.insert(path.to_owned(), SystemTime::now());
-      }
+    let mut snapshot = Snapshot::default();
+    if strategy.is_empty() {
+      return Ok(snapshot);
     }
-    if strategy.hash {
-      let hash_cache = &self.hash_cache;
-      for &path in paths {
-        let hash = match hash_cache.get(path) {
-          Some(hash) => *hash,
-          None => {
-            let res = if path.is_dir() {
-              let dir = &mut tokio::fs::read_dir(path).await.into_diagnostic()?;
-              let mut sub_files = vec![];
-              while let Some(entry) = dir.next_entry().await.into_diagnostic()? {
-                let dir_u8 = entry.path().as_os_str().to_string_lossy().to_string();
-                sub_files.push(dir_u8);
-              }
-              calc_hash(&sub_files)
-            } else {
-              calc_hash(&tokio::fs::read(path).await.into_diagnostic()?)
-            };
-            hash_cache.insert(path.to_owned(), res);
-            res
-          }
-        };
-        file_hashes.insert(path.to_owned(), hash);
+
+    for &path in paths {
+      if snapshot.file_update_times.contains_key(path) || snapshot.file_hashes.contains_key(path) {
+        continue;
+      }
+      // files in immutable paths never change
+      if self.options.is_immutable_path(path) {
+        continue;
+      }
+      // files in managed paths are validated by the version of the package
+      if let Some(item) = self.options.managed_item(path) {
+        if snapshot.managed_items.contains_key(&item) {
+          continue;
+        }
+        if let Some(info) = self.managed_item_info(&item) {
+          snapshot.managed_items.insert(item, info);
+          continue;
+        }
+      }
+      if strategy.timestamp {
+        snapshot
+          .file_update_times
+          .insert(path.to_owned(), SystemTime::now());
+      }
+      if strategy.hash {
+        snapshot
+          .file_hashes
+          .insert(path.to_owned(), self.content_hash(path)?);
       }
     }
 
-    Ok(Snapshot {
-      file_update_times,
-      file_hashes,
-    })
+    Ok(snapshot)
+  }
+
+  /// Create the snapshot of build dependencies, files are snapshotted with `snapshot.build_dependencies`
+  /// and directories with `snapshot.resolve_build_dependencies`.
+  pub fn create_build_dependencies_snapshot(&self, paths: &[PathBuf]) -> Result<Snapshot> {
+    let (directories, files): (Vec<&Path>, Vec<&Path>) =
+      paths.iter().map(|p| p.as_path()).partition(|p| p.is_dir());
+    let mut snapshot =
+      self.create_snapshot_blocking(&files, |option| &option.build_dependencies)?;
+    snapshot.extend(
+      self.create_snapshot_blocking(&directories, |option| &option.resolve_build_dependencies)?,
+    );
+    Ok(snapshot)
   }
 
-  pub async fn check_snapshot_valid(&self, snapshot: &Snapshot) -> Result<bool> {
+  pub fn check_snapshot_valid_blocking(&self, snapshot: &Snapshot) -> Result<bool> {
     let Snapshot {
       file_update_times,
       file_hashes,
-      ..
+      managed_items,
     } = snapshot;
-    if !file_update_times.is_empty() {
-      // check update time
-      let update_time_cache = &self.update_time_cache;
-      for (path, snapshot_time) in file_update_times {
-        if self.modified_files.contains(path) {
-          return Ok(false);
-        }
 
-        let update_time = match update_time_cache.get(path) {
-          Some(t) => *t,
-          None => {
-            let t = tokio::fs::metadata(path)
-              .await
-              .into_diagnostic()?
-              .modified()
-              .into_diagnostic()?;
-            update_time_cache.insert(path.clone(), t);
-            t
-          }
-        };
-
-        if snapshot_time < &update_time {
-          return Ok(false);
-        }
+    // check update time
+    for (path, snapshot_time) in file_update_times {
+      if self.modified_files.contains(path) {
+        return Ok(false);
+      }
+      if snapshot_time < &self.update_time(path)? {
+        return Ok(false);
       }
     }
 
-    if !file_hashes.is_empty() {
-      // check file hash
-      let hash_cache = &self.hash_cache;
-      for (path, snapshot_hash) in file_hashes {
-        if self.modified_files.contains(path) {
-          return Ok(false);
-        }
+    // check file hash
+    for (path, snapshot_hash) in file_hashes {
+      if self.modified_files.contains(path) {
+        return Ok(false);
+      }
+      if snapshot_hash != &self.content_hash(path)? {
+        return Ok(false);
+      }
+    }
 
-        let current_hash = match hash_cache.get(path) {
-          Some(h) => *h,
-          None => {
-            let res = calc_hash(&tokio::fs::read(path).await.into_diagnostic()?);
-            hash_cache.insert(path.clone(), res);
-            res
-          }
-        };
-        if snapshot_hash != &current_hash {
-          return Ok(false);
-        }
+    // check package version of managed items
+    for (item, snapshot_info) in managed_items {
+      if self.modified_files.contains(item) || self.modified_directories.contains(item) {
+        return Ok(false);
+      }
+      if self.managed_item_info(item).as_ref() != Some(snapshot_info) {
+        return Ok(false);
       }
     }
 
     Ok(true)
   }
 
+  fn update_time(&self, path: &Path) -> Result<SystemTime> {
+    if let Some(t) = self.update_time_cache.get(path) {
+      return Ok(*t);
+    }
+    let t = std::fs::metadata(path)
+      .into_diagnostic()?
+      .modified()
+      .into_diagnostic()?;
+    self.update_time_cache.insert(path.to_owned(), t);
+    Ok(t)
+  }
+
+  fn content_hash(&self, path: &Path) -> Result<u64> {
+    if let Some(h) = self.hash_cache.get(path) {
+      return Ok(*h);
+    }
+    let h = if path.is_dir() {
+      let mut sub_files = vec![];
+      for entry in std::fs::read_dir(path).into_diagnostic()? {
+        let entry = entry.into_diagnostic()?;
+        sub_files.push(entry.path().as_os_str().to_string_lossy().to_string());
+      }
+      sub_files.sort();
+      calc_hash(&sub_files)
+    } else {
+      calc_hash(&std::fs::read(path).into_diagnostic()?)
+    };
+    self.hash_cache.insert(path.to_owned(), h);
+    Ok(h)
+  }
+
+  /// Read `name@version` from the package.json of a managed item.
+  fn managed_item_info(&self, item: &Path) -> Option<String> {
+    if let Some(info) = self.managed_item_cache.get(item) {
+      return info.clone();
+    }
+    let info = std::fs::read(item.join("package.json"))
+      .ok()
+      .and_then(|content| serde_json::from_slice::<serde_json::Value>(&content).ok())
+      .and_then(|json| {
+        let name = json.get("name")?.as_str()?;
+        let version = json.get("version")?.as_str()?;
+        Some(format!("{name}@{version}"))
+      });
+    self
+      .managed_item_cache
+      .insert(item.to_owned(), info.clone());
+    info
+  }
+
   pub fn clear(&self) {
     self.update_time_cache.clear();
     self.hash_cache.clear();
+    self.managed_item_cache.clear();
     self.modified_files.clear();
+    self.modified_directories.clear();
   }
 
   pub fn set_modified_files(&self, files: Vec<PathBuf>) {
     for item in files {
+      for dir in item.ancestors().skip(1) {
+        // the ancestors of a directory are already indexed
+        if !self.modified_directories.insert(dir.to_owned()) {
+          break;
+        }
+      }
       self.modified_files.insert(item);
     }
   }
@@ -1,13 +1,24 @@
 use std::{path::PathBuf, time::SystemTime};
 
 use rustc_hash::FxHashMap as HashMap;
+use serde::{Deserialize, Serialize};
 
 mod manager;
 pub use manager::SnapshotManager;
 
 /// Snapshot store dependenct files update time and hash
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Snapshot {
   pub file_update_times: HashMap<PathBuf, SystemTime>,
   pub file_hashes: HashMap<PathBuf, u64>,
+  /// package directories in managed paths and their `name@version`
+  pub managed_items: HashMap<PathBuf, String>,
+}
+
+impl Snapshot {
+  pub fn extend(&mut self, other: Snapshot) {
+    self.file_update_times.extend(other.file_update_times);
+    self.file_hashes.extend(other.file_hashes);
+    self.managed_items.extend(other.managed_items);
+  }
 }
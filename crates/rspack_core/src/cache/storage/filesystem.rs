@@ -0,0 +1,386 @@
+use std::{
+  fmt,
+  hash::{BuildHasherDefault, Hash, Hasher},
+  path::{Path, PathBuf},
+  sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+  },
+  time::{SystemTime, UNIX_EPOCH},
+};
+
+use dashmap::DashMap;
+use rspack_identifier::{Identifier, IdentifierHasher};
+use rustc_hash::FxHasher;
+use serde::{Deserialize, Serialize};
+
+use super::{PersistentItem, Storage};
+use crate::{
+  cache::snapshot::{Snapshot, SnapshotManager},
+  FileSystemCacheOptions,
+};
+
+/// The on-disk layout of a pack, the whole pack is dropped when `version` mismatches
+/// or the snapshot of build dependencies is outdated.
+#[derive(Debug, Serialize, Deserialize)]
+struct Pack<Data> {
+  version: String,
+  build_dependencies: Snapshot,
+  entries: Vec<PackEntry<Data>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PackEntry<Data> {
+  id: String,
+  /// Last time in milliseconds this entry was stored or read.
+  time: u64,
+  data: Data,
+}
+
+/// FileSystemStorage keeps entries in memory during the compilation
+/// and restores / persists them from / to a pack file under the cache location.
+pub struct FileSystemStorage<Item> {
+  path: PathBuf,
+  version: String,
+  max_age: u64,
+  snapshot_manager: Arc<SnapshotManager>,
+  /// snapshot of build dependencies taken when the storage is created,
+  /// None when it can't be created and the storage works in memory only
+  build_dependencies: Option<Snapshot>,
+  data: DashMap<Identifier, (u64, Item), BuildHasherDefault<IdentifierHasher>>,
+  modified: AtomicBool,
+}
+
+impl<Item> fmt::Debug for FileSystemStorage<Item> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("FileSystemStorage")
+      .field("path", &self.path)
+      .field("version", &self.version)
+      .field("max_age", &self.max_age)
+      .field("len", &self.data.len())
+      .finish()
+  }
+}
+
+impl<Item> FileSystemStorage<Item>
+where
+  Item: PersistentItem,
+{
+  pub fn new(
+    options: &FileSystemCacheOptions,
+    name: &str,
+    snapshot_manager: Arc<SnapshotManager>,
+  ) -> Self {
+    let paths = options
+      .build_dependencies
+      .iter()
+      .map(PathBuf::from)
+      .collect::<Vec<_>>();
+    let build_dependencies = match snapshot_manager.create_build_dependencies_snapshot(&paths) {
+      Ok(snapshot) => Some(snapshot),
+      Err(e) => {
+        tracing::warn!("failed to snapshot build dependencies, persistent cache is disabled: {e}");
+        None
+      }
+    };
+    let storage = Self {
+      path: cache_location(options).join(format!("{name}.pack")),
+      version: pack_version(options),
+      max_age: options.max_age as u64,
+      snapshot_manager,
+      build_dependencies,
+      data: Default::default(),
+      modified: AtomicBool::new(false),
+    };
+    storage.restore();
+    storage
+  }
+
+  fn is_expired(&self, time: u64, now: u64) -> bool {
+    self.max_age != 0 && now.saturating_sub(time) > self.max_age
+  }
+
+  fn restore(&self) {
+    if self.build_dependencies.is_none() {
+      return;
+    }
+    let Ok(content) = std::fs::read(&self.path) else {
+      return;
+    };
+    let pack = match serde_json::from_slice::<Pack<Item::Data>>(&content) {
+      Ok(pack) => pack,
+      Err(e) => {
+        tracing::debug!("drop broken cache pack {}: {e}", self.path.display());
+        return;
+      }
+    };
+    if pack.version != self.version
+      || !self
+        .snapshot_manager
+        .check_snapshot_valid_blocking(&pack.build_dependencies)
+        .unwrap_or(false)
+    {
+      // the whole pack is outdated, it will be overwritten when the compiler becomes idle
+      self.modified.store(true, Ordering::Release);
+      return;
+    }
+    let now = now();
+    for PackEntry { id, time, data } in pack.entries {
+      if self.is_expired(time, now) {
+        self.modified.store(true, Ordering::Release);
+        continue;
+      }
+      if let Some(item) = Item::from_data(data) {
+        self.data.insert(Identifier::from(id), (time, item));
+      }
+    }
+  }
+
+  fn persist(&self) -> std::io::Result<()> {
+    let Some(build_dependencies) = &self.build_dependencies else {
+      return Ok(());
+    };
+    let now = now();
+    let mut entries = vec![];
+    let mut skipped = 0;
+    for entry in self.data.iter() {
+      if self.is_expired(entry.0, now) {
+        continue;
+      }
+      match entry.1.to_data() {
+        Some(data) => entries.push(PackEntry {
+          id: entry.key().to_string(),
+          time: entry.0,
+          data,
+        }),
+        None => {
+          tracing::debug!("skip non-persistable cache entry {}", entry.key());
+          skipped += 1;
+        }
+      }
+    }
+    if skipped > 0 {
+      tracing::info!(
+        "{skipped} cache entries can not be persisted into {}, they are only cached in memory",
+        self.path.display()
+      );
+    }
+    let pack = Pack {
+      version: self.version.clone(),
+      build_dependencies: build_dependencies.clone(),
+      entries,
+    };
+    let content = serde_json::to_vec(&pack)?;
+    if let Some(dir) = self.path.parent() {
+      std::fs::create_dir_all(dir)?;
+    }
+    // write to a temporary file first so that a killed process never leaves a truncated pack
+    let temp = self.path.with_extension("pack.tmp");
+    std::fs::write(&temp, content)?;
+    std::fs::rename(temp, &self.path)
+  }
+}
+
+impl<Item> Storage<Item> for FileSystemStorage<Item>
+where
+  Item: PersistentItem + Clone + fmt::Debug + Send + Sync,
+{
+  fn get(&self, id: &Identifier) -> Option<Item> {
+    self.data.get_mut(id).map(|mut item| {
+      item.0 = now();
+      item.1.clone()
+    })
+  }
+
+  fn set(&self, id: Identifier, data: Item) {
+    self.data.insert(id, (now(), data));
+    self.modified.store(true, Ordering::Release);
+  }
+
+  fn remove(&self, id: &Identifier) {
+    if self.data.remove(id).is_some() {
+      self.modified.store(true, Ordering::Release);
+    }
+  }
+
+  fn retain(&self, f: &dyn Fn(&Identifier) -> bool) {
+    let len = self.data.len();
+    self.data.retain(|id, _| f(id));
+    if self.data.len() != len {
+      self.modified.store(true, Ordering::Release);
+    }
+  }
+
+  fn begin_idle(&self) {
+    if self.modified.swap(false, Ordering::AcqRel) {
+      if let Err(e) = self.persist() {
+        tracing::warn!("failed to store cache pack {}: {e}", self.path.display());
+      }
+    }
+  }
+}
+
+fn now() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|d| d.as_millis() as u64)
+    .unwrap_or_default()
+}
+
+/// Defaults to `cache_directory/name` when `cache_location` is not specified.
+fn cache_location(options: &FileSystemCacheOptions) -> PathBuf {
+  if options.cache_location.is_empty() {
+    Path::new(&options.cache_directory).join(&options.name)
+  } else {
+    PathBuf::from(&options.cache_location)
+  }
+}
+
+/// The pack version combines the crate version, the user defined cache version
+/// and the list of build dependencies, any change of them invalidates all packs.
+fn pack_version(options: &FileSystemCacheOptions) -> String {
+  let mut hasher = FxHasher::default();
+  options.build_dependencies.hash(&mut hasher);
+  format!(
+    "{}|{}|{:016x}",
+    env!("CARGO_PKG_VERSION"),
+    options.version,
+    hasher.finish()
+  )
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::{SnapshotOptions, SnapshotStrategy};
+
+  #[derive(Debug, Clone, PartialEq)]
+  struct Item(String);
+
+  impl PersistentItem for Item {
+    type Data = String;
+
+    fn to_data(&self) -> Option<Self::Data> {
+      // items starting with `memory:` can not be persisted
+      (!self.0.starts_with("memory:")).then(|| self.0.clone())
+    }
+
+    fn from_data(data: Self::Data) -> Option<Self> {
+      Some(Self(data))
+    }
+  }
+
+  fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+      "rspack-filesystem-storage-{name}-{}",
+      std::process::id()
+    ));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).expect("should create temp dir");
+    dir
+  }
+
+  fn options(dir: &Path) -> FileSystemCacheOptions {
+    FileSystemCacheOptions {
+      cache_location: dir.join("cache").to_string_lossy().to_string(),
+      version: "1".into(),
+      ..Default::default()
+    }
+  }
+
+  fn storage(options: &FileSystemCacheOptions) -> FileSystemStorage<Item> {
+    let snapshot_manager = SnapshotManager::new(SnapshotOptions {
+      build_dependencies: SnapshotStrategy {
+        hash: true,
+        timestamp: false,
+      },
+      ..Default::default()
+    });
+    FileSystemStorage::new(options, "test", Arc::new(snapshot_manager))
+  }
+
+  #[test]
+  fn persist_and_restore() {
+    let dir = temp_dir("restore");
+    let options = options(&dir);
+    let s = storage(&options);
+    s.set("a".into(), Item("a".into()));
+    s.set("b".into(), Item("memory:b".into()));
+    s.begin_idle();
+    assert!(dir.join("cache/test.pack").exists());
+
+    let s = storage(&options);
+    assert_eq!(s.get(&"a".into()), Some(Item("a".into())));
+    // non-persistable items only live in memory
+    assert_eq!(s.get(&"b".into()), None);
+    let _ = std::fs::remove_dir_all(dir);
+  }
+
+  #[test]
+  fn invalidate_by_version() {
+    let dir = temp_dir("version");
+    let mut options = options(&dir);
+    let s = storage(&options);
+    s.set("a".into(), Item("a".into()));
+    s.begin_idle();
+
+    options.version = "2".into();
+    let s = storage(&options);
+    assert_eq!(s.get(&"a".into()), None);
+    let _ = std::fs::remove_dir_all(dir);
+  }
+
+  #[test]
+  fn invalidate_by_build_dependencies() {
+    let dir = temp_dir("build-dependencies");
+    let config = dir.join("rspack.config.js");
+    std::fs::write(&config, "module.exports = {}").expect("should write config");
+    let mut options = options(&dir);
+    options.build_dependencies = vec![config.to_string_lossy().to_string()];
+    let s = storage(&options);
+    s.set("a".into(), Item("a".into()));
+    s.begin_idle();
+
+    let s = storage(&options);
+    assert_eq!(s.get(&"a".into()), Some(Item("a".into())));
+
+    std::fs::write(&config, "module.exports = { mode: 'production' }")
+      .expect("should write config");
+    let s = storage(&options);
+    assert_eq!(s.get(&"a".into()), None);
+    let _ = std::fs::remove_dir_all(dir);
+  }
+
+  #[test]
+  fn retain_entries() {
+    let dir = temp_dir("retain");
+    let options = options(&dir);
+    let s = storage(&options);
+    s.set("a".into(), Item("a".into()));
+    s.set("b".into(), Item("b".into()));
+    s.begin_idle();
+
+    let s = storage(&options);
+    s.retain(&|id| id.as_str() == "a");
+    s.begin_idle();
+    let s = storage(&options);
+    assert_eq!(s.get(&"a".into()), Some(Item("a".into())));
+    assert_eq!(s.get(&"b".into()), None);
+    let _ = std::fs::remove_dir_all(dir);
+  }
+
+  #[test]
+  fn drop_expired_entries() {
+    let dir = temp_dir("expired");
+    let mut options = options(&dir);
+    options.max_age = 1;
+    let s = storage(&options);
+    s.set("a".into(), Item("a".into()));
+    s.begin_idle();
+
+    std::thread::sleep(std::time::Duration::from_millis(10));
+    let s = storage(&options);
+    assert_eq!(s.get(&"a".into()), None);
+    let _ = std::fs::remove_dir_all(dir);
+  }
+}
@@ -31,4 +31,7 @@ where
   fn remove(&self, id: &Identifier) {
     self.data.remove(id);
   }
+  fn retain(&self, f: &dyn Fn(&Identifier) -> bool) {
+    self.data.retain(|id, _| f(id));
+  }
 }
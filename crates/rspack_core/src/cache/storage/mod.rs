@@ -1,21 +1,39 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, sync::Arc};
 
 use rspack_identifier::Identifier;
+use serde::{de::DeserializeOwned, Serialize};
 
+use super::snapshot::SnapshotManager;
 use crate::CacheOptions;
 
+mod filesystem;
 mod memory;
+use filesystem::FileSystemStorage;
 use memory::MemoryStorage;
 
 pub trait Storage<Item>: Debug + Send + Sync {
   fn get(&self, id: &Identifier) -> Option<Item>;
   fn set(&self, id: Identifier, data: Item);
   fn remove(&self, id: &Identifier);
-  // fn begin_idle(&self);
+  /// Keeps only the entries whose id passes `f`.
+  fn retain(&self, f: &dyn Fn(&Identifier) -> bool);
+  /// Called when the compiler becomes idle, persistent storages flush their pack here.
+  fn begin_idle(&self) {}
   // fn end_idle(&self);
   // fn clear(&self);
 }
 
+/// An item which can be written into the persistent pack of a filesystem cache.
+pub trait PersistentItem: Sized {
+  type Data: Serialize + DeserializeOwned;
+
+  /// Returns `None` when the item can not be persisted, it will only live in memory.
+  fn to_data(&self) -> Option<Self::Data>;
+
+  /// Returns `None` when the persisted data can not be restored.
+  fn from_data(data: Self::Data) -> Option<Self>;
+}
+
 pub fn new_storage<Item>(options: &CacheOptions) -> Option<Box<dyn Storage<Item>>>
 where
   Item: Debug + Clone + Send + Sync + 'static,
@@ -25,3 +43,24 @@ where
     _ => Some(Box::new(MemoryStorage::new())),
   }
 }
+
+/// Create a storage which is backed by a pack named `name` under the cache location
+/// when the filesystem cache is enabled.
+pub fn new_persistent_storage<Item>(
+  options: &CacheOptions,
+  name: &str,
+  snapshot_manager: &Arc<SnapshotManager>,
+) -> Option<Box<dyn Storage<Item>>>
+where
+  Item: PersistentItem + Debug + Clone + Send + Sync + 'static,
+{
+  match options {
+    CacheOptions::Disabled => None,
+    CacheOptions::Memory(_) => Some(Box::new(MemoryStorage::new())),
+    CacheOptions::FileSystem(options) => Some(Box::new(FileSystemStorage::new(
+      options,
+      name,
+      snapshot_manager.clone(),
+    ))),
+  }
+}
@@ -8,7 +8,7 @@ use rustc_hash::FxHashSet as HashSet;
 
 use crate::{get_chunk_from_ukey, Chunk, ChunkByUkey, ChunkGroupByUkey, ChunkGroupUkey};
 use crate::{ChunkLoading, ChunkUkey, Compilation, Filename};
-use crate::{LibraryOptions, ModuleIdentifier, PublicPath, RuntimeSpec};
+use crate::{DependencyLocation, LibraryOptions, ModuleIdentifier, PublicPath, RuntimeSpec};
 
 impl DatabaseItem for ChunkGroup {
   fn ukey(&self) -> rspack_database::Ukey<Self> {
@@ -16,6 +16,14 @@ impl DatabaseItem for ChunkGroup {
   }
 }
 
+/// Where a chunk group is loaded from, e.g. the module and the request of an `import()`.
+#[derive(Debug, Clone)]
+pub struct OriginRecord {
+  pub module_id: Option<ModuleIdentifier>,
+  pub loc: Option<DependencyLocation>,
+  pub request: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct ChunkGroup {
   pub ukey: ChunkGroupUkey,
@@ -28,6 +36,7 @@ pub struct ChunkGroup {
   pub(crate) module_post_order_indices: IdentifierMap<usize>,
   pub(crate) children: HashSet<ChunkGroupUkey>,
   async_entrypoints: HashSet<ChunkGroupUkey>,
+  origins: Vec<OriginRecord>,
   // ChunkGroupInfo
   pub(crate) next_pre_order_index: usize,
   pub(crate) next_post_order_index: usize,
@@ -47,6 +56,7 @@ impl ChunkGroup {
       parents: Default::default(),
       children: Default::default(),
       async_entrypoints: Default::default(),
+      origins: Default::default(),
       kind,
       next_pre_order_index: 0,
       next_post_order_index: 0,
@@ -68,6 +78,23 @@ impl ChunkGroup {
       .copied()
   }
 
+  pub fn add_origin(
+    &mut self,
+    module_id: Option<ModuleIdentifier>,
+    loc: Option<DependencyLocation>,
+    request: Option<String>,
+  ) {
+    self.origins.push(OriginRecord {
+      module_id,
+      loc,
+      request,
+    });
+  }
+
+  pub fn origins(&self) -> &[OriginRecord] {
+    &self.origins
+  }
+
   pub fn get_files(&self, chunk_by_ukey: &ChunkByUkey) -> Vec<String> {
     self
       .chunks
@@ -311,6 +338,7 @@ pub type EntryRuntime = String;
 pub struct EntryOptions {
   pub name: Option<String>,
   pub runtime: Option<EntryRuntime>,
+  pub depend_on: Option<Vec<String>>,
   pub chunk_loading: Option<ChunkLoading>,
   pub async_chunks: Option<bool>,
   pub public_path: Option<PublicPath>,
@@ -334,6 +362,7 @@ impl EntryOptions {
     }
     merge_field!(name);
     merge_field!(runtime);
+    merge_field!(depend_on);
     merge_field!(chunk_loading);
     merge_field!(async_chunks);
     merge_field!(public_path);
@@ -364,11 +393,29 @@ pub enum ChunkGroupOrderKey {
   Prefetch,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum FetchPriority {
+  Low,
+  High,
+  Auto,
+}
+
+impl FetchPriority {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Self::Low => "low",
+      Self::High => "high",
+      Self::Auto => "auto",
+    }
+  }
+}
+
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct ChunkGroupOptions {
   pub name: Option<String>,
   pub preload_order: Option<u32>,
   pub prefetch_order: Option<u32>,
+  pub fetch_priority: Option<FetchPriority>,
 }
 
 impl ChunkGroupOptions {
@@ -381,12 +428,17 @@ impl ChunkGroupOptions {
       name,
       preload_order,
       prefetch_order,
+      fetch_priority: None,
     }
   }
   pub fn name_optional(mut self, name: Option<String>) -> Self {
     self.name = name;
     self
   }
+  pub fn fetch_priority_optional(mut self, fetch_priority: Option<FetchPriority>) -> Self {
+    self.fetch_priority = fetch_priority;
+    self
+  }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -9,6 +9,7 @@ use rspack_identifier::IdentifierMap;
 use rspack_sources::BoxSource;
 use rustc_hash::FxHashMap as HashMap;
 use serde::Serialize;
+use swc_core::ecma::atoms::JsWord;
 
 use crate::{
   AssetInfo, ChunkInitFragments, ModuleIdentifier, PublicPath, RuntimeGlobals, RuntimeMode,
@@ -68,6 +69,23 @@ impl CodeGenerationDataAssetInfo {
   }
 }
 
+/// The exports of an entry module which is rendered at the top level of an ES module chunk, as
+/// pairs of the exported name and the name of the local binding.
+#[derive(Clone, Debug)]
+pub struct CodeGenerationDataTopLevelExports {
+  inner: Vec<(JsWord, JsWord)>,
+}
+
+impl CodeGenerationDataTopLevelExports {
+  pub fn new(inner: Vec<(JsWord, JsWord)>) -> Self {
+    Self { inner }
+  }
+
+  pub fn inner(&self) -> &[(JsWord, JsWord)] {
+    &self.inner
+  }
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct CodeGenerationData {
   inner: anymap::Map<dyn CloneAny + Send + Sync>,
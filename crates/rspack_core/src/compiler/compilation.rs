@@ -20,17 +20,18 @@ use rspack_identifier::{Identifiable, IdentifierMap, IdentifierSet};
 use rspack_sources::{BoxSource, CachedSource, SourceExt};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet, FxHasher};
 use swc_core::ecma::ast::ModuleItem;
-use tokio::sync::mpsc::error::TryRecvError;
+use tokio::sync::mpsc::{error::TryRecvError, UnboundedReceiver, UnboundedSender};
 use tracing::instrument;
 
 use super::{
   hmr::CompilationRecords,
   make::{MakeParam, RebuildDepsBuilder},
+  Records,
 };
 use crate::{
   build_chunk_graph::build_chunk_graph,
   cache::{use_code_splitting_cache, Cache, CodeSplittingCache},
-  get_chunk_from_ukey, get_mut_chunk_from_ukey, is_source_equal,
+  get_chunk_from_ukey, get_entry_runtime, get_mut_chunk_from_ukey, is_source_equal,
   tree_shaking::{optimizer, visitor::SymbolRef, BailoutFlag, OptimizeDependencyResult},
   AddQueue, AddTask, AddTaskResult, AdditionalChunkRuntimeRequirementsArgs,
   AdditionalModuleRequirementsArgs, AsyncDependenciesBlock, BoxDependency, BoxModule, BuildQueue,
@@ -39,12 +40,12 @@ use crate::{
   CleanTask, CleanTaskResult, CodeGenerationResult, CodeGenerationResults, CompilationLogger,
   CompilationLogging, CompilerOptions, ContentHashArgs, ContextDependency, DependencyId,
   DependencyParents, DependencyType, Entry, EntryData, EntryOptions, Entrypoint, ErrorSpan,
-  FactorizeQueue, FactorizeTask, FactorizeTaskResult, Filename, Logger, Module, ModuleFactory,
-  ModuleGraph, ModuleGraphModule, ModuleIdentifier, ModuleProfile, NormalModuleSource, PathData,
-  ProcessAssetsArgs, ProcessDependenciesQueue, ProcessDependenciesResult, ProcessDependenciesTask,
-  RenderManifestArgs, Resolve, ResolverFactory, RuntimeGlobals, RuntimeModule,
-  RuntimeRequirementsInTreeArgs, RuntimeSpec, SharedPluginDriver, SourceType, Stats, TaskResult,
-  WorkerTask,
+  FactorizeQueue, FactorizeTask, FactorizeTaskResult, Filename, ImportModuleTask, Logger, Module,
+  ModuleFactory, ModuleGraph, ModuleGraphModule, ModuleIdentifier, ModuleProfile,
+  NormalModuleSource, PathData, ProcessAssetsArgs, ProcessDependenciesQueue,
+  ProcessDependenciesResult, ProcessDependenciesTask, RenderManifestArgs, Resolve, ResolverFactory,
+  RuntimeGlobals, RuntimeModule, RuntimeRequirementsInTreeArgs, RuntimeSpec, SharedPluginDriver,
+  SourceType, Stats, TaskResult, WorkerTask,
 };
 use crate::{tree_shaking::visitor::OptimizeAnalyzeResult, Context};
 
@@ -62,6 +63,8 @@ pub struct Compilation {
   // So use compilation hash update `hot_index` to fix it.
   pub hot_index: u32,
   pub records: Option<CompilationRecords>,
+  /// Records of the previous compilation, the ids of modules and chunks are revived from them.
+  pub input_records: Option<Arc<Records>>,
   pub options: Arc<CompilerOptions>,
   pub entries: Entry,
   pub global_entry: EntryData,
@@ -109,6 +112,9 @@ pub struct Compilation {
   pub build_dependencies: IndexSet<PathBuf, BuildHasherDefault<FxHasher>>,
   pub side_effects_free_modules: IdentifierSet,
   pub module_item_map: IdentifierMap<Vec<ModuleItem>>,
+  /// `importModule` requests of loaders, they are handled by the running make
+  import_module_tx: UnboundedSender<ImportModuleTask>,
+  import_module_rx: UnboundedReceiver<ImportModuleTask>,
 }
 
 impl Compilation {
@@ -122,9 +128,11 @@ impl Compilation {
     records: Option<CompilationRecords>,
     cache: Arc<Cache>,
   ) -> Self {
+    let (import_module_tx, import_module_rx) = tokio::sync::mpsc::unbounded_channel();
     Self {
       hot_index: 0,
       records,
+      input_records: None,
       options,
       module_graph,
       dependency_factories: Default::default(),
@@ -170,23 +178,20 @@ impl Compilation {
       side_effects_free_modules: IdentifierSet::default(),
       module_item_map: IdentifierMap::default(),
       include_module_ids: IdentifierSet::default(),
+      import_module_tx,
+      import_module_rx,
     }
   }
 
   pub fn get_entry_runtime(&self, name: &String, options: Option<&EntryOptions>) -> RuntimeSpec {
-    let (_, runtime) = if let Some(options) = options {
-      ((), options.runtime.as_ref())
-    } else {
-      match self.entries.get(name) {
-        Some(entry) => ((), entry.options.runtime.as_ref()),
+    let options = match options {
+      Some(options) => options,
+      None => match self.entries.get(name) {
+        Some(entry) => &entry.options,
         None => return RuntimeSpec::from_iter([Arc::from(name.as_str())]),
-      }
+      },
     };
-    // TODO: depend on https://github.com/webpack/webpack/blob/1f99ad6367f2b8a6ef17cce0e058f7a67fb7db18/lib/util/runtime.js#L33, we don't have that field now
-    runtime
-      .or(Some(name))
-      .map(|runtime| RuntimeSpec::from_iter([Arc::from(runtime.as_ref())]))
-      .unwrap_or_default()
+    get_entry_runtime(name, options, &self.entries)
   }
 
   pub fn add_entry(&mut self, entry: BoxDependency, options: EntryOptions) -> Result<()> {
@@ -536,6 +541,8 @@ impl Compilation {
     let mut make_failed_dependencies: HashSet<BuildDependency> = HashSet::default();
     let mut make_failed_module = HashSet::default();
     let mut errored = None;
+    let mut import_module_tasks = vec![];
+    let mut unresolved_dependencies = HashSet::default();
 
     deps_builder
       .revoke_modules(&mut self.module_graph)
@@ -585,6 +592,39 @@ impl Compilation {
     }
 
     tokio::task::block_in_place(|| loop {
+      while let Ok(task) = self.import_module_rx.try_recv() {
+        let dependency_id =
+          self.add_import_module_dependency(task.request.clone(), task.context.clone());
+        self.handle_module_creation(
+          &mut factorize_queue,
+          None,
+          None,
+          vec![dependency_id],
+          false,
+          None,
+          self.lazy_visit_modules.clone(),
+          None,
+        );
+        import_module_tasks.push((dependency_id, task));
+      }
+
+      // the importers are waiting, execute the imported modules once they are built
+      for (dependency_id, task) in std::mem::take(&mut import_module_tasks) {
+        let built =
+          self.is_import_module_built(dependency_id, task.importer, &unresolved_dependencies);
+        let result = match built {
+          Ok(true) => tokio::runtime::Handle::current()
+            .block_on(self.finish_import_module(dependency_id, &task.request)),
+          Ok(false) => {
+            import_module_tasks.push((dependency_id, task));
+            continue;
+          }
+          Err(e) => Err(e),
+        };
+        // the importer may have given up waiting
+        let _ = task.result_tx.send(result);
+      }
+
       let start = factorize_time.start();
       while let Some(task) = factorize_queue.get_task() {
         tokio::spawn({
@@ -779,6 +819,7 @@ impl Compilation {
                   });
                   tracing::trace!("Module created: {}", &module_identifier);
                 } else {
+                  unresolved_dependencies.extend(dependencies.iter().copied());
                   let dep = self
                     .module_graph
                     .dependency_by_id(&dependencies[0])
@@ -786,6 +827,7 @@ impl Compilation {
                   tracing::trace!("Module ignored: {dep:?}")
                 }
               } else {
+                unresolved_dependencies.extend(dependencies.iter().copied());
                 let dep = self
                   .module_graph
                   .dependency_by_id(&dependencies[0])
@@ -878,13 +920,16 @@ impl Compilation {
                     );
                     module_graph.add_dependency(dependency);
                   }
-                  if let Some(current_block) = current_block {
-                    module.add_block_id(current_block.identifier());
-                    module_graph.add_block(current_block);
-                  }
                   for block in blocks {
+                    // nested blocks are already recorded in the ids of their parent block
+                    if current_block.is_none() {
+                      module.add_block_id(block.identifier());
+                    }
                     queue.push_back(block);
                   }
+                  if let Some(current_block) = current_block {
+                    module_graph.add_block(current_block);
+                  }
                 };
               handle_block(
                 build_result.dependencies,
@@ -1934,14 +1979,6 @@ impl Compilation {
     CompilationLogger::new(name.into(), self.logging.clone())
   }
 
-  pub fn execute_module(&self, entry: ModuleIdentifier) -> Result<Option<String>> {
-    let codegen_result = Default::default();
-    // TODO
-    self
-      .plugin_driver
-      .execute_module(entry, vec![], &codegen_result)
-  }
-
   pub fn set_dependency_factory(
     &mut self,
     dependency_type: DependencyType,
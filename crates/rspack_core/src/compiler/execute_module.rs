@@ -0,0 +1,351 @@
+use std::path::PathBuf;
+
+use rayon::prelude::*;
+use rspack_error::{error, Result};
+use rspack_identifier::IdentifierSet;
+use rustc_hash::FxHashSet as HashSet;
+use tokio::sync::oneshot;
+
+use crate::{
+  BoxDependency, Chunk, ChunkGroup, ChunkGroupInfo, ChunkGroupKind, ChunkGroupUkey, ChunkKind,
+  ChunkUkey, CodeGenerationResult, CodeGenerationResults, Compilation, Context, DependencyId,
+  LoaderImportDependency, ModuleIdentifier, RuntimeSpec,
+};
+
+/// The runtime of the modules code generated for execution at build time.
+pub const BUILD_TIME_RUNTIME: &str = "build time";
+
+#[derive(Debug, Default)]
+pub struct ImportModuleResult {
+  /// The id returned by the executor, `None` if there is no executor available.
+  pub id: Option<String>,
+  pub file_dependencies: HashSet<PathBuf>,
+  pub context_dependencies: HashSet<PathBuf>,
+  pub missing_dependencies: HashSet<PathBuf>,
+  pub build_dependencies: HashSet<PathBuf>,
+}
+
+/// An `importModule` request of a loader, which is handled as a task of make.
+#[derive(Debug)]
+pub struct ImportModuleTask {
+  pub request: String,
+  pub context: Context,
+  /// The module whose loader imports the request, it can't be depended on as it waits for the result to be built.
+  pub importer: Option<ModuleIdentifier>,
+  pub result_tx: oneshot::Sender<Result<ImportModuleResult>>,
+}
+
+impl Compilation {
+  /// Build the module of `request` with all its dependencies and execute it, like `this.importModule` in webpack loaders.
+  /// The dependencies of all the executed modules are returned so that the importer can be rebuilt when they change.
+  ///
+  /// The request is queued as a task of the running make, which owns the compilation, so it's only available
+  /// while modules are built, e.g. in loaders.
+  pub async fn import_module(
+    &self,
+    request: String,
+    context: Context,
+    importer: Option<ModuleIdentifier>,
+  ) -> Result<ImportModuleResult> {
+    let (result_tx, result_rx) = oneshot::channel();
+    self
+      .import_module_tx
+      .send(ImportModuleTask {
+        request: request.clone(),
+        context,
+        importer,
+        result_tx,
+      })
+      .map_err(|_| error!("Failed to import module {request}, the compilation is closed"))?;
+    result_rx
+      .await
+      .map_err(|_| error!("Failed to import module {request}, modules are not being built"))?
+  }
+
+  /// Add the dependency of an `importModule` request, it's factorized like an entry without a parent module.
+  pub(crate) fn add_import_module_dependency(
+    &mut self,
+    request: String,
+    context: Context,
+  ) -> DependencyId {
+    let dependency: BoxDependency = Box::new(LoaderImportDependency::new(request, context));
+    let dependency_id = *dependency.id();
+    self.module_graph.add_dependency(dependency);
+    dependency_id
+  }
+
+  /// Whether the module of `dependency_id` and all the modules it depends on are built, and all their
+  /// dependencies are resolved. `unresolved` are the dependencies which are resolved to no module.
+  ///
+  /// Returns an error if the modules depend on the `importer`, which is waiting for them and would never be built.
+  pub(crate) fn is_import_module_built(
+    &self,
+    dependency_id: DependencyId,
+    importer: Option<ModuleIdentifier>,
+    unresolved: &HashSet<DependencyId>,
+  ) -> Result<bool> {
+    if unresolved.contains(&dependency_id) {
+      return Ok(true);
+    }
+    let Some(entry) = self
+      .module_graph
+      .module_identifier_by_dependency_id(&dependency_id)
+    else {
+      return Ok(false);
+    };
+    let mut visited = IdentifierSet::default();
+    let mut queue = vec![*entry];
+    while let Some(module_identifier) = queue.pop() {
+      if !visited.insert(module_identifier) {
+        continue;
+      }
+      if importer == Some(module_identifier) {
+        return Err(error!(
+          "There is a circular build dependency on {module_identifier}, which makes it impossible to import it from its own loader"
+        ));
+      }
+      // modules are added to the module graph after they are built
+      if self
+        .module_graph
+        .module_by_identifier(&module_identifier)
+        .is_none()
+      {
+        return Ok(false);
+      }
+      let Some(dependencies) = self
+        .module_graph
+        .get_module_all_dependencies(&module_identifier)
+      else {
+        return Ok(false);
+      };
+      for dependency_id in dependencies {
+        let dependency = dependency_id.get_dependency(&self.module_graph);
+        if dependency.as_module_dependency().is_none()
+          && dependency.as_context_dependency().is_none()
+        {
+          continue;
+        }
+        if unresolved.contains(dependency_id) {
+          continue;
+        }
+        match self
+          .module_graph
+          .module_identifier_by_dependency_id(dependency_id)
+        {
+          Some(module_identifier) => queue.push(*module_identifier),
+          None => return Ok(false),
+        }
+      }
+    }
+    Ok(true)
+  }
+
+  /// Execute the module of an `importModule` request once it's built, see `is_import_module_built`.
+  pub(crate) async fn finish_import_module(
+    &mut self,
+    dependency_id: DependencyId,
+    request: &str,
+  ) -> Result<ImportModuleResult> {
+    let entry = *self
+      .module_graph
+      .module_identifier_by_dependency_id(&dependency_id)
+      .ok_or_else(|| error!("Failed to import module {request}, the module could not be built"))?;
+
+    let mut result = ImportModuleResult::default();
+    for module_identifier in self.get_build_time_modules(entry) {
+      let Some(build_info) = self
+        .module_graph
+        .module_by_identifier(&module_identifier)
+        .and_then(|module| module.build_info())
+      else {
+        continue;
+      };
+      result
+        .file_dependencies
+        .extend(build_info.file_dependencies.iter().cloned());
+      result
+        .context_dependencies
+        .extend(build_info.context_dependencies.iter().cloned());
+      result
+        .missing_dependencies
+        .extend(build_info.missing_dependencies.iter().cloned());
+      result
+        .build_dependencies
+        .extend(build_info.build_dependencies.iter().cloned());
+    }
+    result.id = self.execute_module(entry).await?;
+    Ok(result)
+  }
+
+  /// Code generate `entry` and the modules it depends on in a temporary chunk with the `build time` runtime,
+  /// then hand the results with the required runtime modules over to the plugins to execute them.
+  pub async fn execute_module(&mut self, entry: ModuleIdentifier) -> Result<Option<String>> {
+    let modules = self.get_build_time_modules(entry);
+    let runtime = RuntimeSpec::from_iter([BUILD_TIME_RUNTIME.into()]);
+
+    let mut chunk = Chunk::new(Some("build time chunk".to_string()), ChunkKind::Normal);
+    chunk.runtime = runtime.clone();
+    let chunk_ukey = chunk.ukey;
+    let mut entrypoint = ChunkGroup::new(
+      ChunkGroupKind::new_entrypoint(true, Box::default()),
+      ChunkGroupInfo {
+        chunk_loading: false,
+        async_chunks: false,
+        runtime: runtime.clone(),
+      },
+    );
+    entrypoint.set_runtime_chunk(chunk_ukey);
+    entrypoint.set_entry_point_chunk(chunk_ukey);
+    entrypoint.connect_chunk(&mut chunk);
+    let entrypoint_ukey = entrypoint.ukey;
+    self.chunk_by_ukey.add(chunk);
+    self.chunk_group_by_ukey.add(entrypoint);
+
+    // The temporary chunk must not leak into the chunk graph and the results of the real compilation
+    let chunk_graph = std::mem::take(&mut self.chunk_graph);
+    let code_generation_results = std::mem::take(&mut self.code_generation_results);
+    let runtime_modules = std::mem::take(&mut self.runtime_modules);
+
+    let result = self
+      .execute_build_time_chunk(entry, modules, chunk_ukey, entrypoint_ukey, &runtime)
+      .await;
+
+    self.chunk_graph = chunk_graph;
+    self.code_generation_results = code_generation_results;
+    self.runtime_modules = runtime_modules;
+    self.chunk_by_ukey.remove(&chunk_ukey);
+    self.chunk_group_by_ukey.remove(&entrypoint_ukey);
+
+    result
+  }
+
+  async fn execute_build_time_chunk(
+    &mut self,
+    entry: ModuleIdentifier,
+    modules: IdentifierSet,
+    chunk_ukey: ChunkUkey,
+    entrypoint_ukey: ChunkGroupUkey,
+    runtime: &RuntimeSpec,
+  ) -> Result<Option<String>> {
+    self.chunk_graph.add_chunk(chunk_ukey);
+    for module_identifier in &modules {
+      self.chunk_graph.add_module(*module_identifier);
+      // Use the identifier as the module id, the executor requires modules by their identifiers
+      self
+        .chunk_graph
+        .set_module_id(*module_identifier, module_identifier.to_string());
+      self
+        .chunk_graph
+        .connect_chunk_and_module(chunk_ukey, *module_identifier);
+    }
+    self
+      .chunk_graph
+      .connect_chunk_and_entry_module(chunk_ukey, entry, entrypoint_ukey);
+
+    // Same as the real code generation, modules with code generation dependencies are generated last
+    let (without_dependencies, with_dependencies): (Vec<_>, Vec<_>) =
+      modules.iter().copied().partition(|module_identifier| {
+        self
+          .module_graph
+          .module_by_identifier(module_identifier)
+          .map_or(true, |module| {
+            module.get_code_generation_dependencies().is_none()
+          })
+      });
+    for batch in [without_dependencies, with_dependencies] {
+      let compilation = &*self;
+      let results = batch
+        .into_par_iter()
+        .filter_map(|module_identifier| {
+          compilation
+            .module_graph
+            .module_by_identifier(&module_identifier)
+            .map(|module| {
+              module
+                .code_generation(compilation, Some(runtime))
+                .map(|result| (module_identifier, result))
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+      for (module_identifier, result) in results {
+        self
+          .code_generation_results
+          .add(module_identifier, runtime.clone(), result.id);
+        self
+          .code_generation_results
+          .module_generation_result_map
+          .insert(result.id, result);
+      }
+    }
+
+    self
+      .process_runtime_requirements(
+        modules,
+        std::iter::once(chunk_ukey),
+        std::iter::once(chunk_ukey),
+        self.plugin_driver.clone(),
+      )
+      .await?;
+
+    let mut runtime_modules = self
+      .chunk_graph
+      .get_chunk_runtime_modules_in_order(&chunk_ukey)
+      .iter()
+      .filter_map(|identifier| {
+        self
+          .runtime_modules
+          .get(identifier)
+          .map(|module| (*identifier, module))
+      })
+      .collect::<Vec<_>>();
+    runtime_modules.sort_unstable_by_key(|(_, module)| module.stage());
+    let runtime_modules = runtime_modules
+      .into_iter()
+      .map(|(identifier, module)| {
+        let result = CodeGenerationResult::default().with_javascript(module.generate(self));
+        (identifier, result)
+      })
+      .collect::<Vec<_>>();
+
+    let mut code_generation_results = std::mem::take(&mut self.code_generation_results);
+    let runtime_module_identifiers = runtime_modules
+      .into_iter()
+      .map(|(identifier, result)| {
+        code_generation_results.add(identifier, runtime.clone(), result.id);
+        code_generation_results
+          .module_generation_result_map
+          .insert(result.id, result);
+        identifier
+      })
+      .collect::<Vec<_>>();
+
+    self
+      .plugin_driver
+      .clone()
+      .execute_module(entry, runtime_module_identifiers, &code_generation_results)
+      .await
+  }
+
+  /// All the modules `entry` depends on, including itself.
+  fn get_build_time_modules(&self, entry: ModuleIdentifier) -> IdentifierSet {
+    let mut modules = IdentifierSet::default();
+    let mut queue = vec![entry];
+    while let Some(module_identifier) = queue.pop() {
+      if !modules.insert(module_identifier) {
+        continue;
+      }
+      let Some(module) = self.module_graph.module_by_identifier(&module_identifier) else {
+        continue;
+      };
+      queue.extend(
+        self
+          .module_graph
+          .get_outgoing_connections(module)
+          .into_iter()
+          .map(|connection| connection.module_identifier)
+          .filter(|module_identifier| !modules.contains(module_identifier)),
+      );
+    }
+    modules
+  }
+}
@@ -1,11 +1,12 @@
+use std::hash::Hash;
 use std::path::PathBuf;
 
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use rspack_error::Result;
 use rspack_fs::AsyncWritableFileSystem;
-use rspack_hash::RspackHashDigest;
+use rspack_hash::{RspackHash, RspackHashDigest};
 use rspack_identifier::IdentifierMap;
-use rustc_hash::FxHashSet as HashSet;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
 use super::MakeParam;
 use crate::{
@@ -26,7 +27,6 @@ where
     let old_hash = self.compilation.hash.clone();
 
     let (old_all_modules, old_runtime_modules) = collect_changed_modules(old.compilation);
-    // TODO: should use `records`
 
     let mut all_old_runtime: RuntimeSpec = Default::default();
     for entry_ukey in old.compilation.get_chunk_graph_entries() {
@@ -47,7 +47,10 @@ where
     let records = CompilationRecords {
       old_chunks,
       all_old_runtime,
-      old_all_modules,
+      old_all_modules: old_all_modules
+        .into_values()
+        .map(|(hash, id)| (id, hash))
+        .collect(),
       old_runtime_modules,
       old_hash,
     };
@@ -62,7 +65,10 @@ where
       self
         .cache
         .set_modified_files(modified_files.iter().cloned().collect::<Vec<_>>());
-      self.plugin_driver.resolver_factory.clear_cache();
+      self
+        .plugin_driver
+        .resolver_factory
+        .clear_entries_affected_by(&modified_files);
 
       let mut new_compilation = Compilation::new(
         self.options.clone(),
@@ -79,6 +85,7 @@ where
       }
 
       new_compilation.hot_index = self.compilation.hot_index + 1;
+      new_compilation.input_records = self.records.clone();
 
       let is_incremental_rebuild_make = self.options.is_incremental_rebuild_make_enabled();
       if is_incremental_rebuild_make {
@@ -139,8 +146,10 @@ where
 pub struct CompilationRecords {
   pub old_chunks: Vec<(String, RuntimeSpec)>,
   pub all_old_runtime: RuntimeSpec,
-  pub old_all_modules: IdentifierMap<(RspackHashDigest, String)>,
-  pub old_runtime_modules: IdentifierMap<String>,
+  /// Module id -> module hash, modules are keyed by ids to diff with the records of another process.
+  pub old_all_modules: HashMap<String, RspackHashDigest>,
+  /// Runtime module identifier -> hash of the runtime module content.
+  pub old_runtime_modules: IdentifierMap<RspackHashDigest>,
   pub old_hash: Option<RspackHashDigest>,
 }
 
@@ -148,7 +157,7 @@ pub fn collect_changed_modules(
   compilation: &Compilation,
 ) -> (
   IdentifierMap<(RspackHashDigest, String)>,
-  IdentifierMap<String>,
+  IdentifierMap<RspackHashDigest>,
 ) {
   let modules_map = compilation
     .chunk_graph
@@ -173,9 +182,11 @@ pub fn collect_changed_modules(
     .runtime_modules
     .iter()
     .map(|(identifier, module)| {
+      let mut hasher = RspackHash::from(&compilation.options.output);
+      module.generate(compilation).source().hash(&mut hasher);
       (
         *identifier,
-        module.generate(compilation).source().to_string(),
+        hasher.digest(&compilation.options.output.hash_digest),
       )
     })
     .collect();
@@ -1,7 +1,10 @@
 mod compilation;
+mod execute_module;
 mod hmr;
 mod make;
 mod queue;
+mod records;
+mod watching;
 
 use std::collections::hash_map::Entry;
 use std::ops::Deref;
@@ -17,9 +20,12 @@ use swc_core::ecma::atoms::JsWord;
 use tracing::instrument;
 
 pub use self::compilation::*;
+pub use self::execute_module::*;
 pub use self::hmr::{collect_changed_modules, CompilationRecords};
 pub use self::make::MakeParam;
 pub use self::queue::*;
+pub use self::records::Records;
+pub use self::watching::WatchEvent;
 use crate::cache::Cache;
 use crate::tree_shaking::symbol::{IndirectType, StarSymbolKind, DEFAULT_JS_WORD};
 use crate::tree_shaking::visitor::SymbolRef;
@@ -45,6 +51,8 @@ where
   /// emitted asset versions
   /// the key of HashMap is filename, the value of HashMap is version
   pub emitted_asset_versions: HashMap<String, String>,
+  /// records of the last compilation, restored from `recordsInputPath` at the first build
+  pub records: Option<Arc<Records>>,
 }
 
 impl<T> Compiler<T>
@@ -82,6 +90,7 @@ where
       loader_resolver_factory,
       cache,
       emitted_asset_versions: Default::default(),
+      records: None,
     }
   }
 
@@ -93,8 +102,7 @@ where
   #[instrument(name = "build", skip_all)]
   pub async fn build(&mut self) -> Result<()> {
     self.cache.end_idle();
-    // TODO: clear the outdated cache entries in resolver,
-    // TODO: maybe it's better to use external entries.
+    // The modified files since the last build are unknown, resolve everything again.
     self.plugin_driver.resolver_factory.clear_cache();
 
     fast_set(
@@ -109,6 +117,11 @@ where
         self.cache.clone(),
       ),
     );
+    self.read_records()?;
+    if let Some(records) = &self.records {
+      self.compilation.input_records = Some(records.clone());
+      self.compilation.records = records.to_compilation_records();
+    }
 
     self
       .compile(MakeParam::ForceBuildDeps(Default::default()))
@@ -289,12 +302,45 @@ where
     self.emit_assets().await?;
     logger.time_end(start);
 
+    let start = logger.time("emitRecords");
+    self.emit_records().await?;
+    logger.time_end(start);
+
     let start = logger.time("done hook");
     self.compilation.done(self.plugin_driver.clone()).await?;
     logger.time_end(start);
     Ok(())
   }
 
+  fn read_records(&mut self) -> Result<()> {
+    if self.records.is_some() {
+      return Ok(());
+    }
+    if let Some(records_input_path) = &self.options.records_input_path {
+      self.records = Records::read(records_input_path)?.map(Arc::new);
+    }
+    Ok(())
+  }
+
+  #[instrument(name = "emit_records", skip_all)]
+  async fn emit_records(&mut self) -> Result<()> {
+    if !self.options.is_records_enabled() {
+      return Ok(());
+    }
+    let records = Records::from_compilation(&self.compilation);
+    if let Some(records_output_path) = &self.options.records_output_path {
+      if let Some(dir) = records_output_path.parent() {
+        self.output_filesystem.create_dir_all(dir).await?;
+      }
+      self
+        .output_filesystem
+        .write(records_output_path, records.to_json()?.as_bytes())
+        .await?;
+    }
+    self.records = Some(Arc::new(records));
+    Ok(())
+  }
+
   #[instrument(name = "emit_assets", skip_all)]
   pub async fn emit_assets(&mut self) -> Result<()> {
     if self.options.output.clean {
@@ -0,0 +1,145 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use rspack_error::{error, Result};
+use rustc_hash::FxHashMap as HashMap;
+use serde::{Deserialize, Serialize};
+
+use super::hmr::{collect_changed_modules, CompilationRecords};
+use crate::{contextify, Chunk, ChunkKind, Compilation, ModuleIdentifier, RuntimeSpec};
+
+/// Records persisted across compilations by `recordsOutputPath` and restored by `recordsInputPath`,
+/// which keep module and chunk ids stable and let hot updates be diffed after a restart.
+///
+/// Module and chunks are keyed by portable keys, so the records can be shared between machines.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Records {
+  /// Full hash of the compilation.
+  pub hash: Option<String>,
+  /// Portable module identifier -> module id.
+  pub module_ids: BTreeMap<String, String>,
+  /// Module id -> module hash.
+  pub module_hashes: BTreeMap<String, String>,
+  /// Chunk name, or the portable identifiers of its root modules -> chunk id.
+  pub chunk_ids: BTreeMap<String, String>,
+  /// Chunk id -> runtimes of the chunk.
+  pub chunk_runtime: BTreeMap<String, Vec<String>>,
+  /// Runtime module identifier -> hash of the runtime module content.
+  pub runtime_module_hashes: BTreeMap<String, String>,
+}
+
+impl Records {
+  /// Read the records, returns `None` if the records file does not exist yet.
+  pub fn read(path: &Path) -> Result<Option<Self>> {
+    let content = match std::fs::read(path) {
+      Ok(content) => content,
+      Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+      Err(e) => {
+        return Err(error!(
+          "Failed to read records from {}: {e}",
+          path.display()
+        ))
+      }
+    };
+    let records = serde_json::from_slice(&content)
+      .map_err(|e| error!("Failed to parse records from {}: {e}", path.display()))?;
+    Ok(Some(records))
+  }
+
+  pub fn to_json(&self) -> Result<String> {
+    serde_json::to_string_pretty(self).map_err(|e| error!("Failed to serialize records: {e}"))
+  }
+
+  pub fn module_key(module_identifier: &ModuleIdentifier, context: &str) -> String {
+    contextify(context, module_identifier)
+  }
+
+  pub fn chunk_key(chunk: &Chunk, compilation: &Compilation) -> String {
+    if let Some(name) = &chunk.name {
+      return name.to_owned();
+    }
+    let mut root_modules = compilation
+      .chunk_graph
+      .get_chunk_root_modules(&chunk.ukey, &compilation.module_graph)
+      .iter()
+      .map(|m| Self::module_key(m, compilation.options.context.as_str()))
+      .collect::<Vec<_>>();
+    root_modules.sort_unstable();
+    root_modules.join(",")
+  }
+
+  /// Record the ids and hashes of a sealed compilation.
+  pub fn from_compilation(compilation: &Compilation) -> Self {
+    let context = compilation.options.context.as_str();
+    let (modules, runtime_modules) = collect_changed_modules(compilation);
+
+    let mut records = Self {
+      hash: compilation.hash.as_ref().map(|h| h.encoded().to_string()),
+      ..Default::default()
+    };
+    for (identifier, (hash, id)) in modules {
+      records
+        .module_ids
+        .insert(Self::module_key(&identifier, context), id.clone());
+      records.module_hashes.insert(id, hash.encoded().to_string());
+    }
+    for chunk in compilation.chunk_by_ukey.values() {
+      if chunk.kind == ChunkKind::HotUpdate {
+        continue;
+      }
+      let Some(id) = &chunk.id else {
+        continue;
+      };
+      records
+        .chunk_ids
+        .insert(Self::chunk_key(chunk, compilation), id.clone());
+      let mut runtime = chunk
+        .runtime
+        .iter()
+        .map(|r| r.to_string())
+        .collect::<Vec<_>>();
+      runtime.sort_unstable();
+      records.chunk_runtime.insert(id.clone(), runtime);
+    }
+    records.runtime_module_hashes = runtime_modules
+      .into_iter()
+      .map(|(identifier, hash)| (identifier.to_string(), hash.encoded().to_string()))
+      .collect();
+    records
+  }
+
+  /// Restore the state of the recorded compilation for diffing hot updates,
+  /// returns `None` if the records are not recorded from a compilation.
+  pub fn to_compilation_records(&self) -> Option<CompilationRecords> {
+    let old_hash = self.hash.as_deref()?;
+    let mut all_old_runtime = RuntimeSpec::default();
+    let old_chunks = self
+      .chunk_runtime
+      .iter()
+      .map(|(id, runtime)| {
+        let runtime = runtime
+          .iter()
+          .map(|r| r.as_str().into())
+          .collect::<RuntimeSpec>();
+        all_old_runtime.extend(runtime.iter().cloned());
+        (id.clone(), runtime)
+      })
+      .collect();
+    Some(CompilationRecords {
+      old_chunks,
+      all_old_runtime,
+      old_all_modules: self
+        .module_hashes
+        .iter()
+        .map(|(id, hash)| (id.clone(), hash.as_str().into()))
+        .collect::<HashMap<_, _>>(),
+      old_runtime_modules: self
+        .runtime_module_hashes
+        .iter()
+        .map(|(identifier, hash)| (identifier.as_str().into(), hash.as_str().into()))
+        .collect(),
+      old_hash: Some(old_hash.into()),
+    })
+  }
+}
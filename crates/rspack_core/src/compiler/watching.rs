@@ -0,0 +1,234 @@
+use std::ops::ControlFlow;
+use std::path::{Path, PathBuf};
+
+use notify::{Config, Event, EventKind, PollWatcher, RecommendedWatcher, RecursiveMode, Watcher};
+use rspack_error::{error, Result};
+use rspack_fs::AsyncWritableFileSystem;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+
+use crate::{Compilation, Compiler, WatchOptions};
+
+#[derive(Debug)]
+pub enum WatchEvent<'a> {
+  /// A build is finished, the diagnostics of the build are available in the compilation.
+  Done(&'a Compilation),
+  /// Some dependencies of the last compilation are changed, a rebuild is about to start.
+  Invalid {
+    changed_files: &'a HashSet<PathBuf>,
+    removed_files: &'a HashSet<PathBuf>,
+  },
+}
+
+impl<T> Compiler<T>
+where
+  T: AsyncWritableFileSystem + Send + Sync,
+{
+  /// Build and then rebuild whenever the file, context or missing dependencies of the last compilation change,
+  /// until `handler` breaks.
+  pub async fn watch<F>(&mut self, options: WatchOptions, mut handler: F) -> Result<()>
+  where
+    F: FnMut(WatchEvent<'_>) -> ControlFlow<()>,
+  {
+    self.build().await?;
+    if handler(WatchEvent::Done(&self.compilation)).is_break() {
+      return Ok(());
+    }
+
+    // keep watching while rebuilding, changes during the rebuild will trigger the next one
+    let (tx, mut rx) = unbounded_channel();
+    let mut watcher = FileWatcher::new(&options, tx)?;
+    loop {
+      let dependencies = WatchDependencies::new(&self.compilation, &options);
+      watcher.update(dependencies.targets());
+
+      let Some(modified_files) = dependencies.aggregate(&mut rx, &options).await else {
+        return Ok(());
+      };
+      let (removed_files, changed_files): (HashSet<_>, HashSet<_>) =
+        modified_files.into_iter().partition(|file| !file.exists());
+      if handler(WatchEvent::Invalid {
+        changed_files: &changed_files,
+        removed_files: &removed_files,
+      })
+      .is_break()
+      {
+        return Ok(());
+      }
+
+      let to_strings = |files: HashSet<PathBuf>| {
+        files
+          .into_iter()
+          .map(|file| file.to_string_lossy().to_string())
+          .collect()
+      };
+      self
+        .rebuild(to_strings(changed_files), to_strings(removed_files))
+        .await?;
+      if handler(WatchEvent::Done(&self.compilation)).is_break() {
+        return Ok(());
+      }
+    }
+  }
+}
+
+/// The watcher lives as long as the watching, so that no change is lost between the builds.
+struct FileWatcher {
+  watcher: Box<dyn Watcher + Send>,
+  watched: HashMap<PathBuf, RecursiveMode>,
+}
+
+impl FileWatcher {
+  fn new(options: &WatchOptions, tx: UnboundedSender<Event>) -> Result<Self> {
+    let event_handler = move |event: notify::Result<Event>| {
+      if let Ok(event) = event {
+        // the receiver is dropped when the watching is stopped
+        let _ = tx.send(event);
+      }
+    };
+    let watcher = match options.poll {
+      Some(interval) => PollWatcher::new(
+        event_handler,
+        Config::default().with_poll_interval(interval),
+      )
+      .map(|watcher| -> Box<dyn Watcher + Send> { Box::new(watcher) }),
+      None => RecommendedWatcher::new(event_handler, Config::default())
+        .map(|watcher| -> Box<dyn Watcher + Send> { Box::new(watcher) }),
+    };
+    let watcher = watcher.map_err(|e| error!("Failed to create the file watcher: {e}"))?;
+    Ok(Self {
+      watcher,
+      watched: HashMap::default(),
+    })
+  }
+
+  /// Watch the new targets and unwatch the ones that are not depended on anymore.
+  fn update(&mut self, targets: HashMap<PathBuf, RecursiveMode>) {
+    let watcher = &mut self.watcher;
+    self.watched.retain(|path, mode| {
+      if targets.get(path) == Some(mode) {
+        return true;
+      }
+      let _ = watcher.unwatch(path);
+      false
+    });
+    for (path, mode) in targets {
+      if self.watched.contains_key(&path) {
+        continue;
+      }
+      // the directory may be removed since it was checked, it will be reported by its parent
+      if self.watcher.watch(&path, mode).is_ok() {
+        self.watched.insert(path, mode);
+      }
+    }
+  }
+}
+
+#[derive(Debug, Default)]
+struct WatchDependencies {
+  files: HashSet<PathBuf>,
+  directories: HashSet<PathBuf>,
+  missing: HashSet<PathBuf>,
+}
+
+impl WatchDependencies {
+  fn new(compilation: &Compilation, options: &WatchOptions) -> Self {
+    let not_ignored = |path: &&PathBuf| !options.is_ignored(path);
+    Self {
+      files: compilation
+        .file_dependencies
+        .iter()
+        .filter(not_ignored)
+        .cloned()
+        .collect(),
+      directories: compilation
+        .context_dependencies
+        .iter()
+        .filter(not_ignored)
+        .cloned()
+        .collect(),
+      missing: compilation
+        .missing_dependencies
+        .iter()
+        .filter(not_ignored)
+        .cloned()
+        .collect(),
+    }
+  }
+
+  /// Whether a change of `path` affects the dependencies.
+  fn is_affected_by(&self, path: &Path) -> bool {
+    self.files.contains(path)
+      || self.directories.iter().any(|dir| path.starts_with(dir))
+      // the creation of a missing file or one of its parent directories
+      || self.missing.iter().any(|missing| missing.starts_with(path))
+  }
+
+  /// Files are watched through their parent directories to keep track of files that are replaced by editors,
+  /// and missing files are watched through their closest existing ancestor.
+  fn targets(&self) -> HashMap<PathBuf, RecursiveMode> {
+    let mut targets = HashMap::default();
+    for dir in &self.directories {
+      if dir.is_dir() {
+        targets.insert(dir.clone(), RecursiveMode::Recursive);
+      }
+    }
+    for path in self.files.iter().chain(self.missing.iter()) {
+      if let Some(dir) = path.ancestors().skip(1).find(|dir| dir.is_dir()) {
+        targets
+          .entry(dir.to_path_buf())
+          .or_insert(RecursiveMode::NonRecursive);
+      }
+    }
+    targets
+  }
+
+  fn collect(&self, event: Event, modified_files: &mut HashSet<PathBuf>, options: &WatchOptions) {
+    if matches!(event.kind, EventKind::Access(_)) {
+      return;
+    }
+    modified_files.extend(
+      event
+        .paths
+        .into_iter()
+        .filter(|path| !options.is_ignored(path) && self.is_affected_by(path)),
+    );
+  }
+
+  /// Wait for the first change and collect the changes until there is no change for `aggregate_timeout`,
+  /// the changes during the last build are already queued. Returns `None` if the watcher is gone.
+  async fn aggregate(
+    &self,
+    rx: &mut UnboundedReceiver<Event>,
+    options: &WatchOptions,
+  ) -> Option<HashSet<PathBuf>> {
+    let mut modified_files = HashSet::default();
+    while modified_files.is_empty() {
+      let event = rx.recv().await?;
+      self.collect(event, &mut modified_files, options);
+    }
+    while let Ok(Some(event)) = tokio::time::timeout(options.aggregate_timeout, rx.recv()).await {
+      self.collect(event, &mut modified_files, options);
+    }
+    Some(modified_files)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn affected_by() {
+    let dependencies = WatchDependencies {
+      files: HashSet::from_iter([PathBuf::from("/app/src/index.js")]),
+      directories: HashSet::from_iter([PathBuf::from("/app/src/pages")]),
+      missing: HashSet::from_iter([PathBuf::from("/app/src/utils/index.js")]),
+    };
+    assert!(dependencies.is_affected_by(Path::new("/app/src/index.js")));
+    assert!(dependencies.is_affected_by(Path::new("/app/src/pages/home/index.js")));
+    assert!(dependencies.is_affected_by(Path::new("/app/src/utils")));
+    assert!(dependencies.is_affected_by(Path::new("/app/src/utils/index.js")));
+    assert!(!dependencies.is_affected_by(Path::new("/app/src/other.js")));
+  }
+}
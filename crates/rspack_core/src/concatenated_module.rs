@@ -0,0 +1,558 @@
+use std::{borrow::Cow, hash::Hash, sync::Arc};
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use rspack_error::{error, Diagnosable, Diagnostic, Result};
+use rspack_identifier::{Identifiable, IdentifierMap};
+use rspack_sources::{
+  BoxSource, CachedSource, ConcatSource, RawSource, ReplaceSource, Source, SourceExt,
+};
+use rspack_util::ext::DynHash;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use swc_core::ecma::atoms::JsWord;
+
+use crate::{
+  get_import_var, property_access, render_init_fragments, to_identifier,
+  AsyncDependenciesBlockIdentifier, BoxModule, BuildInfo, BuildMeta, ChunkUkey, CodeGenerationData,
+  CodeGenerationDataTopLevelExports, CodeGenerationResult, Compilation, ConcatenatedModules,
+  ConcatenationScope, ConnectionState, Context, DependenciesBlock, DependencyId, DependencyType,
+  GenerateContext, HarmonyExportInitFragment, InitFragmentKey, InitFragmentStage, LibIdentOptions,
+  Module, ModuleGraph, ModuleIdentifier, ModuleInitFragments, ModuleType, NormalInitFragment,
+  NormalModule, Resolve, RuntimeGlobals, RuntimeSpec, SourceType, UsedName, DEFAULT_EXPORT,
+};
+
+/// Names which are used by the module wrapper and runtime, top level bindings are never renamed to them.
+const RESERVED_NAMES: [&str; 7] = [
+  "__webpack_require__",
+  "__webpack_exports__",
+  "__webpack_module__",
+  "__webpack_modules__",
+  "__webpack_module_cache__",
+  "module",
+  "exports",
+];
+
+static UNCOOL_EXTRA_INFO_REGEXP: Lazy<Regex> = Lazy::new(|| {
+  Regex::new(r"\.+/|(/index)?\.([a-zA-Z0-9]{1,4})($|\s|\?)|\s*\+\s*\d+\s*modules")
+    .expect("should be a valid regex")
+});
+
+/// A module which is the result of merging harmony modules into the scope of the root module.
+///
+/// The concatenated module shares the identifier with the root module and replaces it in the module graph,
+/// other modules in the scope are disconnected from chunks.
+///
+/// Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/optimize/ConcatenatedModule.js
+#[derive(Debug)]
+pub struct ConcatenatedModule {
+  root_module: BoxModule,
+  /// Modules in the scope in evaluation order, the root module is the last one.
+  modules: Arc<ConcatenatedModules>,
+  dependencies: Vec<DependencyId>,
+  blocks: Vec<AsyncDependenciesBlockIdentifier>,
+  size: f64,
+}
+
+impl ConcatenatedModule {
+  pub fn new(
+    root_module: BoxModule,
+    modules: ConcatenatedModules,
+    module_graph: &ModuleGraph,
+  ) -> Self {
+    let root = root_module.identifier();
+    let mut dependencies = vec![];
+    let mut blocks = vec![];
+    let mut size = 0.0;
+    for module_identifier in &modules {
+      let module = if *module_identifier == root {
+        &root_module
+      } else {
+        module_graph
+          .module_by_identifier(module_identifier)
+          .expect("should have module")
+      };
+      size += module.size(&SourceType::JavaScript);
+      blocks.extend(module.get_blocks().iter().copied());
+      // imports of modules in the same scope are inlined
+      dependencies.extend(module.get_dependencies().iter().copied().filter(|dep_id| {
+        let is_harmony_import = module_graph.dependency_by_id(dep_id).is_some_and(|dep| {
+          matches!(
+            dep.dependency_type(),
+            DependencyType::EsmImport(_) | DependencyType::EsmImportSpecifier
+          )
+        });
+        !is_harmony_import
+          || !module_graph
+            .module_identifier_by_dependency_id(dep_id)
+            .is_some_and(|m| modules.contains(m))
+      }));
+    }
+    Self {
+      root_module,
+      modules: Arc::new(modules),
+      dependencies,
+      blocks,
+      size,
+    }
+  }
+
+  pub fn root_module(&self) -> &BoxModule {
+    &self.root_module
+  }
+
+  pub fn into_root_module(self) -> BoxModule {
+    self.root_module
+  }
+
+  pub fn modules(&self) -> &ConcatenatedModules {
+    &self.modules
+  }
+
+  fn normal_module<'a>(
+    &'a self,
+    module_identifier: &ModuleIdentifier,
+    module_graph: &'a ModuleGraph,
+  ) -> Result<&'a NormalModule> {
+    let module = if *module_identifier == self.root_module.identifier() {
+      Some(&self.root_module)
+    } else {
+      module_graph.module_by_identifier(module_identifier)
+    };
+    module
+      .and_then(|m| m.as_normal_module())
+      .ok_or_else(|| error!("{module_identifier} should be a normal module"))
+  }
+
+  /// Whether the module is the only module of ES module library entry chunks, which load no other
+  /// chunks. It's rendered at the top level of the chunks then, and its exports are re-exported as
+  /// the bindings of the chunk by `export` statements.
+  fn is_top_level_entry(&self, compilation: &Compilation) -> bool {
+    let chunk_graph = &compilation.chunk_graph;
+    let chunk_group_by_ukey = &compilation.chunk_group_by_ukey;
+    let module_identifier = self.identifier();
+    let chunks = chunk_graph.get_module_chunks(module_identifier);
+    !chunks.is_empty()
+      && chunks.iter().all(|chunk_ukey| {
+        let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+        let is_module_library = chunk
+          .get_entry_options(chunk_group_by_ukey)
+          .and_then(|options| options.library.as_ref())
+          .or(compilation.options.output.library.as_ref())
+          .map_or(false, |library| library.library_type == "module");
+        is_module_library
+          && chunk.has_runtime(chunk_group_by_ukey)
+          && chunk_graph.get_chunk_entry_modules(chunk_ukey) == [module_identifier]
+          && chunk_graph.get_number_of_chunk_modules(chunk_ukey) == 1
+          && !chunk.has_async_chunks(chunk_group_by_ukey)
+          && chunk
+            .groups
+            .iter()
+            .all(|group| chunk_group_by_ukey.expect_get(group).chunks.len() == 1)
+      })
+  }
+
+  /// Assign the final names of the top level bindings of every module, bindings are renamed
+  /// when they conflict with names of other modules.
+  fn assign_names(
+    &self,
+    compilation: &Compilation,
+  ) -> Result<IdentifierMap<HashMap<JsWord, JsWord>>> {
+    let module_graph = &compilation.module_graph;
+    let mut reserved: HashSet<JsWord> = RESERVED_NAMES.iter().map(|&n| JsWord::from(n)).collect();
+    let mut all_names = HashSet::default();
+    for module_identifier in self.modules.iter() {
+      let module = self.normal_module(module_identifier, module_graph)?;
+      if let Some(build_info) = module.build_info() {
+        all_names.extend(build_info.used_names.iter().cloned());
+        all_names.extend(build_info.top_level_declarations.iter().flatten().cloned());
+      }
+      // variables of modules imported from the outside of the scope
+      for dep_id in module.get_dependencies() {
+        let is_harmony_import = module_graph.dependency_by_id(dep_id).is_some_and(|dep| {
+          matches!(
+            dep.dependency_type(),
+            DependencyType::EsmImport(_) | DependencyType::EsmImportSpecifier
+          )
+        });
+        if is_harmony_import
+          && let Some(target) = module_graph.module_identifier_by_dependency_id(dep_id)
+          && !self.modules.contains(target)
+        {
+          reserved.insert(get_import_var(module_graph, *dep_id).into());
+        }
+      }
+    }
+    all_names.extend(reserved.iter().cloned());
+
+    let mut assigned: HashSet<JsWord> = HashSet::default();
+    let mut result = IdentifierMap::default();
+    for module_identifier in self.modules.iter() {
+      let module = self.normal_module(module_identifier, module_graph)?;
+      let Some(build_info) = module.build_info() else {
+        continue;
+      };
+      let mut declarations = build_info
+        .top_level_declarations
+        .iter()
+        .flatten()
+        .collect::<Vec<_>>();
+      declarations.sort();
+      let mut names = HashMap::default();
+      for name in declarations {
+        let conflict = name == DEFAULT_EXPORT
+          || reserved.contains(name)
+          || assigned.contains(name)
+          || self.modules.iter().any(|other| {
+            other != module_identifier
+              && self
+                .normal_module(other, module_graph)
+                .ok()
+                .and_then(|m| m.build_info())
+                .is_some_and(|info| info.used_names.contains(name))
+          });
+        let final_name = if conflict {
+          let readable_identifier = module.readable_identifier(&compilation.options.context);
+          let new_name = find_new_name(name, &readable_identifier, &all_names);
+          all_names.insert(new_name.clone());
+          new_name
+        } else {
+          name.clone()
+        };
+        assigned.insert(final_name.clone());
+        names.insert(name.clone(), final_name);
+      }
+      result.insert(*module_identifier, names);
+    }
+    Ok(result)
+  }
+}
+
+/// Port from https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/optimize/ConcatenatedModule.js#L1825
+fn find_new_name(old_name: &str, extra_info: &str, used_names: &HashSet<JsWord>) -> JsWord {
+  let mut name = if old_name == DEFAULT_EXPORT {
+    String::new()
+  } else {
+    old_name.to_string()
+  };
+  let extra_info = UNCOOL_EXTRA_INFO_REGEXP.replace_all(extra_info, "");
+  for info in extra_info.split('/').rev() {
+    name = if name.is_empty() {
+      info.to_string()
+    } else {
+      format!("{info}_{name}")
+    };
+    let name_ident = JsWord::from(to_identifier(&name));
+    if !used_names.contains(&name_ident) {
+      return name_ident;
+    }
+  }
+  let mut i = 0;
+  loop {
+    let name_with_number = JsWord::from(to_identifier(&format!("{name}_{i}")));
+    if !used_names.contains(&name_with_number) {
+      return name_with_number;
+    }
+    i += 1;
+  }
+}
+
+impl Identifiable for ConcatenatedModule {
+  fn identifier(&self) -> ModuleIdentifier {
+    self.root_module.identifier()
+  }
+}
+
+impl DependenciesBlock for ConcatenatedModule {
+  fn add_block_id(&mut self, block: AsyncDependenciesBlockIdentifier) {
+    self.blocks.push(block)
+  }
+
+  fn get_blocks(&self) -> &[AsyncDependenciesBlockIdentifier] {
+    &self.blocks
+  }
+
+  fn add_dependency_id(&mut self, dependency: DependencyId) {
+    self.dependencies.push(dependency)
+  }
+
+  fn get_dependencies(&self) -> &[DependencyId] {
+    &self.dependencies
+  }
+}
+
+impl Diagnosable for ConcatenatedModule {
+  fn add_diagnostic(&self, diagnostic: Diagnostic) {
+    self.root_module.add_diagnostic(diagnostic)
+  }
+
+  fn add_diagnostics(&self, diagnostics: Vec<Diagnostic>) {
+    self.root_module.add_diagnostics(diagnostics)
+  }
+
+  fn clone_diagnostics(&self) -> Vec<Diagnostic> {
+    self.root_module.clone_diagnostics()
+  }
+
+  fn take_diagnostics(&self) -> Vec<Diagnostic> {
+    self.root_module.take_diagnostics()
+  }
+}
+
+#[async_trait::async_trait]
+impl Module for ConcatenatedModule {
+  fn module_type(&self) -> &ModuleType {
+    self.root_module.module_type()
+  }
+
+  fn source_types(&self) -> &[SourceType] {
+    self.root_module.source_types()
+  }
+
+  fn original_source(&self) -> Option<&dyn Source> {
+    self.root_module.original_source()
+  }
+
+  fn readable_identifier(&self, context: &Context) -> Cow<str> {
+    Cow::Owned(format!(
+      "{} + {} modules",
+      self.root_module.readable_identifier(context),
+      self.modules.len() - 1
+    ))
+  }
+
+  fn size(&self, _source_type: &SourceType) -> f64 {
+    self.size
+  }
+
+  fn build_info(&self) -> Option<&BuildInfo> {
+    self.root_module.build_info()
+  }
+
+  fn build_meta(&self) -> Option<&BuildMeta> {
+    self.root_module.build_meta()
+  }
+
+  fn set_module_build_info_and_meta(&mut self, build_info: BuildInfo, build_meta: BuildMeta) {
+    self
+      .root_module
+      .set_module_build_info_and_meta(build_info, build_meta)
+  }
+
+  fn code_generation(
+    &self,
+    compilation: &Compilation,
+    runtime: Option<&RuntimeSpec>,
+  ) -> Result<CodeGenerationResult> {
+    let module_graph = &compilation.module_graph;
+    let mut names = self.assign_names(compilation)?;
+
+    let mut results = vec![];
+    let mut scopes = IdentifierMap::default();
+    for module_identifier in self.modules.iter() {
+      let module = self.normal_module(module_identifier, module_graph)?;
+      let mut scope = ConcatenationScope::new(
+        *module_identifier,
+        self.modules.clone(),
+        names.remove(module_identifier).unwrap_or_default(),
+      );
+      let result =
+        module.code_generation_in_concatenation_scope(compilation, runtime, &mut scope)?;
+      scopes.insert(*module_identifier, scope);
+      results.push((module, result));
+    }
+
+    let mut code_generation_result = CodeGenerationResult::default();
+    let mut data = CodeGenerationData::default();
+    let mut source = ConcatSource::default();
+
+    // exports of the root module are the exports of the concatenated module
+    let root = self.root_module.identifier();
+    let root_scope = scopes.get(&root).expect("should have root scope");
+    let mut export_map = vec![];
+    for (name, local) in root_scope.exports() {
+      let used_name = if compilation.options.is_new_tree_shaking() {
+        module_graph
+          .get_exports_info(&root)
+          .id
+          .get_used_name(module_graph, runtime, UsedName::Str(name.clone()))
+          .map(|used| match used {
+            UsedName::Str(name) => name,
+            UsedName::Vec(names) => names[0].clone(),
+          })
+      } else if compilation.options.builtins.tree_shaking.is_true() {
+        module_graph
+          .get_exports_info(&root)
+          .old_get_used_exports()
+          .contains(name)
+          .then(|| name.clone())
+      } else {
+        Some(name.clone())
+      };
+      if let Some(used_name) = used_name {
+        export_map.push((used_name, root_scope.get_final_name(local)));
+      }
+    }
+    let inner_runtime_requirements = results
+      .iter()
+      .fold(RuntimeGlobals::empty(), |acc, (_, result)| {
+        acc | result.runtime_requirements
+      });
+    if inner_runtime_requirements.is_empty() && self.is_top_level_entry(compilation) {
+      // the modules are rendered without the module wrapper, exports are declared in the startup
+      data.insert(CodeGenerationDataTopLevelExports::new(export_map));
+    } else {
+      code_generation_result
+        .runtime_requirements
+        .insert(RuntimeGlobals::MAKE_NAMESPACE_OBJECT | RuntimeGlobals::EXPORTS);
+      let exports_argument = self.get_exports_argument();
+      let mut init_fragments: ModuleInitFragments = vec![Box::new(NormalInitFragment::new(
+        format!(
+          "{}({exports_argument});\n",
+          RuntimeGlobals::MAKE_NAMESPACE_OBJECT
+        ),
+        InitFragmentStage::StageHarmonyExports,
+        0,
+        InitFragmentKey::HarmonyCompatibility,
+        None,
+      ))];
+      if !export_map.is_empty() {
+        init_fragments.push(Box::new(HarmonyExportInitFragment::new(
+          exports_argument,
+          export_map,
+        )));
+      }
+      source.add(render_init_fragments(
+        RawSource::from("").boxed(),
+        init_fragments,
+        &mut GenerateContext {
+          compilation,
+          module_generator_options: None,
+          runtime_requirements: &mut code_generation_result.runtime_requirements,
+          data: &mut data,
+          requested_source_type: SourceType::JavaScript,
+          runtime,
+          concatenation_scope: None,
+        },
+      )?);
+    }
+
+    for (module, result) in results {
+      source.add(RawSource::from(format!(
+        "\n;// CONCATENATED MODULE: {}\n",
+        module.readable_identifier(&compilation.options.context)
+      )));
+      code_generation_result
+        .runtime_requirements
+        .insert(result.runtime_requirements);
+      code_generation_result
+        .chunk_init_fragments
+        .extend(result.chunk_init_fragments.iter().cloned());
+      let Some(module_source) = result.get(&SourceType::JavaScript) else {
+        continue;
+      };
+      source.add(self.replace_module_references(module_source, &scopes));
+    }
+
+    code_generation_result.data = data;
+    code_generation_result.add(
+      SourceType::JavaScript,
+      CachedSource::new(source.boxed()).boxed(),
+    );
+    code_generation_result.set_hash(
+      &compilation.options.output.hash_function,
+      &compilation.options.output.hash_digest,
+      &compilation.options.output.hash_salt,
+    );
+    Ok(code_generation_result)
+  }
+
+  fn name_for_condition(&self) -> Option<Box<str>> {
+    self.root_module.name_for_condition()
+  }
+
+  fn update_hash(&self, state: &mut dyn std::hash::Hasher) {
+    self.root_module.update_hash(state);
+    for module in self.modules.iter() {
+      module.dyn_hash(state);
+    }
+  }
+
+  fn lib_ident(&self, options: LibIdentOptions) -> Option<Cow<str>> {
+    self.root_module.lib_ident(options)
+  }
+
+  fn get_resolve_options(&self) -> Option<Box<Resolve>> {
+    self.root_module.get_resolve_options()
+  }
+
+  fn get_context(&self) -> Option<Box<Context>> {
+    self.root_module.get_context()
+  }
+
+  fn chunk_condition(&self, chunk_key: &ChunkUkey, compilation: &Compilation) -> Option<bool> {
+    self.root_module.chunk_condition(chunk_key, compilation)
+  }
+
+  fn get_side_effects_connection_state(
+    &self,
+    module_graph: &ModuleGraph,
+    module_chain: &mut HashSet<ModuleIdentifier>,
+  ) -> ConnectionState {
+    self
+      .root_module
+      .get_side_effects_connection_state(module_graph, module_chain)
+  }
+}
+
+impl ConcatenatedModule {
+  /// Replace the placeholders created by `ConcatenationScope::create_module_reference`
+  /// with the final names of the referenced exports.
+  fn replace_module_references(
+    &self,
+    source: &BoxSource,
+    scopes: &IdentifierMap<ConcatenationScope>,
+  ) -> BoxSource {
+    let code = source.source();
+    let references = ConcatenationScope::match_module_references(&code);
+    if references.is_empty() {
+      return source.clone();
+    }
+    let mut replace_source = ReplaceSource::new(source.clone());
+    for (start, end, index, ids) in references {
+      let final_name = self
+        .modules
+        .get_index(index)
+        .and_then(|module_identifier| scopes.get(module_identifier))
+        .zip(ids.first())
+        .and_then(|(scope, export_name)| {
+          let local = scope.exports().get(export_name)?;
+          Some(format!(
+            "{}{}",
+            scope.get_final_name(local),
+            property_access(&ids, 1)
+          ))
+        })
+        .unwrap_or_else(|| "/* non-existent export */ undefined".to_string());
+      replace_source.replace(start as u32, end as u32, &final_name, None);
+    }
+    replace_source.boxed()
+  }
+}
+
+impl Hash for ConcatenatedModule {
+  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    "__rspack_internal__ConcatenatedModule".hash(state);
+    self.identifier().hash(state);
+    for module in self.modules.iter() {
+      module.hash(state);
+    }
+  }
+}
+
+impl PartialEq for ConcatenatedModule {
+  fn eq(&self, other: &Self) -> bool {
+    self.identifier() == other.identifier()
+  }
+}
+
+impl Eq for ConcatenatedModule {}
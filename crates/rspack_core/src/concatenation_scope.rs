@@ -0,0 +1,128 @@
+use std::{fmt::Write, hash::BuildHasherDefault, sync::Arc};
+
+use indexmap::IndexSet;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use rspack_identifier::IdentifierHasher;
+use rustc_hash::FxHashMap as HashMap;
+use swc_core::ecma::atoms::JsWord;
+
+use crate::ModuleIdentifier;
+
+pub const DEFAULT_EXPORT: &str = "__WEBPACK_DEFAULT_EXPORT__";
+
+pub type ConcatenatedModules = IndexSet<ModuleIdentifier, BuildHasherDefault<IdentifierHasher>>;
+
+static MODULE_REFERENCE_REGEXP: Lazy<Regex> = Lazy::new(|| {
+  Regex::new(r"__WEBPACK_MODULE_REFERENCE__(\d+)_([\da-f]+)__").expect("should be a valid regex")
+});
+
+/// Scope of a module which is generated as a part of a `ConcatenatedModule`.
+///
+/// Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/ConcatenationScope.js
+#[derive(Debug)]
+pub struct ConcatenationScope {
+  current_module: ModuleIdentifier,
+  modules: Arc<ConcatenatedModules>,
+  /// Final names of the top level bindings of the current module.
+  names: HashMap<JsWord, JsWord>,
+  /// Export name -> local name of the current module.
+  exports: HashMap<JsWord, JsWord>,
+}
+
+impl ConcatenationScope {
+  pub fn new(
+    current_module: ModuleIdentifier,
+    modules: Arc<ConcatenatedModules>,
+    names: HashMap<JsWord, JsWord>,
+  ) -> Self {
+    Self {
+      current_module,
+      modules,
+      names,
+      exports: Default::default(),
+    }
+  }
+
+  pub fn current_module(&self) -> &ModuleIdentifier {
+    &self.current_module
+  }
+
+  pub fn is_module_in_scope(&self, module: &ModuleIdentifier) -> bool {
+    self.modules.contains(module)
+  }
+
+  pub fn register_export(&mut self, export_name: JsWord, local: JsWord) {
+    self.exports.entry(export_name).or_insert(local);
+  }
+
+  pub fn exports(&self) -> &HashMap<JsWord, JsWord> {
+    &self.exports
+  }
+
+  /// The name of a top level binding after it's renamed to avoid conflicts.
+  pub fn get_final_name(&self, name: &str) -> JsWord {
+    let name = JsWord::from(name);
+    self.names.get(&name).cloned().unwrap_or(name)
+  }
+
+  /// Create a placeholder referencing the export `ids` of another module in the scope,
+  /// it's replaced with the final name of the export when the concatenated module is rendered.
+  pub fn create_module_reference(&self, module: &ModuleIdentifier, ids: &[JsWord]) -> String {
+    let index = self
+      .modules
+      .get_index_of(module)
+      .expect("should be a module in the concatenation scope");
+    let ids = serde_json::to_string(ids).expect("should serialize ids");
+    let mut hex = String::with_capacity(ids.len() * 2);
+    for byte in ids.bytes() {
+      write!(hex, "{byte:02x}").expect("should write to string");
+    }
+    format!("__WEBPACK_MODULE_REFERENCE__{index}_{hex}__")
+  }
+
+  /// Find all module references created by `create_module_reference` in the code,
+  /// returns `(start, end, module index, ids)`.
+  pub fn match_module_references(code: &str) -> Vec<(usize, usize, usize, Vec<JsWord>)> {
+    MODULE_REFERENCE_REGEXP
+      .captures_iter(code)
+      .filter_map(|captures| {
+        let whole = captures.get(0)?;
+        let index = captures.get(1)?.as_str().parse::<usize>().ok()?;
+        let hex = captures.get(2)?.as_str();
+        let bytes = (0..hex.len())
+          .step_by(2)
+          .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+          .collect::<Option<Vec<_>>>()?;
+        let ids = serde_json::from_slice::<Vec<String>>(&bytes).ok()?;
+        Some((
+          whole.start(),
+          whole.end(),
+          index,
+          ids.into_iter().map(JsWord::from).collect(),
+        ))
+      })
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn module_reference_should_round_trip() {
+    let a = ModuleIdentifier::from("a");
+    let b = ModuleIdentifier::from("b");
+    let modules = Arc::new(ConcatenatedModules::from_iter([a, b]));
+    let scope = ConcatenationScope::new(a, modules, Default::default());
+    let reference = scope.create_module_reference(&b, &["default".into(), "x y".into()]);
+    let code = format!("foo({reference});");
+    let references = ConcatenationScope::match_module_references(&code);
+    assert_eq!(references.len(), 1);
+    let (start, end, index, ids) = &references[0];
+    assert_eq!(&code[*start..*end], reference);
+    assert_eq!(*index, 1);
+    assert_eq!(ids, &vec![JsWord::from("default"), JsWord::from("x y")]);
+  }
+}
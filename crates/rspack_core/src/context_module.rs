@@ -13,18 +13,19 @@ use regex::{Captures, Regex};
 use rspack_error::{impl_empty_diagnosable_trait, miette::IntoDiagnostic, Result};
 use rspack_hash::RspackHash;
 use rspack_identifier::{Identifiable, Identifier};
-use rspack_regex::RspackRegex;
+use rspack_regex::{regexp_as_str, RspackRegex};
 use rspack_sources::{BoxSource, ConcatSource, RawSource, SourceExt};
 use rustc_hash::FxHashMap as HashMap;
 use rustc_hash::FxHashSet as HashSet;
+use swc_core::ecma::atoms::JsWord;
 
 use crate::{
   contextify, get_exports_type_with_strict, impl_build_info_meta, stringify_map, to_path,
   AsyncDependenciesBlock, AsyncDependenciesBlockIdentifier, BoxDependency, BuildContext, BuildInfo,
   BuildMeta, BuildResult, ChunkGraph, ChunkGroupOptions, CodeGenerationResult, Compilation,
   ContextElementDependency, DependenciesBlock, DependencyCategory, DependencyId, ExportsType,
-  FakeNamespaceObjectMode, GroupOptions, LibIdentOptions, Module, ModuleType, Resolve,
-  ResolveInnerOptions, ResolveOptionsWithDependencyType, ResolverFactory, RuntimeGlobals,
+  FakeNamespaceObjectMode, FetchPriority, GroupOptions, LibIdentOptions, Module, ModuleType,
+  Resolve, ResolveInnerOptions, ResolveOptionsWithDependencyType, ResolverFactory, RuntimeGlobals,
   RuntimeSpec, SourceType,
 };
 
@@ -124,12 +125,15 @@ pub struct ContextOptions {
   pub reg_exp: Option<RspackRegex>,
   // TODO: remove `reg_str`
   pub reg_str: String, // generate context module id
-  pub include: Option<String>,
-  pub exclude: Option<String>,
+  pub include: Option<RspackRegex>,
+  pub exclude: Option<RspackRegex>,
   pub category: DependencyCategory,
   pub request: String,
   pub namespace_object: ContextNameSpaceObject,
   pub chunk_name: Option<String>,
+  /// Exports used by the context element modules, from `webpackExports`.
+  pub referenced_exports: Option<Vec<JsWord>>,
+  pub fetch_priority: Option<FetchPriority>,
 }
 
 impl PartialEq for ContextOptions {
@@ -137,11 +141,13 @@ impl PartialEq for ContextOptions {
     self.mode == other.mode
       && self.recursive == other.recursive
       && self.reg_str == other.reg_str
-      && self.include == other.include
-      && self.exclude == other.exclude
+      && self.include.as_ref().map(regexp_as_str) == other.include.as_ref().map(regexp_as_str)
+      && self.exclude.as_ref().map(regexp_as_str) == other.exclude.as_ref().map(regexp_as_str)
       && self.category == other.category
       && self.request == other.request
       && self.namespace_object == other.namespace_object
+      && self.referenced_exports == other.referenced_exports
+      && self.fetch_priority == other.fetch_priority
   }
 }
 
@@ -152,11 +158,13 @@ impl Hash for ContextOptions {
     self.mode.hash(state);
     self.recursive.hash(state);
     self.reg_str.hash(state);
-    self.include.hash(state);
-    self.exclude.hash(state);
+    self.include.as_ref().map(regexp_as_str).hash(state);
+    self.exclude.as_ref().map(regexp_as_str).hash(state);
     self.category.hash(state);
     self.request.hash(state);
     self.namespace_object.hash(state);
+    self.referenced_exports.hash(state);
+    self.fetch_priority.hash(state);
   }
 }
 
@@ -699,6 +707,11 @@ impl ContextModule {
     }
     for entry in fs::read_dir(dir).into_diagnostic()? {
       let path = entry.into_diagnostic()?.path();
+      if let Some(exclude) = &options.context_options.exclude
+        && exclude.test(&path.to_string_lossy())
+      {
+        continue;
+      }
       if path.is_dir() {
         if options.context_options.recursive {
           Self::visit_dirs(ctx, &path, dependencies, options, resolve_options)?;
@@ -709,6 +722,10 @@ impl ContextModule {
       {
         // ignore hidden files
         continue;
+      } else if let Some(include) = &options.context_options.include
+        && !include.test(&path.to_string_lossy())
+      {
+        continue;
       } else {
         // FIXME: nodejs resolver return path of context, sometimes is '/a/b', sometimes is '/a/b/'
         let relative_path = {
@@ -750,7 +767,7 @@ impl ContextModule {
             context: options.resource.clone().into(),
             options: options.context_options.clone(),
             resource_identifier: format!("context{}|{}", &options.resource, path.to_string_lossy()),
-            referenced_exports: None,
+            referenced_exports: options.context_options.referenced_exports.clone(),
           });
         })
       }
@@ -789,9 +806,10 @@ impl ContextModule {
     {
       let name = self.options.context_options.chunk_name.clone();
       let mut block = AsyncDependenciesBlock::new(self.identifier, "", None);
-      block.set_group_options(GroupOptions::ChunkGroup(ChunkGroupOptions::new(
-        name, None, None,
-      )));
+      block.set_group_options(GroupOptions::ChunkGroup(
+        ChunkGroupOptions::new(name, None, None)
+          .fetch_priority_optional(self.options.context_options.fetch_priority),
+      ));
       for context_element_dependency in context_element_dependencies {
         block.add_dependency(Box::new(context_element_dependency));
       }
@@ -823,9 +841,10 @@ impl ContextModule {
           &context_element_dependency.user_request,
           None,
         );
-        block.set_group_options(GroupOptions::ChunkGroup(ChunkGroupOptions::new(
-          name, None, None,
-        )));
+        block.set_group_options(GroupOptions::ChunkGroup(
+          ChunkGroupOptions::new(name, None, None)
+            .fetch_priority_optional(self.options.context_options.fetch_priority),
+        ));
         block.add_dependency(Box::new(context_element_dependency));
         blocks.push(block);
       }
@@ -127,10 +127,11 @@ impl AsyncDependenciesBlock {
     std::mem::take(&mut self.dependencies)
   }
 
-  pub fn add_block(&mut self, _block: AsyncDependenciesBlock) {
-    unimplemented!("Nested block are not implemented");
-    // self.block_ids.push(block.id);
-    // self.blocks.push(block);
+  /// Nested blocks are owned by this block until they are moved into the module graph
+  /// at make stage, the ids are kept so the nesting is preserved afterwards.
+  pub fn add_block(&mut self, block: AsyncDependenciesBlock) {
+    self.block_ids.push(block.id);
+    self.blocks.push(block);
   }
 
   pub fn take_blocks(&mut self) -> Vec<AsyncDependenciesBlock> {
@@ -143,9 +144,8 @@ impl AsyncDependenciesBlock {
 }
 
 impl DependenciesBlock for AsyncDependenciesBlock {
-  fn add_block_id(&mut self, _block: AsyncDependenciesBlockIdentifier) {
-    unimplemented!("Nested block are not implemented");
-    // self.block_ids.push(block);
+  fn add_block_id(&mut self, block: AsyncDependenciesBlockIdentifier) {
+    self.block_ids.push(block)
   }
 
   fn get_blocks(&self) -> &[AsyncDependenciesBlockIdentifier] {
@@ -22,6 +22,8 @@ pub enum DependencyType {
   DynamicImport,
   // import() eager
   DynamicImportEager,
+  // import() weak
+  DynamicImportWeak,
   // cjs require
   CjsRequire,
   // cjs full require
@@ -50,6 +52,8 @@ pub enum DependencyType {
   CssImport,
   // css modules compose
   CssCompose,
+  // css modules @value import
+  CssValueImport,
   // context element
   ContextElement,
   // import context
@@ -62,6 +66,10 @@ pub enum DependencyType {
   RequireContext,
   // require.resolve
   RequireResolve,
+  // require.ensure
+  RequireEnsure,
+  // require.ensure item
+  RequireEnsureItem,
   /// wasm import
   WasmImport,
   /// wasm export import
@@ -86,6 +94,8 @@ pub enum DependencyType {
   ConsumeSharedFallback,
   /// Webpack is included
   WebpackIsIncluded,
+  /// this.importModule() in loaders
+  LoaderImport,
   Custom(Box<str>), // TODO it will increase large layout size
 }
 
@@ -114,13 +124,17 @@ impl DependencyType {
       DependencyType::CssUrl => Cow::Borrowed("css url"),
       DependencyType::CssImport => Cow::Borrowed("css import"),
       DependencyType::CssCompose => Cow::Borrowed("css compose"),
+      DependencyType::CssValueImport => Cow::Borrowed("css value import"),
       DependencyType::ContextElement => Cow::Borrowed("context element"),
       // TODO: mode
       DependencyType::ImportContext => Cow::Borrowed("import context"),
       DependencyType::DynamicImportEager => Cow::Borrowed("import() eager"),
+      DependencyType::DynamicImportWeak => Cow::Borrowed("import() weak"),
       DependencyType::CommonJSRequireContext => Cow::Borrowed("commonjs require context"),
       DependencyType::RequireContext => Cow::Borrowed("require.context"),
       DependencyType::RequireResolve => Cow::Borrowed("require.resolve"),
+      DependencyType::RequireEnsure => Cow::Borrowed("require.ensure"),
+      DependencyType::RequireEnsureItem => Cow::Borrowed("require.ensure item"),
       DependencyType::WasmImport => Cow::Borrowed("wasm import"),
       DependencyType::WasmExportImported => Cow::Borrowed("wasm export imported"),
       DependencyType::StaticExports => Cow::Borrowed("static exports"),
@@ -137,6 +151,7 @@ impl DependencyType {
       DependencyType::ProvideModuleForShared => Cow::Borrowed("provide module for shared"),
       DependencyType::ConsumeSharedFallback => Cow::Borrowed("consume shared fallback"),
       DependencyType::WebpackIsIncluded => Cow::Borrowed("__webpack_is_included__"),
+      DependencyType::LoaderImport => Cow::Borrowed("loader import"),
     }
   }
 }
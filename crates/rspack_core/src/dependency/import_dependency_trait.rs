@@ -12,7 +12,10 @@ pub trait ImportDependencyTrait: ModuleDependency {
     _runtime: Option<&RuntimeSpec>,
   ) -> Vec<ExtendedReferencedExport> {
     if let Some(referenced_exports) = self.referenced_exports() {
-      vec![ReferencedExport::new(referenced_exports.clone(), false).into()]
+      referenced_exports
+        .iter()
+        .map(|name| ReferencedExport::new(vec![name.clone()], false).into())
+        .collect()
     } else {
       vec![ExtendedReferencedExport::Array(vec![])]
     }
@@ -0,0 +1,60 @@
+use crate::{
+  AsContextDependency, AsDependencyTemplate, Context, Dependency, DependencyCategory, DependencyId,
+  DependencyType, ModuleDependency,
+};
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+pub struct LoaderImportDependency {
+  id: DependencyId,
+  request: String,
+  context: Context,
+}
+
+impl LoaderImportDependency {
+  pub fn new(request: String, context: Context) -> Self {
+    Self {
+      request,
+      context,
+      id: DependencyId::new(),
+    }
+  }
+}
+
+impl Dependency for LoaderImportDependency {
+  fn dependency_debug_name(&self) -> &'static str {
+    "LoaderImportDependency"
+  }
+
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Esm
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::LoaderImport
+  }
+
+  fn get_context(&self) -> Option<&Context> {
+    Some(&self.context)
+  }
+}
+
+impl ModuleDependency for LoaderImportDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request;
+  }
+}
+
+impl AsDependencyTemplate for LoaderImportDependency {}
+impl AsContextDependency for LoaderImportDependency {}
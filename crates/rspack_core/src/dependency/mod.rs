@@ -9,6 +9,7 @@ mod dependency_trait;
 mod dependency_type;
 mod entry;
 mod import_dependency_trait;
+mod loader_import;
 mod module_dependency;
 mod runtime_requirements_dependency;
 mod runtime_template;
@@ -27,6 +28,7 @@ pub use dependency_trait::*;
 pub use dependency_type::DependencyType;
 pub use entry::*;
 pub use import_dependency_trait::ImportDependencyTrait;
+pub use loader_import::*;
 pub use module_dependency::*;
 pub use runtime_requirements_dependency::RuntimeRequirementsDependency;
 pub use runtime_template::*;
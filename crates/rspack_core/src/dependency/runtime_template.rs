@@ -371,6 +371,18 @@ pub fn block_promise(
   let key = block.block_promise_key(compilation);
   runtime_requirements.insert(RuntimeGlobals::ENSURE_CHUNK);
   runtime_requirements.insert(RuntimeGlobals::LOAD_CHUNK_WITH_BLOCK);
+  if let Some(fetch_priority) = block
+    .get_group_options()
+    .and_then(|options| options.normal_options())
+    .and_then(|options| options.fetch_priority)
+  {
+    runtime_requirements.insert(RuntimeGlobals::HAS_FETCH_PRIORITY);
+    return format!(
+      "{}({key}, \"{}\")",
+      RuntimeGlobals::LOAD_CHUNK_WITH_BLOCK,
+      fetch_priority.as_str()
+    );
+  }
   format!("{}({key})", RuntimeGlobals::LOAD_CHUNK_WITH_BLOCK)
 }
 
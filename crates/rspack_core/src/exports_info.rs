@@ -565,6 +565,90 @@ impl ExportsInfo {
     false
   }
 
+  // https://github.com/webpack/webpack/blob/1f99ad6367f2b8a6ef17cce0e058f7a67fb7db18/lib/ExportsInfo.js#L530-L571
+  pub fn get_provided_exports(&self, mg: &ModuleGraph) -> ProvidedExports {
+    if self.redirect_to.is_none() {
+      match mg.get_export_info_by_id(&self.other_exports_info).provided {
+        None => return ProvidedExports::Null,
+        Some(ExportInfoProvided::True | ExportInfoProvided::Null) => return ProvidedExports::True,
+        Some(ExportInfoProvided::False) => {}
+      }
+    }
+    let mut exports = vec![];
+    for export_info_id in self.get_ordered_exports() {
+      let export_info = mg.get_export_info_by_id(export_info_id);
+      if !matches!(export_info.provided, Some(ExportInfoProvided::False))
+        && let Some(name) = &export_info.name
+      {
+        exports.push(name.clone());
+      }
+    }
+    if let Some(redirect_to) = self.redirect_to
+      && let ProvidedExports::Vec(redirected) =
+        redirect_to.get_exports_info(mg).get_provided_exports(mg)
+    {
+      for name in redirected {
+        if !exports.contains(&name) {
+          exports.push(name);
+        }
+      }
+    }
+    exports.sort_unstable_by(|a, b| a.as_ref().cmp(b.as_ref()));
+    ProvidedExports::Vec(exports)
+  }
+
+  // https://github.com/webpack/webpack/blob/1f99ad6367f2b8a6ef17cce0e058f7a67fb7db18/lib/ExportsInfo.js#L573-L619
+  pub fn get_used_exports(&self, runtime: Option<&RuntimeSpec>, mg: &ModuleGraph) -> UsedExports {
+    if self.redirect_to.is_none() {
+      match mg
+        .get_export_info_by_id(&self.other_exports_info)
+        .get_used(runtime)
+      {
+        UsageState::NoInfo => return UsedExports::Null,
+        UsageState::Unknown | UsageState::OnlyPropertiesUsed | UsageState::Used => {
+          return UsedExports::Bool(true)
+        }
+        UsageState::Unused => {}
+      }
+    }
+    let mut exports = vec![];
+    for export_info_id in self.get_ordered_exports() {
+      let export_info = mg.get_export_info_by_id(export_info_id);
+      match export_info.get_used(runtime) {
+        UsageState::NoInfo => return UsedExports::Null,
+        UsageState::Unknown => return UsedExports::Bool(true),
+        UsageState::OnlyPropertiesUsed | UsageState::Used => {
+          if let Some(name) = &export_info.name {
+            exports.push(name.clone());
+          }
+        }
+        UsageState::Unused => {}
+      }
+    }
+    if let Some(redirect_to) = self.redirect_to {
+      match redirect_to
+        .get_exports_info(mg)
+        .get_used_exports(runtime, mg)
+      {
+        UsedExports::Vec(redirected) => exports.extend(redirected),
+        UsedExports::Bool(false) => {}
+        other => return other,
+      }
+    }
+    if exports.is_empty() {
+      match mg
+        .get_export_info_by_id(&self._side_effects_only_info)
+        .get_used(runtime)
+      {
+        UsageState::NoInfo => return UsedExports::Null,
+        UsageState::Unused => return UsedExports::Bool(false),
+        _ => {}
+      }
+    }
+    exports.sort_unstable_by(|a, b| a.as_ref().cmp(b.as_ref()));
+    UsedExports::Vec(exports)
+  }
+
   pub fn get_ordered_exports(&self) -> impl Iterator<Item = &ExportInfoId> {
     // TODO need order
     self.exports.values()
@@ -1422,6 +1506,24 @@ impl ExportInfo {
   }
 }
 
+/// The exports provided by a module, `Null` means there is no provide info and `True` means
+/// the exports are unknown, e.g. the module is a CommonJS module with dynamic exports.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProvidedExports {
+  Null,
+  True,
+  Vec(Vec<JsWord>),
+}
+
+/// The exports of a module used in a runtime, `Null` means there is no usage info and
+/// `Bool(true)` means the module is used in an unknown way.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UsedExports {
+  Null,
+  Bool(bool),
+  Vec(Vec<JsWord>),
+}
+
 #[derive(Debug, PartialEq, Copy, Clone, Default, Hash, PartialOrd, Ord, Eq)]
 pub enum UsageState {
   Unused = 0,
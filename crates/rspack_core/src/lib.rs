@@ -18,6 +18,7 @@ pub use template::Template;
 mod module_profile;
 pub use module_profile::*;
 use rspack_database::Database;
+use serde::{Deserialize, Serialize};
 pub mod external_module;
 pub use external_module::*;
 mod logger;
@@ -85,6 +86,10 @@ pub use loader::*;
 // pub use external::*;
 mod chunk_group;
 pub use chunk_group::*;
+mod concatenation_scope;
+pub use concatenation_scope::*;
+mod concatenated_module;
+pub use concatenated_module::*;
 mod ukey;
 pub use ukey::*;
 mod module_graph_module;
@@ -99,7 +104,7 @@ pub use rspack_sources;
 #[cfg(debug_assertions)]
 pub mod debug_info;
 
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SourceType {
   JavaScript,
   Css,
@@ -4,21 +4,23 @@ use std::path::PathBuf;
 use std::{any::Any, borrow::Cow, fmt::Debug};
 
 use async_trait::async_trait;
+use indexmap::IndexMap;
 use json::JsonValue;
 use rspack_error::{Diagnosable, Result};
 use rspack_hash::{RspackHash, RspackHashDigest};
 use rspack_identifier::{Identifiable, Identifier};
 use rspack_sources::Source;
-use rspack_util::ext::{AsAny, DynEq, DynHash};
+use rspack_util::ext::{AsAny, DynEq, DynHash, IntoAny};
 use rustc_hash::FxHashSet as HashSet;
 use swc_core::ecma::atoms::JsWord;
 
 use crate::tree_shaking::visitor::OptimizeAnalyzeResult;
 use crate::{
   AsyncDependenciesBlock, BoxDependency, ChunkUkey, CodeGenerationResult, Compilation,
-  CompilerContext, CompilerOptions, ConnectionState, Context, ContextModule, DependenciesBlock,
-  DependencyId, DependencyTemplate, ExternalModule, ModuleDependency, ModuleGraph, ModuleType,
-  NormalModule, RawModule, Resolve, RuntimeSpec, SelfModule, SharedPluginDriver, SourceType,
+  CompilerContext, CompilerOptions, ConcatenatedModule, ConnectionState, Context, ContextModule,
+  DependenciesBlock, DependencyId, DependencyTemplate, ExternalModule, ModuleDependency,
+  ModuleGraph, ModuleType, NormalModule, RawModule, Resolve, RuntimeSpec, SelfModule,
+  SharedPluginDriver, SourceType,
 };
 
 pub struct BuildContext<'a> {
@@ -49,6 +51,26 @@ pub struct BuildInfo {
   pub all_star_exports: Vec<DependencyId>,
   pub need_create_require: bool,
   pub json_data: Option<JsonValue>,
+  /// Top level bindings declared by the module, only collected when `optimization.concatenateModules` is enabled.
+  pub top_level_declarations: Option<HashSet<JsWord>>,
+  /// Occurrences of the top level bindings, used to rename them when the module is concatenated.
+  pub top_level_references: Vec<TopLevelReference>,
+  /// Names of free variables and nested bindings, renamed top level bindings of other modules
+  /// in the same concatenated module should never collide with them.
+  pub used_names: HashSet<JsWord>,
+  pub module_concatenation_bailout: Option<String>,
+  /// Values defined by `@value` and `:export` of a css modules module, as `name -> (value, None)`,
+  /// and imported by `@value`, as `name -> (imported name, Some(request))`.
+  pub css_modules_values: Option<IndexMap<String, (String, Option<String>)>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TopLevelReference {
+  pub name: JsWord,
+  pub start: u32,
+  pub end: u32,
+  /// `{ a }` in object literals and patterns, rename should keep the key.
+  pub shorthand: bool,
 }
 
 #[derive(Debug, Default, Clone, Hash, PartialEq, Eq)]
@@ -141,7 +163,16 @@ pub type ModuleIdentifier = Identifier;
 
 #[async_trait]
 pub trait Module:
-  Debug + Send + Sync + AsAny + DynHash + DynEq + Identifiable + DependenciesBlock + Diagnosable
+  Debug
+  + Send
+  + Sync
+  + AsAny
+  + IntoAny
+  + DynHash
+  + DynEq
+  + Identifiable
+  + DependenciesBlock
+  + Diagnosable
 {
   /// Defines what kind of module this is.
   fn module_type(&self) -> &ModuleType;
@@ -428,6 +459,7 @@ impl_module_downcast_helpers!(RawModule, raw_module);
 impl_module_downcast_helpers!(ContextModule, context_module);
 impl_module_downcast_helpers!(ExternalModule, external_module);
 impl_module_downcast_helpers!(SelfModule, self_module);
+impl_module_downcast_helpers!(ConcatenatedModule, concatenated_module);
 
 pub struct LibIdentOptions<'me> {
   pub context: &'me str,
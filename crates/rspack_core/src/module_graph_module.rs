@@ -28,6 +28,8 @@ pub struct ModuleGraphModule {
   pub exports: ExportsInfoId,
   pub profile: Option<Box<ModuleProfile>>,
   pub is_async: bool,
+  /// Reasons why optimizations like module concatenation bailed out on this module
+  pub optimization_bailout: Vec<String>,
 }
 
 impl ModuleGraphModule {
@@ -51,6 +53,7 @@ impl ModuleGraphModule {
       exports: exports_info_id,
       profile: None,
       is_async: false,
+      optimization_bailout: vec![],
     }
   }
 
@@ -26,11 +26,11 @@ use serde_json::json;
 use crate::{
   add_connection_states, contextify, get_context, impl_build_info_meta,
   AsyncDependenciesBlockIdentifier, BoxLoader, BoxModule, BuildContext, BuildInfo, BuildMeta,
-  BuildResult, CodeGenerationResult, Compilation, CompilerOptions, ConnectionState, Context,
-  DependenciesBlock, DependencyId, DependencyTemplate, GenerateContext, GeneratorOptions,
-  LibIdentOptions, Module, ModuleDependency, ModuleGraph, ModuleIdentifier, ModuleType,
-  ParseContext, ParseResult, ParserAndGenerator, ParserOptions, Resolve, RspackLoaderRunnerPlugin,
-  RuntimeSpec, SourceType,
+  BuildResult, CodeGenerationResult, Compilation, CompilerOptions, ConcatenationScope,
+  ConnectionState, Context, DependenciesBlock, DependencyId, DependencyTemplate, GenerateContext,
+  GeneratorOptions, LibIdentOptions, Module, ModuleDependency, ModuleGraph, ModuleIdentifier,
+  ModuleType, ParseContext, ParseResult, ParserAndGenerator, ParserOptions, Resolve,
+  RspackLoaderRunnerPlugin, RuntimeSpec, SourceType,
 };
 
 bitflags! {
@@ -444,53 +444,7 @@ impl Module for NormalModule {
     compilation: &Compilation,
     runtime: Option<&RuntimeSpec>,
   ) -> Result<CodeGenerationResult> {
-    if let NormalModuleSource::BuiltSucceed(source) = &self.source {
-      let mut code_generation_result = CodeGenerationResult::default();
-      for source_type in self.source_types() {
-        let generation_result = self.parser_and_generator.generate(
-          source,
-          self,
-          &mut GenerateContext {
-            compilation,
-            module_generator_options: self.generator_options.as_ref(),
-            runtime_requirements: &mut code_generation_result.runtime_requirements,
-            data: &mut code_generation_result.data,
-            requested_source_type: *source_type,
-            runtime,
-          },
-        )?;
-        code_generation_result.add(*source_type, CachedSource::new(generation_result).boxed());
-      }
-      code_generation_result.set_hash(
-        &compilation.options.output.hash_function,
-        &compilation.options.output.hash_digest,
-        &compilation.options.output.hash_salt,
-      );
-      Ok(code_generation_result)
-    } else if let NormalModuleSource::BuiltFailed(error_message) = &self.source {
-      let mut code_generation_result = CodeGenerationResult::default();
-
-      // If the module build failed and the module is able to emit JavaScript source,
-      // we should emit an error message to the runtime, otherwise we do nothing.
-      if self.source_types().contains(&SourceType::JavaScript) {
-        let error = error_message.render_report(compilation.options.stats.colors)?;
-        code_generation_result.add(
-          SourceType::JavaScript,
-          RawSource::from(format!("throw new Error({});\n", json!(error))).boxed(),
-        );
-      }
-      code_generation_result.set_hash(
-        &compilation.options.output.hash_function,
-        &compilation.options.output.hash_digest,
-        &compilation.options.output.hash_salt,
-      );
-      Ok(code_generation_result)
-    } else {
-      Err(error!(
-        "Failed to generate code because ast or source is not set for module {}",
-        self.request
-      ))
-    }
+    self.generate(compilation, runtime, None)
   }
 
   fn name_for_condition(&self) -> Option<Box<str>> {
@@ -614,6 +568,75 @@ impl PartialEq for NormalModule {
 impl Eq for NormalModule {}
 
 impl NormalModule {
+  /// Generate the module as a part of a concatenated module, top level bindings are renamed
+  /// by `concatenation_scope` and references to modules in the scope are left as placeholders.
+  pub fn code_generation_in_concatenation_scope(
+    &self,
+    compilation: &Compilation,
+    runtime: Option<&RuntimeSpec>,
+    concatenation_scope: &mut ConcatenationScope,
+  ) -> Result<CodeGenerationResult> {
+    self.generate(compilation, runtime, Some(concatenation_scope))
+  }
+
+  // the scope is reborrowed for every source type
+  #[allow(clippy::needless_option_as_deref)]
+  fn generate(
+    &self,
+    compilation: &Compilation,
+    runtime: Option<&RuntimeSpec>,
+    mut concatenation_scope: Option<&mut ConcatenationScope>,
+  ) -> Result<CodeGenerationResult> {
+    if let NormalModuleSource::BuiltSucceed(source) = &self.source {
+      let mut code_generation_result = CodeGenerationResult::default();
+      for source_type in self.source_types() {
+        let generation_result = self.parser_and_generator.generate(
+          source,
+          self,
+          &mut GenerateContext {
+            compilation,
+            module_generator_options: self.generator_options.as_ref(),
+            runtime_requirements: &mut code_generation_result.runtime_requirements,
+            data: &mut code_generation_result.data,
+            requested_source_type: *source_type,
+            runtime,
+            concatenation_scope: concatenation_scope.as_deref_mut(),
+          },
+        )?;
+        code_generation_result.add(*source_type, CachedSource::new(generation_result).boxed());
+      }
+      code_generation_result.set_hash(
+        &compilation.options.output.hash_function,
+        &compilation.options.output.hash_digest,
+        &compilation.options.output.hash_salt,
+      );
+      Ok(code_generation_result)
+    } else if let NormalModuleSource::BuiltFailed(error_message) = &self.source {
+      let mut code_generation_result = CodeGenerationResult::default();
+
+      // If the module build failed and the module is able to emit JavaScript source,
+      // we should emit an error message to the runtime, otherwise we do nothing.
+      if self.source_types().contains(&SourceType::JavaScript) {
+        let error = error_message.render_report(compilation.options.stats.colors)?;
+        code_generation_result.add(
+          SourceType::JavaScript,
+          RawSource::from(format!("throw new Error({});\n", json!(error))).boxed(),
+        );
+      }
+      code_generation_result.set_hash(
+        &compilation.options.output.hash_function,
+        &compilation.options.output.hash_digest,
+        &compilation.options.output.hash_salt,
+      );
+      Ok(code_generation_result)
+    } else {
+      Err(error!(
+        "Failed to generate code because ast or source is not set for module {}",
+        self.request
+      ))
+    }
+  }
+
   fn create_source(&self, content: Content, source_map: Option<SourceMap>) -> Result<BoxSource> {
     if content.is_buffer() {
       return Ok(RawSource::Buffer(content.into_bytes()).boxed());
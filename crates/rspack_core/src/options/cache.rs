@@ -4,6 +4,8 @@ pub struct MemoryCacheOptions {
   pub max_generations: u32,
 }
 
+/// Resolve, code generation and minify results are persisted into packs under `cache_location`,
+/// module build results are only cached in memory.
 #[derive(Debug, Clone, Default)]
 pub struct FileSystemCacheOptions {
   // Collect unused memory allocated during deserialization
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use crate::{
   Builtins, CacheOptions, Context, DevServerOptions, Devtool, Experiments,
   IncrementalRebuildMakeState, Mode, ModuleOptions, NodeOption, Optimization, OutputOptions,
@@ -25,6 +27,8 @@ pub struct CompilerOptions {
   pub profile: bool,
   pub bail: bool,
   pub builtins: Builtins,
+  pub records_input_path: Option<PathBuf>,
+  pub records_output_path: Option<PathBuf>,
 }
 
 impl CompilerOptions {
@@ -40,6 +44,10 @@ impl CompilerOptions {
     self.experiments.incremental_rebuild.emit_asset
   }
 
+  pub fn is_records_enabled(&self) -> bool {
+    self.records_input_path.is_some() || self.records_output_path.is_some()
+  }
+
   pub fn is_new_tree_shaking(&self) -> bool {
     self.experiments.rspack_future.new_treeshaking
   }
@@ -37,3 +37,5 @@ mod experiments;
 pub use experiments::*;
 mod node;
 pub use node::*;
+mod watch;
+pub use watch::*;
@@ -88,7 +88,9 @@ pub enum ChunkLoadingType {
   Require,
   AsyncNode,
   Import,
-  // TODO: Custom
+  /// Chunk loading type registered by a plugin, e.g. `CustomChunkLoadingPlugin`, which adds
+  /// the runtime module for the chunks using it, see `is_enabled_for_chunk`.
+  Custom(String),
 }
 
 impl From<&str> for ChunkLoadingType {
@@ -99,12 +101,12 @@ impl From<&str> for ChunkLoadingType {
       "require" => Self::Require,
       "async-node" => Self::AsyncNode,
       "import" => Self::Import,
-      _ => unimplemented!("custom chunkLoading in not supported yet"),
+      _ => Self::Custom(value.to_string()),
     }
   }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum WasmLoading {
   Enable(WasmLoadingType),
   Disable,
@@ -119,11 +121,13 @@ impl From<&str> for WasmLoading {
   }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum WasmLoadingType {
   Fetch,
   AsyncNode,
   AsyncNodeModule,
+  /// Wasm loading type registered by a plugin, e.g. `CustomWasmLoadingPlugin`.
+  Custom(String),
 }
 
 impl From<&str> for WasmLoadingType {
@@ -132,7 +136,7 @@ impl From<&str> for WasmLoadingType {
       "fetch" => Self::Fetch,
       "async-node" => Self::AsyncNode,
       "async-node-module" => Self::AsyncNodeModule,
-      _ => todo!(),
+      _ => Self::Custom(value.to_string()),
     }
   }
 }
@@ -2,8 +2,9 @@ use hashlink::LinkedHashMap;
 
 use super::value_type::{GetValueType, ValueType};
 use super::{
-  Alias, BrowserField, ConditionNames, ExportsField, ExtensionAlias, Extensions, Fallback,
-  FullySpecified, MainFields, MainFiles, Modules, PreferRelative, Symlink, TsconfigOptions,
+  Alias, AliasFields, BrowserField, ConditionNames, DescriptionFiles, ExportsField, ExtensionAlias,
+  Extensions, Fallback, FullySpecified, ImportsField, MainFields, MainFiles, Modules,
+  PreferRelative, Restrictions, Roots, Symlink, TsconfigOptions,
 };
 use super::{ByDependency, DependencyCategoryStr, Resolve};
 
@@ -32,6 +33,11 @@ fn is_empty(resolve: &Resolve) -> bool {
     && is_none!(exports_field)
     && is_none!(extension_alias)
     && is_none!(tsconfig)
+    && is_none!(imports_field)
+    && is_none!(alias_fields)
+    && is_none!(restrictions)
+    && is_none!(roots)
+    && is_none!(description_files)
     && is_none!(by_dependency)
 }
 
@@ -57,6 +63,11 @@ struct ResolveWithEntry {
   fully_specified: Entry<FullySpecified>,
   exports_field: Entry<ExportsField>,
   extension_alias: Entry<ExtensionAlias>,
+  imports_field: Entry<ImportsField>,
+  alias_fields: Entry<AliasFields>,
+  restrictions: Entry<Restrictions>,
+  roots: Entry<Roots>,
+  description_files: Entry<DescriptionFiles>,
 }
 
 fn parse_resolve(resolve: Resolve) -> ResolveWithEntry {
@@ -83,6 +94,11 @@ fn parse_resolve(resolve: Resolve) -> ResolveWithEntry {
     fully_specified: entry!(fully_specified),
     exports_field: entry!(exports_field),
     extension_alias: entry!(extension_alias),
+    imports_field: entry!(imports_field),
+    alias_fields: entry!(alias_fields),
+    restrictions: entry!(restrictions),
+    roots: entry!(roots),
+    description_files: entry!(description_files),
   };
   let Some(by_dependency) = resolve.by_dependency else {
     return res;
@@ -125,6 +141,11 @@ fn parse_resolve(resolve: Resolve) -> ResolveWithEntry {
   update_by_value!(exports_field);
   update_by_value!(extension_alias);
   update_by_value!(tsconfig);
+  update_by_value!(imports_field);
+  update_by_value!(alias_fields);
+  update_by_value!(restrictions);
+  update_by_value!(roots);
+  update_by_value!(description_files);
 
   res
 }
@@ -297,6 +318,26 @@ fn _merge_resolve(first: Resolve, second: Resolve) -> Resolve {
     extension_alias: merge!(extension_alias, ValueType::Other, |_| false, |a, b| {
       extend_extension_alias(a, b)
     }),
+    imports_field: merge!(imports_field, ValueType::Other, |_| false, |_, b| b),
+    alias_fields: merge!(alias_fields, ValueType::Other, |_| false, |_, b| b),
+    restrictions: merge!(
+      restrictions,
+      second.restrictions.base.get_value_type(),
+      need_merge_base,
+      |a, b| normalize_string_array(a, b)
+    ),
+    roots: merge!(
+      roots,
+      second.roots.base.get_value_type(),
+      need_merge_base,
+      |a, b| normalize_string_array(a, b)
+    ),
+    description_files: merge!(
+      description_files,
+      second.description_files.base.get_value_type(),
+      need_merge_base,
+      |a, b| normalize_string_array(a, b)
+    ),
   };
 
   let mut by_dependency: LinkedHashMap<DependencyCategoryStr, Resolve> = LinkedHashMap::new();
@@ -327,6 +368,11 @@ fn _merge_resolve(first: Resolve, second: Resolve) -> Resolve {
   setup_by_values!(fully_specified);
   setup_by_values!(exports_field);
   setup_by_values!(extension_alias);
+  setup_by_values!(imports_field);
+  setup_by_values!(alias_fields);
+  setup_by_values!(restrictions);
+  setup_by_values!(roots);
+  setup_by_values!(description_files);
 
   macro_rules! to_resolve {
     ($ident: ident) => {
@@ -354,6 +400,11 @@ fn _merge_resolve(first: Resolve, second: Resolve) -> Resolve {
   to_resolve!(fully_specified);
   to_resolve!(exports_field);
   to_resolve!(extension_alias);
+  to_resolve!(imports_field);
+  to_resolve!(alias_fields);
+  to_resolve!(restrictions);
+  to_resolve!(roots);
+  to_resolve!(description_files);
 
   let by_dependency = if by_dependency.iter().all(|(_, by_value)| is_empty(by_value)) {
     None
@@ -377,6 +428,11 @@ fn _merge_resolve(first: Resolve, second: Resolve) -> Resolve {
     fully_specified: result_entry.fully_specified.base,
     exports_field: result_entry.exports_field.base,
     extension_alias: result_entry.extension_alias.base,
+    imports_field: result_entry.imports_field.base,
+    alias_fields: result_entry.alias_fields.base,
+    restrictions: result_entry.restrictions.base,
+    roots: result_entry.roots.base,
+    description_files: result_entry.description_files.base,
   }
 }
 
@@ -2187,4 +2243,45 @@ mod test {
       }
     )
   }
+
+  #[test]
+  fn test_merge_resolver_options_19() {
+    let first = Resolve {
+      roots: string_list(&["/a"]),
+      description_files: string_list(&["package.json"]),
+      imports_field: Some(vec![vec!["imports".to_string()]]),
+      by_dependency: Some(ByDependency::from_iter([(
+        "esm".into(),
+        Resolve {
+          restrictions: string_list(&["/src"]),
+          ..Default::default()
+        },
+      )])),
+      ..Default::default()
+    };
+    let second = Resolve {
+      roots: string_list(&["/b", "..."]),
+      alias_fields: Some(vec![vec!["browser".to_string()]]),
+      imports_field: Some(vec![vec!["internal".to_string()]]),
+      ..Default::default()
+    };
+
+    pretty_assertions::assert_eq!(
+      merge_resolve(first, second),
+      Resolve {
+        roots: string_list(&["/b", "/a"]),
+        description_files: string_list(&["package.json"]),
+        imports_field: Some(vec![vec!["internal".to_string()]]),
+        alias_fields: Some(vec![vec!["browser".to_string()]]),
+        by_dependency: Some(ByDependency::from_iter([(
+          "esm".into(),
+          Resolve {
+            restrictions: string_list(&["/src"]),
+            ..Default::default()
+          },
+        )])),
+        ..Default::default()
+      }
+    )
+  }
 }
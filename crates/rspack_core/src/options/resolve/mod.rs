@@ -22,6 +22,11 @@ pub(super) type FullySpecified = bool;
 pub(super) type ExportsField = Vec<Vec<String>>;
 pub(super) type ExtensionAlias = Vec<(String, Vec<String>)>;
 pub(super) type Modules = Vec<String>;
+pub(super) type ImportsField = Vec<Vec<String>>;
+pub(super) type AliasFields = Vec<Vec<String>>;
+pub(super) type Restrictions = Vec<String>;
+pub(super) type Roots = Vec<String>;
+pub(super) type DescriptionFiles = Vec<String>;
 
 #[derive(Debug, Clone, Default, Hash, PartialEq, Eq)]
 pub struct Resolve {
@@ -66,6 +71,23 @@ pub struct Resolve {
   /// A list map ext to another.
   /// Default is `[]`
   pub extension_alias: Option<ExtensionAlias>,
+  /// A list of imports fields in descriptions files, used to resolve
+  /// the `#internal` specifiers of a package.
+  /// Default is `[["imports"]]`.
+  pub imports_field: Option<ImportsField>,
+  /// A list of alias fields in descriptions files.
+  /// Takes precedence over `browser_field` when it is set.
+  pub alias_fields: Option<AliasFields>,
+  /// A list of absolute paths, the resolved result must be in one of them.
+  /// Default is `[]`
+  pub restrictions: Option<Restrictions>,
+  /// A list of directories where server-relative requests
+  /// (starting with `/`) are resolved.
+  /// Default is `[]`
+  pub roots: Option<Roots>,
+  /// The description files to read for each directory.
+  /// Default is `["package.json"]`.
+  pub description_files: Option<DescriptionFiles>,
   pub by_dependency: Option<ByDependency>,
 }
 
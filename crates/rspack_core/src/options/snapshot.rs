@@ -1,21 +1,119 @@
+use std::path::{Path, PathBuf};
+
+use rspack_regex::RspackRegex;
+
 #[derive(Debug, Clone, Default)]
 pub struct SnapshotStrategy {
   pub hash: bool,
   pub timestamp: bool,
 }
 
+impl SnapshotStrategy {
+  pub fn is_empty(&self) -> bool {
+    !self.hash && !self.timestamp
+  }
+}
+
+#[derive(Debug, Clone)]
+pub enum SnapshotPath {
+  String(String),
+  Regexp(RspackRegex),
+}
+
+impl SnapshotPath {
+  /// Returns the matched directory prefix of `path`.
+  ///
+  /// For regexp, the whole match is used as the directory, e.g. `/^(.+?[\\/]node_modules[\\/])/`.
+  pub fn matched_directory<'a>(&self, path: &'a str) -> Option<&'a str> {
+    match self {
+      Self::String(s) => path.starts_with(s.as_str()).then(|| &path[..s.len()]),
+      Self::Regexp(r) => r.find(path).map(|range| &path[..range.end]),
+    }
+  }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct SnapshotOptions {
-  // Snapshots for resolving of build dependencies when using the persistent cache.
-  // pub resolve_build_dependencies: SnapshotStrategy,
-  // Snapshots for build dependencies when using the persistent cache.
-  // pub build_dependencies: SnapshotStrategy,
+  /// Snapshots for resolving of build dependencies when using the persistent cache.
+  pub resolve_build_dependencies: SnapshotStrategy,
+  /// Snapshots for build dependencies when using the persistent cache.
+  pub build_dependencies: SnapshotStrategy,
   /// Snapshots for resolving of requests.
   pub resolve: SnapshotStrategy,
   /// Snapshots for building modules.
   pub module: SnapshotStrategy,
-  // An array of paths that are managed by a package manager and contain a version or a hash in their paths.
-  // immutable_paths: Vec<String>,
-  // An array of paths that are managed by a package manager.
-  // managed_paths: Vec<String>,
+  /// An array of paths that are managed by a package manager and contain a version or a hash in their paths.
+  pub immutable_paths: Vec<SnapshotPath>,
+  /// An array of paths that are managed by a package manager.
+  pub managed_paths: Vec<SnapshotPath>,
+}
+
+impl SnapshotOptions {
+  pub fn is_immutable_path(&self, path: &Path) -> bool {
+    let path = path.to_string_lossy();
+    self
+      .immutable_paths
+      .iter()
+      .any(|p| p.matched_directory(&path).is_some())
+  }
+
+  /// Returns the package directory when `path` is located in a managed path,
+  /// e.g. `/project/node_modules/@scope/pkg` for `/project/node_modules/@scope/pkg/lib/index.js`.
+  pub fn managed_item(&self, path: &Path) -> Option<PathBuf> {
+    let path_str = path.to_string_lossy();
+    self.managed_paths.iter().find_map(|p| {
+      let dir = p.matched_directory(&path_str)?;
+      let rest = &path_str[dir.len()..];
+      let mut segments = rest.split(['/', '\\']).filter(|s| !s.is_empty());
+      let first = segments.next()?;
+      let package = if first.starts_with('@') {
+        format!("{first}/{}", segments.next()?)
+      } else {
+        first.to_string()
+      };
+      // the package directory itself is snapshotted as a normal directory
+      segments.next()?;
+      Some(Path::new(dir).join(package))
+    })
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn options() -> SnapshotOptions {
+    SnapshotOptions {
+      immutable_paths: vec![SnapshotPath::String("/store/".into())],
+      managed_paths: vec![SnapshotPath::Regexp(
+        RspackRegex::new(r"^(.+?[\\/]node_modules[\\/])").expect("should be valid regex"),
+      )],
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn managed_item() {
+    let options = options();
+    assert_eq!(
+      options.managed_item(Path::new("/app/node_modules/react/index.js")),
+      Some(PathBuf::from("/app/node_modules/react"))
+    );
+    assert_eq!(
+      options.managed_item(Path::new("/app/node_modules/@scope/pkg/lib/index.js")),
+      Some(PathBuf::from("/app/node_modules/@scope/pkg"))
+    );
+    assert_eq!(
+      options.managed_item(Path::new("/app/node_modules/react")),
+      None
+    );
+    assert_eq!(options.managed_item(Path::new("/app/src/index.js")), None);
+  }
+
+  #[test]
+  fn immutable_path() {
+    let options = options();
+    assert!(options.is_immutable_path(Path::new("/store/react@18/index.js")));
+    assert!(!options.is_immutable_path(Path::new("/app/src/index.js")));
+  }
 }
@@ -1,4 +1,128 @@
-#[derive(Debug, Default)]
+/// The presets of `stats`, the JavaScript side resolves the preset names and passes the
+/// resulting toggles, these are used for the defaults of [StatsOptions].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StatsPreset {
+  None,
+  ErrorsOnly,
+  ErrorsWarnings,
+  Minimal,
+  #[default]
+  Normal,
+  Detailed,
+  Verbose,
+}
+
+/// Controls which parts of [crate::Stats] are computed, the defaults are the same as the
+/// `normal` preset of `stats.toJson()`.
+#[derive(Debug, Clone)]
 pub struct StatsOptions {
   pub colors: bool,
+  pub modules: bool,
+  pub reasons: bool,
+  pub module_assets: bool,
+  pub nested_modules: bool,
+  pub source: bool,
+  pub chunk_modules: bool,
+  pub chunk_relations: bool,
+  pub chunk_origins: bool,
+  pub ids: bool,
+  pub used_exports: bool,
+  pub provided_exports: bool,
+  pub optimization_bailout: bool,
+  pub depth: bool,
+  pub module_trace: bool,
+}
+
+impl Default for StatsOptions {
+  fn default() -> Self {
+    Self::from_preset(StatsPreset::Normal)
+  }
+}
+
+impl StatsOptions {
+  pub fn from_preset(preset: StatsPreset) -> Self {
+    let none = Self {
+      colors: false,
+      modules: false,
+      reasons: false,
+      module_assets: false,
+      nested_modules: false,
+      source: false,
+      chunk_modules: false,
+      chunk_relations: false,
+      chunk_origins: false,
+      ids: false,
+      used_exports: false,
+      provided_exports: false,
+      optimization_bailout: false,
+      depth: false,
+      module_trace: false,
+    };
+    let normal = Self {
+      modules: true,
+      reasons: true,
+      module_assets: true,
+      nested_modules: true,
+      chunk_modules: true,
+      chunk_relations: true,
+      ids: true,
+      optimization_bailout: true,
+      ..none.clone()
+    };
+    match preset {
+      StatsPreset::None => none,
+      StatsPreset::ErrorsOnly | StatsPreset::ErrorsWarnings => Self {
+        module_trace: true,
+        ..none
+      },
+      StatsPreset::Minimal => Self {
+        modules: true,
+        ..none
+      },
+      StatsPreset::Normal => normal,
+      StatsPreset::Detailed => Self {
+        chunk_modules: false,
+        chunk_origins: true,
+        used_exports: true,
+        provided_exports: true,
+        depth: true,
+        module_trace: true,
+        ..normal
+      },
+      StatsPreset::Verbose => Self {
+        chunk_origins: true,
+        used_exports: true,
+        provided_exports: true,
+        depth: true,
+        module_trace: true,
+        ..normal
+      },
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_from_preset() {
+    let normal = StatsOptions::default();
+    assert!(normal.modules && normal.reasons && normal.chunk_modules);
+    assert!(!normal.chunk_origins && !normal.module_trace);
+
+    let errors_only = StatsOptions::from_preset(StatsPreset::ErrorsOnly);
+    assert!(errors_only.module_trace);
+    assert!(!errors_only.modules && !errors_only.reasons);
+
+    let detailed = StatsOptions::from_preset(StatsPreset::Detailed);
+    assert!(detailed.chunk_origins && detailed.depth && detailed.module_trace);
+    assert!(!detailed.chunk_modules);
+
+    let verbose = StatsOptions::from_preset(StatsPreset::Verbose);
+    assert!(verbose.chunk_modules && verbose.used_exports && verbose.provided_exports);
+
+    let none = StatsOptions::from_preset(StatsPreset::None);
+    assert!(!none.modules && !none.ids && !none.module_trace);
+  }
 }
@@ -0,0 +1,49 @@
+use std::path::Path;
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct WatchOptions {
+  /// Delay the rebuild after the first change, changes in this period are aggregated into one rebuild.
+  pub aggregate_timeout: Duration,
+  /// Glob patterns of the paths that should not be watched, e.g. `**/node_modules/**`.
+  pub ignored: Vec<String>,
+  /// Check the file system for changes with the interval instead of relying on native events,
+  /// useful for network file systems where native events are not available.
+  pub poll: Option<Duration>,
+}
+
+impl Default for WatchOptions {
+  fn default() -> Self {
+    Self {
+      aggregate_timeout: Duration::from_millis(20),
+      ignored: vec![],
+      poll: None,
+    }
+  }
+}
+
+impl WatchOptions {
+  pub fn is_ignored(&self, path: &Path) -> bool {
+    let path = path.to_string_lossy().replace('\\', "/");
+    self
+      .ignored
+      .iter()
+      .any(|pattern| glob_match::glob_match(pattern, &path))
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn ignored() {
+    let options = WatchOptions {
+      ignored: vec!["**/node_modules/**".into(), "**/*.log".into()],
+      ..Default::default()
+    };
+    assert!(options.is_ignored(Path::new("/app/node_modules/react/index.js")));
+    assert!(options.is_ignored(Path::new("/app/debug.log")));
+    assert!(!options.is_ignored(Path::new("/app/src/index.js")));
+  }
+}
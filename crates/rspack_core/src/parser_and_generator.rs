@@ -9,8 +9,8 @@ use rspack_sources::BoxSource;
 use crate::{
   tree_shaking::visitor::OptimizeAnalyzeResult, AsyncDependenciesBlock, BoxDependency, BoxLoader,
   BuildExtraDataType, BuildInfo, BuildMeta, CodeGenerationData, Compilation, CompilerOptions,
-  DependencyTemplate, GeneratorOptions, Module, ModuleDependency, ModuleIdentifier, ModuleType,
-  ParserOptions, RuntimeGlobals, RuntimeSpec, SourceType,
+  ConcatenationScope, DependencyTemplate, GeneratorOptions, Module, ModuleDependency,
+  ModuleIdentifier, ModuleType, ParserOptions, RuntimeGlobals, RuntimeSpec, SourceType,
 };
 
 #[derive(Derivative)]
@@ -48,6 +48,8 @@ pub struct GenerateContext<'a> {
   pub data: &'a mut CodeGenerationData,
   pub requested_source_type: SourceType,
   pub runtime: Option<&'a RuntimeSpec>,
+  /// Some when the module is generated as a part of a concatenated module.
+  pub concatenation_scope: Option<&'a mut ConcatenationScope>,
 }
 
 pub trait ParserAndGenerator: Send + Sync + Debug {
@@ -13,9 +13,9 @@ use crate::{
   FactorizeArgs, JsChunkHashArgs, LoaderRunnerContext, MakeParam, Module, ModuleFactoryResult,
   ModuleIdentifier, ModuleType, NormalModule, NormalModuleAfterResolveArgs,
   NormalModuleBeforeResolveArgs, NormalModuleCreateData, OptimizeChunksArgs, ParserAndGenerator,
-  PluginContext, ProcessAssetsArgs, RenderArgs, RenderChunkArgs, RenderManifestArgs,
-  RenderModuleContentArgs, RenderStartupArgs, Resolver, RuntimeRequirementsInTreeArgs, SourceType,
-  ThisCompilationArgs,
+  PluginContext, ProcessAssetsArgs, ProvidedSharedModule, RenderArgs, RenderChunkArgs,
+  RenderManifestArgs, RenderModuleContentArgs, RenderStartupArgs, Resolver,
+  RuntimeRequirementsInTreeArgs, SourceType, ThisCompilationArgs,
 };
 
 // use anyhow::{Context, Result};
@@ -516,7 +516,16 @@ pub trait Plugin: Debug + Send + Sync {
     Ok(())
   }
 
-  fn execute_module(
+  // Stats hook, modules provided to a share scope are reported in the `shared` section
+  fn stats_shared(
+    &self,
+    _compilation: &Compilation,
+    _shared: &mut Vec<ProvidedSharedModule>,
+  ) -> Result<()> {
+    Ok(())
+  }
+
+  async fn execute_module(
     &self,
     _entry: ModuleIdentifier,
     _runtime_modules: Vec<ModuleIdentifier>,
@@ -24,8 +24,9 @@ use crate::{
   PluginProcessAssetsOutput, PluginRenderChunkHookOutput, PluginRenderHookOutput,
   PluginRenderManifestHookOutput, PluginRenderModuleContentOutput, PluginRenderStartupHookOutput,
   PluginRuntimeRequirementsInTreeOutput, PluginThisCompilationHookOutput, ProcessAssetsArgs,
-  RenderArgs, RenderChunkArgs, RenderManifestArgs, RenderModuleContentArgs, RenderStartupArgs,
-  Resolver, ResolverFactory, RuntimeRequirementsInTreeArgs, Stats, ThisCompilationArgs,
+  ProvidedSharedModule, RenderArgs, RenderChunkArgs, RenderManifestArgs, RenderModuleContentArgs,
+  RenderStartupArgs, Resolver, ResolverFactory, RuntimeRequirementsInTreeArgs, Stats,
+  ThisCompilationArgs,
 };
 
 pub struct PluginDriver {
@@ -709,6 +710,14 @@ impl PluginDriver {
     Ok(())
   }
 
+  pub fn stats_shared(&self, compilation: &Compilation) -> Result<Vec<ProvidedSharedModule>> {
+    let mut shared = vec![];
+    for plugin in &self.plugins {
+      plugin.stats_shared(compilation, &mut shared)?;
+    }
+    Ok(shared)
+  }
+
   pub async fn should_emit(&self, compilation: &mut Compilation) -> Result<bool> {
     let mut res = true;
     for plugin in &self.plugins {
@@ -752,15 +761,16 @@ impl PluginDriver {
   }
 
   #[instrument(name = "plugin:execute_module", skip_all)]
-  pub fn execute_module(
+  pub async fn execute_module(
     &self,
     entry: ModuleIdentifier,
     runtime_modules: Vec<ModuleIdentifier>,
     codegen_results: &CodeGenerationResults,
   ) -> Result<Option<String>> {
     for plugin in &self.plugins {
-      if let Some(exports) =
-        plugin.execute_module(entry, runtime_modules.clone(), codegen_results)?
+      if let Some(exports) = plugin
+        .execute_module(entry, runtime_modules.clone(), codegen_results)
+        .await?
       {
         return Ok(Some(exports));
       }
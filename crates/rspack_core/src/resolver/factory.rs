@@ -1,9 +1,16 @@
-use std::{hash::BuildHasherDefault, sync::Arc};
+use std::{
+  hash::BuildHasherDefault,
+  path::{Path, PathBuf},
+  sync::Arc,
+};
 
 use dashmap::DashMap;
-use rustc_hash::FxHasher;
+use rustc_hash::{FxHashSet as HashSet, FxHasher};
+use serde_json::Value;
 
-use super::resolver_impl::Resolver;
+use super::file_system::CachedFileSystem;
+use super::resolver_impl::{ResolveContext, ResolveInnerError, Resolver};
+use super::ResolveResult;
 use crate::{DependencyCategory, Resolve};
 
 #[derive(Debug, Hash, Eq, PartialEq, Clone)]
@@ -14,13 +21,49 @@ pub struct ResolveOptionsWithDependencyType {
   pub dependency_category: DependencyCategory,
 }
 
+#[derive(Debug, Hash, Eq, PartialEq)]
+struct ResolveCacheKey {
+  options: ResolveOptionsWithDependencyType,
+  context: PathBuf,
+  request: String,
+}
+
+#[derive(Debug)]
+struct ResolveCacheEntry {
+  result: ResolveResult,
+  /// The files and directories touched by the resolution, found or not.
+  context: ResolveContext,
+}
+
+impl ResolveCacheEntry {
+  /// Whether one of `modified_files` is a dependency of the resolution or one of its ancestor directories,
+  /// or has been created inside one of its missing dependencies.
+  fn is_affected_by(&self, modified_files: &HashSet<PathBuf>) -> bool {
+    let file_affected = self.context.file_dependencies.iter().any(|dependency| {
+      modified_files
+        .iter()
+        .any(|modified| dependency.starts_with(modified))
+    });
+    file_affected
+      || self.context.missing_dependencies.iter().any(|dependency| {
+        modified_files
+          .iter()
+          .any(|modified| dependency.starts_with(modified) || modified.starts_with(dependency))
+      })
+  }
+}
+
 #[derive(Debug)]
 pub struct ResolverFactory {
   base_options: Resolve,
+  /// The file system shared by all resolvers, kept across rebuilds except for the modified paths.
+  file_system: CachedFileSystem,
   resolver: Resolver,
   /// Different resolvers are used for different resolution strategies such as ESM and CJS.
   /// All resolvers share the same underlying cache.
   resolvers: DashMap<ResolveOptionsWithDependencyType, Arc<Resolver>, BuildHasherDefault<FxHasher>>,
+  /// Successful resolutions, kept across rebuilds until one of their dependencies is modified.
+  entries: DashMap<ResolveCacheKey, ResolveCacheEntry, BuildHasherDefault<FxHasher>>,
 }
 
 impl Default for ResolverFactory {
@@ -31,14 +74,62 @@ impl Default for ResolverFactory {
 
 impl ResolverFactory {
   pub fn clear_cache(&self) {
+    self.file_system.clear();
     self.resolver.clear_cache();
+    self.entries.clear();
+  }
+
+  /// Remove the cached resolutions affected by `modified_files`, the others are kept for the next compilation.
+  pub fn clear_entries_affected_by(&self, modified_files: &HashSet<PathBuf>) {
+    if modified_files.is_empty() {
+      return;
+    }
+    // Paths mapped by tsconfig are not tracked in the dependencies of the resolutions
+    if self
+      .tsconfig_files()
+      .iter()
+      .any(|file| modified_files.contains(file))
+    {
+      self.clear_cache();
+      return;
+    }
+    self
+      .entries
+      .retain(|_, entry| !entry.is_affected_by(modified_files));
+    self.file_system.invalidate(modified_files);
+    // The resolvers can only drop their path cache as a whole,
+    // it is filled again from the file system cache above.
+    self.resolver.clear_cache();
+  }
+
+  /// The tsconfig files used by the resolvers, including the ones they extend.
+  fn tsconfig_files(&self) -> HashSet<PathBuf> {
+    let mut files = HashSet::default();
+    let config_files = self
+      .resolvers
+      .iter()
+      .filter_map(|resolver| resolver.options().tsconfig().map(Path::to_path_buf))
+      .chain(self.resolver.options().tsconfig().map(Path::to_path_buf))
+      .collect::<Vec<_>>();
+    for config_file in config_files {
+      let config_file = if config_file.is_dir() {
+        config_file.join("tsconfig.json")
+      } else {
+        config_file
+      };
+      collect_tsconfig_files(config_file, &mut files);
+    }
+    files
   }
 
   pub fn new(options: Resolve) -> Self {
+    let file_system = CachedFileSystem::default();
     Self {
       base_options: options.clone(),
-      resolver: Resolver::new(options),
+      resolver: Resolver::new(options, file_system.clone()),
+      file_system,
       resolvers: Default::default(),
+      entries: Default::default(),
     }
   }
 
@@ -56,4 +147,175 @@ impl ResolverFactory {
       resolver
     }
   }
+
+  /// Resolve `request` in `context` with the resolver for `options`.
+  /// Successful results are cached together with their file and missing dependencies.
+  pub fn resolve(
+    &self,
+    options: ResolveOptionsWithDependencyType,
+    context: &Path,
+    request: &str,
+    resolve_context: &mut ResolveContext,
+  ) -> Result<ResolveResult, ResolveInnerError> {
+    let key = ResolveCacheKey {
+      options,
+      context: context.to_path_buf(),
+      request: request.to_string(),
+    };
+    if let Some(entry) = self.entries.get(&key) {
+      resolve_context
+        .file_dependencies
+        .extend(entry.context.file_dependencies.iter().cloned());
+      resolve_context
+        .missing_dependencies
+        .extend(entry.context.missing_dependencies.iter().cloned());
+      return Ok(entry.result.clone());
+    }
+
+    let resolver = self.get(key.options.clone());
+    let mut entry_context = ResolveContext::default();
+    let result = resolver.resolve_with_context(context, request, &mut entry_context);
+    resolve_context
+      .file_dependencies
+      .extend(entry_context.file_dependencies.iter().cloned());
+    resolve_context
+      .missing_dependencies
+      .extend(entry_context.missing_dependencies.iter().cloned());
+    if let Ok(result) = &result {
+      self.entries.insert(
+        key,
+        ResolveCacheEntry {
+          result: result.clone(),
+          context: entry_context,
+        },
+      );
+    }
+    result
+  }
+}
+
+/// Add `config_file` and the tsconfig files it extends to `files`.
+fn collect_tsconfig_files(config_file: PathBuf, files: &mut HashSet<PathBuf>) {
+  if files.contains(&config_file) {
+    return;
+  }
+  files.insert(config_file.clone());
+  let Ok(mut content) = std::fs::read_to_string(&config_file) else {
+    return;
+  };
+  if json_strip_comments::strip(&mut content).is_err() {
+    return;
+  }
+  let Ok(json) = serde_json::from_str::<Value>(&content) else {
+    return;
+  };
+  let extends = match json.get("extends") {
+    Some(Value::String(extends)) => vec![extends.as_str()],
+    Some(Value::Array(extends)) => extends.iter().filter_map(Value::as_str).collect(),
+    _ => return,
+  };
+  let Some(directory) = config_file.parent() else {
+    return;
+  };
+  for specifier in extends {
+    if let Some(extended) = resolve_tsconfig_extends(directory, specifier) {
+      collect_tsconfig_files(extended, files);
+    }
+  }
+}
+
+/// Locate an extended tsconfig the way TypeScript does, relative paths first and packages in `node_modules` otherwise.
+fn resolve_tsconfig_extends(directory: &Path, specifier: &str) -> Option<PathBuf> {
+  let with_json_extension = |path: PathBuf| {
+    if specifier.ends_with(".json") {
+      path
+    } else {
+      let mut path = path.into_os_string();
+      path.push(".json");
+      PathBuf::from(path)
+    }
+  };
+  if specifier.starts_with('.') || Path::new(specifier).is_absolute() {
+    return Some(with_json_extension(directory.join(specifier)));
+  }
+  directory.ancestors().find_map(|ancestor| {
+    let path = ancestor.join("node_modules").join(specifier);
+    if path.is_dir() {
+      Some(path.join("tsconfig.json"))
+    } else {
+      let path = with_json_extension(path);
+      path.is_file().then_some(path)
+    }
+  })
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn affected_by() {
+    let entry = ResolveCacheEntry {
+      result: ResolveResult::Ignored,
+      context: ResolveContext {
+        file_dependencies: HashSet::from_iter([
+          PathBuf::from("/app/node_modules/react/package.json"),
+          PathBuf::from("/app/node_modules/react/index.js"),
+        ]),
+        missing_dependencies: HashSet::from_iter([PathBuf::from("/app/src/node_modules")]),
+      },
+    };
+    let affected_by = |path: &str| entry.is_affected_by(&HashSet::from_iter([PathBuf::from(path)]));
+    assert!(affected_by("/app/node_modules/react/package.json"));
+    assert!(affected_by("/app/node_modules/react"));
+    assert!(affected_by("/app/src/node_modules"));
+    assert!(affected_by("/app/src/node_modules/react/index.js"));
+    assert!(!affected_by("/app/src/index.js"));
+    assert!(!affected_by("/app/node_modules/react-dom/package.json"));
+  }
+
+  #[test]
+  fn rebuild() {
+    let root = std::env::temp_dir().join(format!("rspack-resolver-factory-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    std::fs::create_dir_all(root.join("src")).unwrap();
+    std::fs::create_dir_all(root.join("node_modules/lib")).unwrap();
+    std::fs::write(root.join("src/other.js"), "").unwrap();
+    std::fs::write(root.join("node_modules/lib/index.js"), "").unwrap();
+    let root = std::fs::canonicalize(root).unwrap();
+    let context = root.join("src");
+
+    let factory = ResolverFactory::new(Resolve {
+      extensions: Some(vec![".js".to_string()]),
+      ..Default::default()
+    });
+    let resolve = |request: &str| {
+      let options = ResolveOptionsWithDependencyType {
+        resolve_options: None,
+        resolve_to_context: false,
+        dependency_category: DependencyCategory::Esm,
+      };
+      match factory.resolve(options, &context, request, &mut Default::default()) {
+        Ok(ResolveResult::Resource(resource)) => resource.path,
+        result => panic!("unexpected resolution of {request}: {result:?}"),
+      }
+    };
+    assert_eq!(resolve("lib"), root.join("node_modules/lib/index.js"));
+    assert_eq!(resolve("./other"), root.join("src/other.js"));
+
+    // Shadow `lib` with a new file closer to the context
+    std::fs::create_dir_all(root.join("src/node_modules/lib")).unwrap();
+    std::fs::write(root.join("src/node_modules/lib/index.js"), "").unwrap();
+    factory.clear_entries_affected_by(&HashSet::from_iter([
+      root.join("src/node_modules/lib/index.js")
+    ]));
+    assert_eq!(factory.entries.len(), 1);
+
+    // The unaffected resolution is reused without touching the file system
+    std::fs::remove_file(root.join("src/other.js")).unwrap();
+    assert_eq!(resolve("./other"), root.join("src/other.js"));
+    assert_eq!(resolve("lib"), root.join("src/node_modules/lib/index.js"));
+
+    std::fs::remove_dir_all(&root).unwrap();
+  }
 }
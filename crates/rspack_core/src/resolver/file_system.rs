@@ -0,0 +1,107 @@
+use std::{
+  fmt,
+  hash::BuildHasherDefault,
+  io,
+  path::{Path, PathBuf},
+  sync::Arc,
+};
+
+use dashmap::DashMap;
+use oxc_resolver::{FileMetadata, FileSystem, FileSystemOs};
+use rustc_hash::{FxHashSet as HashSet, FxHasher};
+
+type PathMap<V> = DashMap<PathBuf, V, BuildHasherDefault<FxHasher>>;
+
+/// File system used by the resolvers, the results are kept across rebuilds
+/// until [CachedFileSystem::invalidate] is called with a related path.
+///
+/// Clones share the same cache.
+#[derive(Debug, Default, Clone)]
+pub struct CachedFileSystem {
+  inner: Arc<Inner>,
+}
+
+#[derive(Default)]
+struct Inner {
+  fs: FileSystemOs,
+  metadata: PathMap<Result<FileMetadata, io::ErrorKind>>,
+  symlink_metadata: PathMap<Result<FileMetadata, io::ErrorKind>>,
+  contents: PathMap<Result<String, io::ErrorKind>>,
+  canonicalized: PathMap<Result<PathBuf, io::ErrorKind>>,
+}
+
+impl fmt::Debug for Inner {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    f.debug_struct("Inner")
+      .field("metadata", &self.metadata.len())
+      .field("symlink_metadata", &self.symlink_metadata.len())
+      .field("contents", &self.contents.len())
+      .field("canonicalized", &self.canonicalized.len())
+      .finish()
+  }
+}
+
+impl CachedFileSystem {
+  /// Remove the cached results of `modified_files`, of the files inside them
+  /// and of their ancestor directories, which may have been created or removed with them.
+  pub fn invalidate(&self, modified_files: &HashSet<PathBuf>) {
+    let is_affected = |path: &PathBuf| {
+      modified_files
+        .iter()
+        .any(|modified| path.starts_with(modified) || modified.starts_with(path))
+    };
+    let inner = &self.inner;
+    inner.metadata.retain(|path, _| !is_affected(path));
+    inner.symlink_metadata.retain(|path, _| !is_affected(path));
+    inner.contents.retain(|path, _| !is_affected(path));
+    inner.canonicalized.retain(|path, _| !is_affected(path));
+  }
+
+  pub fn clear(&self) {
+    let inner = &self.inner;
+    inner.metadata.clear();
+    inner.symlink_metadata.clear();
+    inner.contents.clear();
+    inner.canonicalized.clear();
+  }
+}
+
+fn cached<V: Clone>(
+  map: &PathMap<Result<V, io::ErrorKind>>,
+  path: &Path,
+  f: impl FnOnce() -> io::Result<V>,
+) -> io::Result<V> {
+  if let Some(result) = map.get(path) {
+    return result.clone().map_err(io::Error::from);
+  }
+  let result = f();
+  map.insert(
+    path.to_path_buf(),
+    result.as_ref().map_err(|error| error.kind()).cloned(),
+  );
+  result
+}
+
+impl FileSystem for CachedFileSystem {
+  fn read_to_string(&self, path: &Path) -> io::Result<String> {
+    let inner = &self.inner;
+    cached(&inner.contents, path, || inner.fs.read_to_string(path))
+  }
+
+  fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+    let inner = &self.inner;
+    cached(&inner.metadata, path, || inner.fs.metadata(path))
+  }
+
+  fn symlink_metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+    let inner = &self.inner;
+    cached(&inner.symlink_metadata, path, || {
+      inner.fs.symlink_metadata(path)
+    })
+  }
+
+  fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+    let inner = &self.inner;
+    cached(&inner.canonicalized, path, || inner.fs.canonicalize(path))
+  }
+}
@@ -1,4 +1,5 @@
 mod factory;
+mod file_system;
 mod resolver_impl;
 
 use std::{fmt, path::PathBuf};
@@ -70,9 +71,9 @@ pub async fn resolve(
   let base_dir = base_dir.as_ref();
 
   let mut context = Default::default();
-  let resolver = plugin_driver.resolver_factory.get(dep);
-  let result = resolver
-    .resolve_with_context(base_dir, args.specifier, &mut context)
+  let result = plugin_driver
+    .resolver_factory
+    .resolve(dep, base_dir, args.specifier, &mut context)
     .map_err(|error| error.into_resolve_error(&args));
 
   args.file_dependencies.extend(context.file_dependencies);
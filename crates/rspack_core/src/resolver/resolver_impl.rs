@@ -10,7 +10,7 @@ use rspack_error::{
 use rspack_loader_runner::DescriptionData;
 use rustc_hash::FxHashSet as HashSet;
 
-use super::{ResolveResult, Resource};
+use super::{file_system::CachedFileSystem, ResolveResult, Resource};
 use crate::{AliasMap, DependencyCategory, Resolve, ResolveArgs, ResolveOptionsWithDependencyType};
 
 #[derive(Debug, Default, Clone)]
@@ -69,6 +69,12 @@ impl<'a> ResolveInnerOptions<'a> {
       Self::OxcResolver(options) => options.modules.iter(),
     }
   }
+
+  pub fn tsconfig(&self) -> Option<&Path> {
+    match self {
+      Self::OxcResolver(options) => options.tsconfig.as_ref().map(|c| c.config_file.as_path()),
+    }
+  }
 }
 
 /// Proxy to [oxc_resolver::Resolver]
@@ -76,17 +82,17 @@ impl<'a> ResolveInnerOptions<'a> {
 /// Internal caches are shared.
 #[derive(Debug)]
 pub enum Resolver {
-  OxcResolver(oxc_resolver::Resolver),
+  OxcResolver(oxc_resolver::ResolverGeneric<CachedFileSystem>),
 }
 
 impl Resolver {
-  pub fn new(options: Resolve) -> Self {
-    Self::new_oxc_resolver(options)
+  pub fn new(options: Resolve, file_system: CachedFileSystem) -> Self {
+    Self::new_oxc_resolver(options, file_system)
   }
 
-  fn new_oxc_resolver(options: Resolve) -> Self {
+  fn new_oxc_resolver(options: Resolve, file_system: CachedFileSystem) -> Self {
     let options = to_oxc_resolver_options(options, false, DependencyCategory::Unknown);
-    let resolver = oxc_resolver::Resolver::new(options);
+    let resolver = oxc_resolver::ResolverGeneric::new_with_file_system(file_system, options);
     Self::OxcResolver(resolver)
   }
 
@@ -191,7 +197,9 @@ fn to_oxc_resolver_options(
   let options = options.merge_by_dependency(dependency_type);
   let tsconfig = options.tsconfig.map(|c| c.into());
   let enforce_extension = oxc_resolver::EnforceExtension::Auto;
-  let description_files = vec!["package.json".to_string()];
+  let description_files = options
+    .description_files
+    .unwrap_or_else(|| vec!["package.json".to_string()]);
   let extensions = options.extensions.expect("should have extensions");
   let alias = options
     .alias
@@ -216,14 +224,13 @@ fn to_oxc_resolver_options(
   let main_fields = options
     .main_fields
     .unwrap_or_else(|| vec![String::from("module"), String::from("main")]);
-  let alias_fields = (if options.browser_field.unwrap_or(true) {
-    vec!["browser".to_string()]
-  } else {
-    vec![]
-  })
-  .into_iter()
-  .map(|x| vec![x])
-  .collect();
+  let alias_fields = options.alias_fields.unwrap_or_else(|| {
+    if options.browser_field.unwrap_or(true) {
+      vec![vec!["browser".to_string()]]
+    } else {
+      vec![]
+    }
+  });
   let condition_names = options
     .condition_names
     .unwrap_or_else(|| vec!["module".to_string(), "import".to_string()]);
@@ -249,7 +256,22 @@ fn to_oxc_resolver_options(
   let exports_fields = options
     .exports_field
     .unwrap_or_else(|| vec![vec!["exports".to_string()]]);
+  let imports_fields = options
+    .imports_field
+    .unwrap_or_else(|| vec![vec!["imports".to_string()]]);
   let extension_alias = options.extension_alias.unwrap_or_default();
+  let restrictions = options
+    .restrictions
+    .unwrap_or_default()
+    .into_iter()
+    .map(|path| oxc_resolver::Restriction::Path(PathBuf::from(path)))
+    .collect();
+  let roots = options
+    .roots
+    .unwrap_or_default()
+    .into_iter()
+    .map(PathBuf::from)
+    .collect();
   oxc_resolver::ResolveOptions {
     fallback,
     modules,
@@ -267,11 +289,12 @@ fn to_oxc_resolver_options(
     resolve_to_context,
     fully_specified,
     exports_fields,
+    imports_fields,
     extension_alias,
+    restrictions,
+    roots,
     // not supported by rspack yet
     prefer_absolute: false,
-    restrictions: vec![],
-    roots: vec![],
     builtin_modules: false,
   }
 }
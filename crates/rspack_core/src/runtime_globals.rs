@@ -245,6 +245,16 @@ bitflags! {
     const PRELOAD_CHUNK = 1 << 60;
 
     const PRELOAD_CHUNK_HANDLERS = 1 << 61;
+
+    /**
+     * chunks are loaded with the fetch priority from `webpackFetchPriority`
+     */
+    const HAS_FETCH_PRIORITY = 1 << 62;
+
+    /**
+     * object with all WebAssembly.instance exports
+     */
+    const WASM_INSTANCES = 1 << 63;
   }
 }
 
@@ -327,12 +337,19 @@ impl RuntimeGlobals {
       R::PREFETCH_CHUNK_HANDLERS => "__webpack_require__.F",
       R::PRELOAD_CHUNK => "__webpack_require__.G",
       R::PRELOAD_CHUNK_HANDLERS => "__webpack_require__.H",
+      R::HAS_FETCH_PRIORITY => "has fetch priority",
+      R::WASM_INSTANCES => "__webpack_require__.w",
       r => panic!(
         "Unexpected flag `{r:?}`. RuntimeGlobals should only be printed for one single flag."
       ),
     }
   }
 
+  /// The single flag printed as `name`, e.g. `__webpack_require__.p` for `PUBLIC_PATH`.
+  pub fn from_name(name: &str) -> Option<Self> {
+    Self::all().iter().find(|flag| flag.name() == name)
+  }
+
   /// A stub function for bitflags `iter` in 2.0.0, we are stuck to 1.3.0 now
   pub fn iter(&self) -> impl Iterator<Item = Self> {
     let mut bit = 0;
@@ -377,6 +394,22 @@ mod test {
     assert_eq!(format!("{flags}"), "__webpack_require__.k");
   }
 
+  #[test]
+  fn test_from_name() {
+    assert_eq!(
+      RuntimeGlobals::from_name("__webpack_require__.p"),
+      Some(RuntimeGlobals::PUBLIC_PATH)
+    );
+    assert_eq!(
+      RuntimeGlobals::from_name("__webpack_require__.m (add only)"),
+      Some(RuntimeGlobals::MODULE_FACTORIES_ADD_ONLY)
+    );
+    assert_eq!(
+      RuntimeGlobals::from_name("__webpack_require__.unknown"),
+      None
+    );
+  }
+
   #[test]
   #[should_panic]
   fn test_panic_when_print_multiple_flags() {
@@ -1,12 +1,16 @@
+use std::collections::VecDeque;
+
 use either::Either;
 use itertools::Itertools;
 use rspack_error::emitter::{DiagnosticDisplay, DiagnosticDisplayer};
 use rspack_error::emitter::{StdioDiagnosticDisplay, StringDiagnosticDisplay};
 use rspack_error::Result;
+use rspack_identifier::{IdentifierMap, IdentifierSet};
 use rspack_sources::Source;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
 use crate::{get_chunk_from_ukey, get_chunk_group_from_ukey};
+use crate::{merge_runtime, ProvidedExports, RuntimeSpec, StatsOptions, UsedExports};
 use crate::{BoxModule, BoxRuntimeModule, Chunk};
 use crate::{ChunkGroupUkey, Compilation, LogType, ModuleIdentifier, ModuleType, SourceType};
 
@@ -125,42 +129,31 @@ impl Stats<'_> {
     (assets, assets_by_chunk_name)
   }
 
-  pub fn get_modules(
-    &self,
-    reasons: bool,
-    module_assets: bool,
-    nested_modules: bool,
-    source: bool,
-  ) -> Result<Vec<StatsModule>> {
+  pub fn get_modules(&self, options: &StatsOptions) -> Result<Vec<StatsModule>> {
+    if !options.modules {
+      return Ok(vec![]);
+    }
+    let depths = options.depth.then(|| self.get_module_depths());
     let mut modules: Vec<StatsModule> = self
       .compilation
       .module_graph
       .modules()
       .values()
-      .map(|module| self.get_module(module, reasons, module_assets, nested_modules, source))
+      .map(|module| self.get_module(module, options, depths.as_ref()))
       .chain(
         self
           .compilation
           .runtime_modules
           .iter()
-          .map(|(identifier, module)| {
-            self.get_runtime_module(identifier, module, reasons, module_assets)
-          }),
+          .map(|(identifier, module)| self.get_runtime_module(identifier, module, options)),
       )
       .collect::<Result<_>>()?;
     Self::sort_modules(&mut modules);
     Ok(modules)
   }
 
-  pub fn get_chunks(
-    &self,
-    chunk_modules: bool,
-    chunk_relations: bool,
-    reasons: bool,
-    module_assets: bool,
-    nested_modules: bool,
-    source: bool,
-  ) -> Result<Vec<StatsChunk>> {
+  pub fn get_chunks(&self, options: &StatsOptions) -> Result<Vec<StatsChunk>> {
+    let depths = (options.chunk_modules && options.depth).then(|| self.get_module_depths());
     let mut chunks: Vec<StatsChunk> = self
       .compilation
       .chunk_by_ukey
@@ -172,14 +165,14 @@ impl Stats<'_> {
         let mut auxiliary_files = Vec::from_iter(c.auxiliary_files.iter().cloned());
         auxiliary_files.sort_unstable();
 
-        let chunk_modules = if chunk_modules {
+        let chunk_modules = if options.chunk_modules {
           let chunk_modules = self
             .compilation
             .chunk_graph
             .get_chunk_modules(&c.ukey, &self.compilation.module_graph);
           let mut chunk_modules = chunk_modules
             .into_iter()
-            .map(|m| self.get_module(m, reasons, module_assets, nested_modules, source))
+            .map(|m| self.get_module(m, options, depths.as_ref()))
             .collect::<Result<Vec<_>>>()?;
           Self::sort_modules(&mut chunk_modules);
           Some(chunk_modules)
@@ -187,17 +180,18 @@ impl Stats<'_> {
           None
         };
         let (parents, children, siblings) = if let Some((parents, children, siblings)) =
-          chunk_relations.then(|| self.get_chunk_relations(c))
+          options.chunk_relations.then(|| self.get_chunk_relations(c))
         {
           (Some(parents), Some(children), Some(siblings))
         } else {
           (None, None, None)
         };
+        let origins = options.chunk_origins.then(|| self.get_chunk_origins(c));
         Ok(StatsChunk {
           r#type: "chunk",
           files,
           auxiliary_files,
-          id: c.id.clone(),
+          id: if options.ids { c.id.clone() } else { None },
           names: c.name.clone().map(|n| vec![n]).unwrap_or_default(),
           entry: c.has_entry_module(&self.compilation.chunk_graph),
           initial: c.can_be_initial(&self.compilation.chunk_group_by_ukey),
@@ -209,6 +203,7 @@ impl Stats<'_> {
           parents,
           children,
           siblings,
+          origins,
         })
       })
       .collect::<Result<_>>()?;
@@ -278,7 +273,57 @@ impl Stats<'_> {
     named_chunk_groups
   }
 
-  pub fn get_errors(&self) -> Vec<StatsError> {
+  pub fn get_shared(&self) -> Result<Vec<StatsShared>> {
+    let mut shared: Vec<StatsShared> = vec![];
+    let mut visited = IdentifierSet::default();
+    for module in self
+      .compilation
+      .plugin_driver
+      .stats_shared(self.compilation)?
+    {
+      // Reported once for each instance of the plugins providing it
+      if !visited.insert(module.module_identifier) {
+        continue;
+      }
+      let mut chunks: Vec<String> = self
+        .compilation
+        .chunk_graph
+        .chunk_graph_module_by_module_identifier
+        .get(&module.module_identifier)
+        .map(|chunk_graph_module| {
+          chunk_graph_module
+            .chunks
+            .iter()
+            .filter_map(|c| self.compilation.chunk_by_ukey.expect_get(c).id.clone())
+            .collect()
+        })
+        .unwrap_or_default();
+      chunks.sort();
+      let version = StatsSharedVersion {
+        version: module.version,
+        request: module.request,
+        chunks,
+      };
+      match shared
+        .iter_mut()
+        .find(|s| s.share_scope == module.share_scope && s.share_key == module.share_key)
+      {
+        Some(s) => s.versions.push(version),
+        None => shared.push(StatsShared {
+          share_scope: module.share_scope,
+          share_key: module.share_key,
+          versions: vec![version],
+        }),
+      }
+    }
+    for s in &mut shared {
+      s.versions.sort_by(|a, b| a.version.cmp(&b.version));
+    }
+    shared.sort_by(|a, b| (&a.share_scope, &a.share_key).cmp(&(&b.share_scope, &b.share_key)));
+    Ok(shared)
+  }
+
+  pub fn get_errors(&self, options: &StatsOptions) -> Vec<StatsError> {
     let mut diagnostic_displayer = DiagnosticDisplayer::new(self.compilation.options.stats.colors);
     self
       .compilation
@@ -305,12 +350,17 @@ impl Stats<'_> {
           module_identifier: module_identifier.map(|i| i.to_string()),
           module_name,
           module_id: module_id.flatten(),
+          module_trace: if options.module_trace {
+            self.get_module_trace(module_identifier)
+          } else {
+            vec![]
+          },
         }
       })
       .collect()
   }
 
-  pub fn get_warnings(&self) -> Vec<StatsWarning> {
+  pub fn get_warnings(&self, options: &StatsOptions) -> Vec<StatsWarning> {
     let mut diagnostic_displayer = DiagnosticDisplayer::new(self.compilation.options.stats.colors);
     self
       .compilation
@@ -337,6 +387,11 @@ impl Stats<'_> {
           module_identifier: module_identifier.map(|i| i.to_string()),
           module_name,
           module_id: module_id.flatten(),
+          module_trace: if options.module_trace {
+            self.get_module_trace(module_identifier)
+          } else {
+            vec![]
+          },
         }
       })
       .collect()
@@ -374,10 +429,8 @@ impl Stats<'_> {
   fn get_module<'a>(
     &'a self,
     module: &'a BoxModule,
-    reasons: bool,
-    module_assets: bool,
-    nested_modules: bool,
-    source: bool,
+    options: &StatsOptions,
+    depths: Option<&IdentifierMap<usize>>,
   ) -> Result<StatsModule<'a>> {
     let identifier = module.identifier();
     let mgm = self
@@ -386,6 +439,37 @@ impl Stats<'_> {
       .module_graph_module_by_identifier(&identifier)
       .unwrap_or_else(|| panic!("Could not find ModuleGraphModule by identifier: {identifier:?}"));
 
+    let modules = options
+      .nested_modules
+      .then(|| -> Result<_> {
+        let Some(concatenated_module) = module.as_concatenated_module() else {
+          return Ok(vec![]);
+        };
+        let inner_options = StatsOptions {
+          nested_modules: false,
+          ..options.clone()
+        };
+        let mut modules = concatenated_module
+          .modules()
+          .iter()
+          .map(|m| {
+            let inner = if *m == identifier {
+              concatenated_module.root_module()
+            } else {
+              self
+                .compilation
+                .module_graph
+                .module_by_identifier(m)
+                .expect("should have module")
+            };
+            self.get_module(inner, &inner_options, depths)
+          })
+          .collect::<Result<Vec<_>>>()?;
+        Self::sort_modules(&mut modules);
+        Ok(modules)
+      })
+      .transpose()?;
+
     let issuer = self.compilation.module_graph.get_issuer(module);
     let (issuer_name, issuer_id) = issuer
       .map(|i| get_stats_module_name_and_id(i, self.compilation))
@@ -403,7 +487,8 @@ impl Stats<'_> {
     }
     issuer_path.reverse();
 
-    let reasons = reasons
+    let reasons = options
+      .reasons
       .then(|| -> Result<_> {
         let mut reasons: Vec<StatsModuleReason> = mgm
           .incoming_connections_unordered(&self.compilation.module_graph)?
@@ -445,17 +530,21 @@ impl Stats<'_> {
       })
       .transpose()?;
 
-    let mut chunks: Vec<Option<String>> = self
-      .compilation
-      .chunk_graph
-      .get_chunk_graph_module(mgm.module_identifier)
-      .chunks
-      .iter()
-      .map(|k| self.compilation.chunk_by_ukey.expect_get(k).id.clone())
-      .collect();
+    let mut chunks: Vec<Option<String>> = if options.ids {
+      self
+        .compilation
+        .chunk_graph
+        .get_chunk_graph_module(mgm.module_identifier)
+        .chunks
+        .iter()
+        .map(|k| self.compilation.chunk_by_ukey.expect_get(k).id.clone())
+        .collect()
+    } else {
+      vec![]
+    };
     chunks.sort_unstable();
 
-    let assets = module_assets.then(|| {
+    let assets = options.module_assets.then(|| {
       let mut assets: Vec<_> = module
         .build_info()
         .as_ref()
@@ -465,8 +554,6 @@ impl Stats<'_> {
       assets
     });
 
-    // TODO: a placeholder for concatenation modules
-    let modules = nested_modules.then(Vec::new);
     let profile = if let Some(p) = mgm.get_profile()
       && let Some(factory) = p.factory.duration()
       && let Some(integration) = p.integration.duration()
@@ -481,6 +568,42 @@ impl Stats<'_> {
       None
     };
 
+    let (used_exports, provided_exports) = if self.compilation.options.is_new_tree_shaking() {
+      let exports_info = self.compilation.module_graph.get_exports_info(&identifier);
+      let used_exports = options.used_exports.then(|| {
+        let runtime = self
+          .compilation
+          .chunk_graph
+          .get_module_runtimes(identifier, &self.compilation.chunk_by_ukey)
+          .values()
+          .into_iter()
+          .fold(RuntimeSpec::default(), |acc, runtime| {
+            merge_runtime(&acc, runtime)
+          });
+        match exports_info.get_used_exports(Some(&runtime), &self.compilation.module_graph) {
+          UsedExports::Null => StatsUsedExports::Null,
+          UsedExports::Bool(used) => StatsUsedExports::Bool(used),
+          UsedExports::Vec(exports) => {
+            StatsUsedExports::Vec(exports.iter().map(|e| e.to_string()).collect())
+          }
+        }
+      });
+      let provided_exports = options
+        .provided_exports
+        .then(
+          || match exports_info.get_provided_exports(&self.compilation.module_graph) {
+            ProvidedExports::Vec(exports) => {
+              Some(exports.iter().map(|e| e.to_string()).collect::<Vec<_>>())
+            }
+            ProvidedExports::Null | ProvidedExports::True => None,
+          },
+        )
+        .flatten();
+      (used_exports, provided_exports)
+    } else {
+      (None, None)
+    };
+
     Ok(StatsModule {
       r#type: "module",
       module_type: *module.module_type(),
@@ -489,27 +612,39 @@ impl Stats<'_> {
       name: module
         .readable_identifier(&self.compilation.options.context)
         .into(),
-      id: self
-        .compilation
-        .chunk_graph
-        .get_module_id(identifier)
-        .clone(),
+      id: if options.ids {
+        self
+          .compilation
+          .chunk_graph
+          .get_module_id(identifier)
+          .clone()
+      } else {
+        None
+      },
       chunks,
       size: module.size(&SourceType::JavaScript),
       issuer: issuer.map(|i| i.identifier().to_string()),
       issuer_name,
-      issuer_id: issuer_id.and_then(|i| i),
+      issuer_id: issuer_id.flatten().filter(|_| options.ids),
       issuer_path,
       reasons,
       assets,
       modules,
-      source: source.then(|| module.original_source()).flatten(),
+      source: options.source.then(|| module.original_source()).flatten(),
       profile,
       orphan: self
         .compilation
         .chunk_graph
         .get_number_of_module_chunks(identifier)
         == 0,
+      optimization_bailout: if options.optimization_bailout {
+        mgm.optimization_bailout.clone()
+      } else {
+        vec![]
+      },
+      depth: depths.and_then(|depths| depths.get(&identifier).copied()),
+      used_exports,
+      provided_exports,
     })
   }
 
@@ -517,8 +652,7 @@ impl Stats<'_> {
     &'a self,
     identifier: &ModuleIdentifier,
     module: &'a BoxRuntimeModule,
-    reasons: bool,
-    module_assets: bool,
+    options: &StatsOptions,
   ) -> Result<StatsModule<'a>> {
     let mut chunks: Vec<Option<String>> = self
       .compilation
@@ -543,8 +677,8 @@ impl Stats<'_> {
       issuer_name: None,
       issuer_id: None,
       issuer_path: Vec::new(),
-      reasons: reasons.then_some(vec![]),
-      assets: module_assets.then_some(vec![]),
+      reasons: options.reasons.then_some(vec![]),
+      assets: options.module_assets.then_some(vec![]),
       modules: None,
       source: None,
       profile: None,
@@ -553,6 +687,10 @@ impl Stats<'_> {
         .chunk_graph
         .get_number_of_module_chunks(*identifier)
         == 0,
+      optimization_bailout: vec![],
+      depth: None,
+      used_exports: None,
+      provided_exports: None,
     })
   }
   fn get_chunk_relations(&self, chunk: &Chunk) -> (Vec<String>, Vec<String>, Vec<String>) {
@@ -605,6 +743,106 @@ impl Stats<'_> {
     siblings.sort();
     (parents, children, siblings)
   }
+
+  fn get_module_trace(&self, module_identifier: Option<ModuleIdentifier>) -> Vec<StatsModuleTrace> {
+    let mut module_trace = vec![];
+    let mut current = module_identifier.and_then(|identifier| {
+      self
+        .compilation
+        .module_graph
+        .module_by_identifier(&identifier)
+    });
+    // the issuers may form a cycle, e.g. an entry module imported by another module
+    let mut visited = IdentifierSet::default();
+    while let Some(module) = current {
+      if !visited.insert(module.identifier()) {
+        break;
+      }
+      let Some(origin) = self.compilation.module_graph.get_issuer(module) else {
+        break;
+      };
+      let (origin_name, origin_id) = get_stats_module_name_and_id(origin, self.compilation);
+      let (module_name, module_id) = get_stats_module_name_and_id(module, self.compilation);
+      module_trace.push(StatsModuleTrace {
+        origin: StatsModuleIssuer {
+          identifier: origin.identifier().to_string(),
+          name: origin_name,
+          id: origin_id,
+        },
+        module: StatsModuleIssuer {
+          identifier: module.identifier().to_string(),
+          name: module_name,
+          id: module_id,
+        },
+      });
+      current = Some(origin);
+    }
+    module_trace
+  }
+
+  /// The shortest distance from the entry modules to each module.
+  fn get_module_depths(&self) -> IdentifierMap<usize> {
+    let module_graph = &self.compilation.module_graph;
+    let mut depths = IdentifierMap::default();
+    let mut queue = VecDeque::new();
+    for dependency_id in self
+      .compilation
+      .entries
+      .values()
+      .flat_map(|entry| entry.dependencies.iter())
+      .chain(self.compilation.global_entry.dependencies.iter())
+    {
+      if let Some(module_identifier) =
+        module_graph.module_identifier_by_dependency_id(dependency_id)
+        && !depths.contains_key(module_identifier)
+      {
+        depths.insert(*module_identifier, 0);
+        queue.push_back(*module_identifier);
+      }
+    }
+    while let Some(module_identifier) = queue.pop_front() {
+      let depth = depths[&module_identifier] + 1;
+      let Some(mgm) = module_graph.module_graph_module_by_identifier(&module_identifier) else {
+        continue;
+      };
+      for connection_id in &mgm.outgoing_connections {
+        let Some(connection) = module_graph.connection_by_connection_id(connection_id) else {
+          continue;
+        };
+        if !depths.contains_key(&connection.module_identifier) {
+          depths.insert(connection.module_identifier, depth);
+          queue.push_back(connection.module_identifier);
+        }
+      }
+    }
+    depths
+  }
+
+  fn get_chunk_origins(&self, chunk: &Chunk) -> Vec<StatsOriginRecord> {
+    let mut origins = chunk
+      .groups
+      .iter()
+      .filter_map(|cg| get_chunk_group_from_ukey(cg, &self.compilation.chunk_group_by_ukey))
+      .flat_map(|cg| cg.origins())
+      .map(|origin| {
+        let (module_name, module_id) = origin
+          .module_id
+          .and_then(|i| self.compilation.module_graph.module_by_identifier(&i))
+          .map(|m| get_stats_module_name_and_id(m, self.compilation))
+          .unzip();
+        StatsOriginRecord {
+          module: origin.module_id.map(|i| i.to_string()).unwrap_or_default(),
+          module_identifier: origin.module_id.map(|i| i.to_string()).unwrap_or_default(),
+          module_name: module_name.unwrap_or_default(),
+          module_id: module_id.flatten().unwrap_or_default(),
+          request: origin.request.clone().unwrap_or_default(),
+        }
+      })
+      .collect::<Vec<_>>();
+    origins.sort_unstable();
+    origins.dedup();
+    origins
+  }
 }
 
 fn get_stats_module_name_and_id(
@@ -624,6 +862,7 @@ pub struct StatsError {
   pub module_identifier: Option<String>,
   pub module_name: Option<String>,
   pub module_id: Option<String>,
+  pub module_trace: Vec<StatsModuleTrace>,
 }
 
 #[derive(Debug)]
@@ -633,6 +872,7 @@ pub struct StatsWarning {
   pub module_identifier: Option<String>,
   pub module_name: Option<String>,
   pub module_id: Option<String>,
+  pub module_trace: Vec<StatsModuleTrace>,
 }
 
 #[derive(Debug)]
@@ -679,6 +919,17 @@ pub struct StatsModule<'a> {
   pub source: Option<&'a dyn Source>,
   pub profile: Option<StatsModuleProfile>,
   pub orphan: bool,
+  pub optimization_bailout: Vec<String>,
+  pub depth: Option<usize>,
+  pub used_exports: Option<StatsUsedExports>,
+  pub provided_exports: Option<Vec<String>>,
+}
+
+#[derive(Debug)]
+pub enum StatsUsedExports {
+  Null,
+  Bool(bool),
+  Vec(Vec<String>),
 }
 
 #[derive(Debug)]
@@ -702,6 +953,22 @@ pub struct StatsChunk<'a> {
   pub parents: Option<Vec<String>>,
   pub children: Option<Vec<String>>,
   pub siblings: Option<Vec<String>>,
+  pub origins: Option<Vec<StatsOriginRecord>>,
+}
+
+#[derive(Debug)]
+pub struct StatsModuleTrace {
+  pub origin: StatsModuleIssuer,
+  pub module: StatsModuleIssuer,
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct StatsOriginRecord {
+  pub module: String,
+  pub module_identifier: String,
+  pub module_name: String,
+  pub module_id: String,
+  pub request: String,
 }
 
 #[derive(Debug)]
@@ -718,6 +985,33 @@ pub struct StatsChunkGroup {
   pub assets_size: f64,
 }
 
+#[derive(Debug)]
+pub struct StatsShared {
+  pub share_scope: String,
+  pub share_key: String,
+  pub versions: Vec<StatsSharedVersion>,
+}
+
+/// A module provided to a share scope by module federation.
+#[derive(Debug, Clone)]
+pub struct ProvidedSharedModule {
+  pub share_scope: String,
+  pub share_key: String,
+  /// `None` when the version is disabled with `version: false`.
+  pub version: Option<String>,
+  /// The resource of the provided module.
+  pub request: String,
+  pub module_identifier: ModuleIdentifier,
+}
+
+#[derive(Debug)]
+pub struct StatsSharedVersion {
+  pub version: Option<String>,
+  pub request: String,
+  /// Ids of the chunks registering the version to the share scope.
+  pub chunks: Vec<String>,
+}
+
 #[derive(Debug)]
 pub struct StatsModuleIssuer {
   pub identifier: String,
@@ -1,14 +1,41 @@
 use std::{collections::HashMap, sync::Arc};
 
+use indexmap::IndexSet;
 use once_cell::sync::Lazy;
 use regex::{Captures, Regex};
 
 use crate::{
-  EntryOptions, Filename, RuntimeSpec, CHUNK_HASH_PLACEHOLDER, CONTENT_HASH_PLACEHOLDER,
+  Entry, EntryOptions, Filename, RuntimeSpec, CHUNK_HASH_PLACEHOLDER, CONTENT_HASH_PLACEHOLDER,
   FULL_HASH_PLACEHOLDER, HASH_PLACEHOLDER,
 };
 
-pub fn get_entry_runtime(name: &str, options: &EntryOptions) -> RuntimeSpec {
+/// Entries with `dependOn` share the runtimes of the entries they depend on.
+///
+/// Align with https://github.com/webpack/webpack/blob/1f99ad6367f2b8a6ef17cce0e058f7a67fb7db18/lib/util/runtime.js#L33
+pub fn get_entry_runtime(name: &str, options: &EntryOptions, entries: &Entry) -> RuntimeSpec {
+  if let Some(depend_on) = &options.depend_on {
+    let mut result = RuntimeSpec::default();
+    let mut queue = depend_on
+      .iter()
+      .map(String::as_str)
+      .collect::<IndexSet<_>>();
+    let mut i = 0;
+    while let Some(name) = queue.get_index(i).copied() {
+      i += 1;
+      let Some(dep) = entries.get(name) else {
+        continue;
+      };
+      if let Some(depend_on) = &dep.options.depend_on {
+        queue.extend(depend_on.iter().map(String::as_str));
+      } else {
+        result.insert(Arc::from(dep.options.runtime.as_deref().unwrap_or(name)));
+      }
+    }
+    if !result.is_empty() {
+      return result;
+    }
+    return RuntimeSpec::from_iter([Arc::from(name)]);
+  }
   RuntimeSpec::from_iter([Arc::from(
     options.runtime.clone().unwrap_or_else(|| name.to_string()),
   )])
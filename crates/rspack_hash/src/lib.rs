@@ -3,23 +3,38 @@ use std::{
   hash::{Hash, Hasher},
 };
 
-use data_encoding::HEXLOWER_PERMISSIVE;
+use data_encoding::{BASE64, BASE64URL_NOPAD, HEXLOWER_PERMISSIVE};
 use md4::Digest;
+use rspack_error::{error, Error};
 use smol_str::SmolStr;
 use xxhash_rust::xxh3;
 
 #[derive(Debug, Clone, Copy)]
 pub enum HashFunction {
   Xxhash64,
+  Xxhash128,
   MD4,
+  MD5,
+  SHA256,
+  SHA384,
+  SHA512,
 }
 
-impl From<&str> for HashFunction {
-  fn from(value: &str) -> Self {
+impl TryFrom<&str> for HashFunction {
+  type Error = Error;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
     match value {
-      "xxhash64" => HashFunction::Xxhash64,
-      "md4" => HashFunction::MD4,
-      _ => unimplemented!("{}", value),
+      "xxhash64" => Ok(HashFunction::Xxhash64),
+      "xxhash128" => Ok(HashFunction::Xxhash128),
+      "md4" => Ok(HashFunction::MD4),
+      "md5" => Ok(HashFunction::MD5),
+      "sha256" => Ok(HashFunction::SHA256),
+      "sha384" => Ok(HashFunction::SHA384),
+      "sha512" => Ok(HashFunction::SHA512),
+      _ => Err(error!(
+        "Unsupported hash function \"{value}\", expected one of \"xxhash64\", \"xxhash128\", \"md4\", \"md5\", \"sha256\", \"sha384\" or \"sha512\""
+      )),
     }
   }
 }
@@ -27,15 +42,69 @@ impl From<&str> for HashFunction {
 #[derive(Debug, Clone, Copy)]
 pub enum HashDigest {
   Hex,
+  Base64,
+  Base64Url,
+  /// Base-N encoding of the digest, the same as the `base26`...`base62`
+  /// digest types of `loader-utils`.
+  BaseN(u8),
 }
 
-impl From<&str> for HashDigest {
-  fn from(value: &str) -> Self {
+impl TryFrom<&str> for HashDigest {
+  type Error = Error;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
     match value {
-      "hex" => HashDigest::Hex,
-      _ => unimplemented!(),
+      "hex" => Ok(HashDigest::Hex),
+      "base64" => Ok(HashDigest::Base64),
+      "base64url" => Ok(HashDigest::Base64Url),
+      _ => value
+        .strip_prefix("base")
+        .and_then(|base| base.parse::<u8>().ok())
+        .filter(|base| base_encode_table(*base).is_some())
+        .map(HashDigest::BaseN)
+        .ok_or_else(|| {
+          error!(
+            "Unsupported hash digest \"{value}\", expected one of \"hex\", \"base64\", \"base64url\", \"base26\", \"base32\", \"base36\", \"base49\", \"base52\", \"base58\" or \"base62\""
+          )
+        }),
+    }
+  }
+}
+
+fn base_encode_table(base: u8) -> Option<&'static [u8]> {
+  let table: &'static [u8] = match base {
+    26 => b"abcdefghijklmnopqrstuvwxyz",
+    // no 0lio
+    32 => b"123456789abcdefghjkmnpqrstuvwxyz",
+    36 => b"0123456789abcdefghijklmnopqrstuvwxyz",
+    // no lIO
+    49 => b"abcdefghijkmnopqrstuvwxyzABCDEFGHJKLMNPQRSTUVWXYZ",
+    52 => b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ",
+    // no 0lIO
+    58 => b"123456789abcdefghijkmnopqrstuvwxyzABCDEFGHJKLMNPQRSTUVWXYZ",
+    62 => b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ",
+    _ => return None,
+  };
+  Some(table)
+}
+
+/// Encode the bytes as a little-endian big number in `base`, aligns with `encodeBufferToBase` of `loader-utils`.
+fn encode_to_base(bytes: &[u8], base: u8) -> String {
+  let table = base_encode_table(base).expect("should be a supported base");
+  // most significant digit first
+  let mut number = bytes.iter().rev().copied().collect::<Vec<u8>>();
+  let mut output = vec![];
+  while number.iter().any(|digit| *digit != 0) {
+    let mut remainder = 0u32;
+    for digit in number.iter_mut() {
+      let value = (remainder << 8) | (*digit as u32);
+      *digit = (value / base as u32) as u8;
+      remainder = value % base as u32;
     }
+    output.push(table[remainder as usize]);
   }
+  output.reverse();
+  String::from_utf8(output).expect("should be ascii")
 }
 
 #[derive(Debug, Clone, Hash)]
@@ -56,14 +125,24 @@ impl From<Option<String>> for HashSalt {
 #[derive(Clone)]
 pub enum RspackHash {
   Xxhash64(Box<xxh3::Xxh3>),
+  Xxhash128(Box<xxh3::Xxh3>),
   MD4(md4::Md4),
+  MD5(md5::Md5),
+  SHA256(sha2::Sha256),
+  SHA384(sha2::Sha384),
+  SHA512(sha2::Sha512),
 }
 
 impl fmt::Debug for RspackHash {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     match self {
       Self::Xxhash64(_) => write!(f, "RspackHash(Xxhash64)"),
+      Self::Xxhash128(_) => write!(f, "RspackHash(Xxhash128)"),
       Self::MD4(_) => write!(f, "RspackHash(MD4)"),
+      Self::MD5(_) => write!(f, "RspackHash(MD5)"),
+      Self::SHA256(_) => write!(f, "RspackHash(SHA256)"),
+      Self::SHA384(_) => write!(f, "RspackHash(SHA384)"),
+      Self::SHA512(_) => write!(f, "RspackHash(SHA512)"),
     }
   }
 }
@@ -72,7 +151,12 @@ impl RspackHash {
   pub fn new(function: &HashFunction) -> Self {
     match function {
       HashFunction::Xxhash64 => Self::Xxhash64(Box::new(xxh3::Xxh3::new())),
+      HashFunction::Xxhash128 => Self::Xxhash128(Box::new(xxh3::Xxh3::new())),
       HashFunction::MD4 => Self::MD4(md4::Md4::new()),
+      HashFunction::MD5 => Self::MD5(md5::Md5::new()),
+      HashFunction::SHA256 => Self::SHA256(sha2::Sha256::new()),
+      HashFunction::SHA384 => Self::SHA384(sha2::Sha384::new()),
+      HashFunction::SHA512 => Self::SHA512(sha2::Sha512::new()),
     }
   }
 
@@ -82,12 +166,20 @@ impl RspackHash {
     this
   }
 
-  pub fn digest(self, digest: &HashDigest) -> RspackHashDigest {
-    let inner = match self {
+  fn finalize(self) -> Vec<u8> {
+    match self {
       RspackHash::Xxhash64(hasher) => hasher.finish().to_le_bytes().to_vec(),
-      RspackHash::MD4(hash) => hash.finalize().to_vec(),
-    };
-    RspackHashDigest::new(inner, digest)
+      RspackHash::Xxhash128(hasher) => hasher.digest128().to_le_bytes().to_vec(),
+      RspackHash::MD4(hasher) => hasher.finalize().to_vec(),
+      RspackHash::MD5(hasher) => hasher.finalize().to_vec(),
+      RspackHash::SHA256(hasher) => hasher.finalize().to_vec(),
+      RspackHash::SHA384(hasher) => hasher.finalize().to_vec(),
+      RspackHash::SHA512(hasher) => hasher.finalize().to_vec(),
+    }
+  }
+
+  pub fn digest(self, digest: &HashDigest) -> RspackHashDigest {
+    RspackHashDigest::new(self.finalize(), digest)
   }
 }
 
@@ -95,9 +187,9 @@ impl Hasher for RspackHash {
   fn finish(&self) -> u64 {
     match self {
       RspackHash::Xxhash64(hasher) => hasher.finish(),
-      RspackHash::MD4(hasher) => {
+      _ => {
         // finalize take ownership, so we need to clone it
-        let hash = hasher.clone().finalize();
+        let hash = self.clone().finalize();
         let msb_u64: u64 = ((hash[0] as u64) << 56)
           | ((hash[1] as u64) << 48)
           | ((hash[2] as u64) << 40)
@@ -114,7 +206,12 @@ impl Hasher for RspackHash {
   fn write(&mut self, bytes: &[u8]) {
     match self {
       RspackHash::Xxhash64(hasher) => hasher.write(bytes),
+      RspackHash::Xxhash128(hasher) => hasher.write(bytes),
       RspackHash::MD4(hasher) => hasher.update(bytes),
+      RspackHash::MD5(hasher) => hasher.update(bytes),
+      RspackHash::SHA256(hasher) => hasher.update(bytes),
+      RspackHash::SHA384(hasher) => hasher.update(bytes),
+      RspackHash::SHA512(hasher) => hasher.update(bytes),
     }
   }
 }
@@ -128,6 +225,9 @@ impl RspackHashDigest {
   pub fn new(inner: Vec<u8>, digest: &HashDigest) -> Self {
     let encoded = match digest {
       HashDigest::Hex => HEXLOWER_PERMISSIVE.encode(&inner).into(),
+      HashDigest::Base64 => BASE64.encode(&inner).into(),
+      HashDigest::Base64Url => BASE64URL_NOPAD.encode(&inner).into(),
+      HashDigest::BaseN(base) => encode_to_base(&inner, *base).into(),
     };
     Self { encoded }
   }
@@ -142,6 +242,14 @@ impl RspackHashDigest {
   }
 }
 
+impl From<&str> for RspackHashDigest {
+  fn from(value: &str) -> Self {
+    Self {
+      encoded: value.into(),
+    }
+  }
+}
+
 impl Hash for RspackHashDigest {
   fn hash<H: Hasher>(&self, state: &mut H) {
     self.encoded.hash(state);
@@ -153,3 +261,50 @@ impl PartialEq for RspackHashDigest {
     self.encoded == other.encoded
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn hash(function: &str, digest: &str, content: &str) -> String {
+    let mut hasher = RspackHash::new(&HashFunction::try_from(function).expect("should be valid"));
+    hasher.write(content.as_bytes());
+    hasher
+      .digest(&HashDigest::try_from(digest).expect("should be valid"))
+      .encoded()
+      .to_string()
+  }
+
+  #[test]
+  fn should_match_node_crypto_digests() {
+    assert_eq!(
+      hash("md5", "hex", "rspack"),
+      "c6a2f17cd5f27276f94ba2de647e4de4"
+    );
+    assert_eq!(
+      hash("sha256", "base64", "rspack"),
+      "G2rSbLQvlv7nFLb95ZAut0Bjrls3JInjlCN22+Vgv/g="
+    );
+    assert_eq!(
+      hash("sha256", "base64url", "rspack"),
+      "G2rSbLQvlv7nFLb95ZAut0Bjrls3JInjlCN22-Vgv_g"
+    );
+  }
+
+  #[test]
+  fn should_encode_to_base() {
+    assert_eq!(encode_to_base(&[0], 26), "");
+    assert_eq!(encode_to_base(&[25], 26), "z");
+    assert_eq!(encode_to_base(&[26], 26), "ba");
+    // little-endian: 0x0100 = 256 = 9 * 26 + 22
+    assert_eq!(encode_to_base(&[0, 1], 26), "jw");
+    assert_eq!(encode_to_base(&[255, 255], 62), "h31");
+  }
+
+  #[test]
+  fn should_error_on_unknown_values() {
+    assert!(HashFunction::try_from("sha3").is_err());
+    assert!(HashDigest::try_from("base27").is_err());
+    assert!(HashDigest::try_from("latin1").is_err());
+  }
+}
@@ -14,7 +14,7 @@ use rayon::prelude::*;
 use regex::Regex;
 use rspack_core::{
   compare_runtime, BoxModule, Chunk, ChunkGraph, ChunkUkey, Compilation, ModuleGraph,
-  ModuleIdentifier,
+  ModuleIdentifier, Records,
 };
 use rspack_util::{
   comparators::{compare_ids, compare_numbers},
@@ -58,6 +58,29 @@ pub fn get_used_module_ids_and_modules(
   (used_ids, modules)
 }
 
+/// Restore the module ids from `compilation.input_records`, ids that are already used are skipped.
+pub fn revive_module_ids(compilation: &mut Compilation) {
+  let Some(records) = compilation.input_records.clone() else {
+    return;
+  };
+  if records.module_ids.is_empty() {
+    return;
+  }
+  let (mut used_ids, modules) = get_used_module_ids_and_modules(compilation, None);
+  let context = compilation.options.context.as_str();
+  for module in modules {
+    let Some(id) = records
+      .module_ids
+      .get(&Records::module_key(&module, context))
+    else {
+      continue;
+    };
+    if used_ids.insert(id.clone()) {
+      compilation.chunk_graph.set_module_id(module, id.clone());
+    }
+  }
+}
+
 pub fn get_short_module_name(module: &BoxModule, context: &str) -> String {
   let lib_ident = module.lib_ident(rspack_core::LibIdentOptions { context });
   if let Some(lib_ident) = lib_ident {
@@ -457,6 +480,44 @@ pub fn get_used_chunk_ids(compilation: &Compilation) -> HashSet<String> {
   used_ids
 }
 
+/// Restore the chunk ids from `compilation.input_records`, ids that are already used are skipped.
+pub fn revive_chunk_ids(compilation: &mut Compilation) {
+  let Some(records) = compilation.input_records.clone() else {
+    return;
+  };
+  if records.chunk_ids.is_empty() {
+    return;
+  }
+  let mut used_ids = get_used_chunk_ids(compilation);
+  let mut chunks = compilation
+    .chunk_by_ukey
+    .values()
+    .filter(|chunk| chunk.id.is_none())
+    .filter_map(|chunk| {
+      let key = Records::chunk_key(chunk, compilation);
+      let id = records.chunk_ids.get(&key)?;
+      Some((chunk, key, id))
+    })
+    .collect::<Vec<_>>();
+  // assign ids in a stable order when multiple chunks want the same id
+  chunks.sort_by(|(a, a_key, _), (b, b_key, _)| {
+    a_key.cmp(b_key).then_with(|| {
+      compare_chunks_by_modules(&compilation.chunk_graph, &compilation.module_graph, a, b)
+    })
+  });
+  let chunks = chunks
+    .into_iter()
+    .map(|(chunk, _, id)| (chunk.ukey, id))
+    .collect::<Vec<_>>();
+  for (ukey, id) in chunks {
+    if used_ids.insert(id.clone()) {
+      let chunk = compilation.chunk_by_ukey.expect_get_mut(&ukey);
+      chunk.id = Some(id.clone());
+      chunk.ids = vec![id.clone()];
+    }
+  }
+}
+
 pub fn assign_ascending_chunk_ids(chunks: &[ChunkUkey], compilation: &mut Compilation) {
   let used_ids = get_used_chunk_ids(compilation);
 
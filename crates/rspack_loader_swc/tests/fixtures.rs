@@ -33,6 +33,8 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
     CompilerContext {
       options: std::sync::Arc::new(CompilerOptions {
         bail: false,
+        records_input_path: None,
+        records_output_path: None,
         context: rspack_core::Context::default(),
         dev_server: rspack_core::DevServerOptions::default(),
         devtool: rspack_core::Devtool::from("source-map".to_string()),
@@ -93,6 +95,7 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
           used_exports: Default::default(),
           inner_graph: Default::default(),
           mangle_exports: Default::default(),
+          concatenate_modules: false,
         },
         profile: false,
       }),
@@ -3,25 +3,52 @@ use rspack_core::{
   DependencyType, ErrorSpan, ModuleDependency, TemplateContext, TemplateReplaceSource,
 };
 
+/// The `layer(...)`, `supports(...)` and media query list following the url of an `@import`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct CssImportConditions {
+  /// `Some("")` for an anonymous layer, e.g. `@import "a.css" layer;`
+  pub layer: Option<String>,
+  pub supports: Option<String>,
+  pub media: Option<String>,
+}
+
+impl CssImportConditions {
+  pub fn is_empty(&self) -> bool {
+    self.layer.is_none() && self.supports.is_none() && self.media.is_none()
+  }
+}
+
 #[derive(Debug, Clone)]
 pub struct CssImportDependency {
   id: DependencyId,
   request: String,
+  conditions: CssImportConditions,
   span: Option<ErrorSpan>,
   start: u32,
   end: u32,
 }
 
 impl CssImportDependency {
-  pub fn new(request: String, span: Option<ErrorSpan>, start: u32, end: u32) -> Self {
+  pub fn new(
+    request: String,
+    conditions: CssImportConditions,
+    span: Option<ErrorSpan>,
+    start: u32,
+    end: u32,
+  ) -> Self {
     Self {
       id: DependencyId::new(),
       request,
+      conditions,
       span,
       start,
       end,
     }
   }
+
+  pub fn conditions(&self) -> &CssImportConditions {
+    &self.conditions
+  }
 }
 
 impl Dependency for CssImportDependency {
@@ -1,7 +1,9 @@
 mod compose;
 mod import;
 mod url;
+mod value_import;
 
 pub use compose::*;
 pub use import::*;
 pub use url::*;
+pub use value_import::*;
@@ -0,0 +1,61 @@
+use rspack_core::{
+  AsContextDependency, AsDependencyTemplate, Dependency, DependencyCategory, DependencyId,
+  DependencyType, ErrorSpan, ModuleDependency,
+};
+
+/// `@value primary from "./colors.css"`, it's resolved in the same way as `composes`.
+#[derive(Debug, Clone)]
+pub struct CssValueImportDependency {
+  id: DependencyId,
+  request: String,
+  span: Option<ErrorSpan>,
+}
+
+impl CssValueImportDependency {
+  pub fn new(request: String, span: Option<ErrorSpan>) -> Self {
+    Self {
+      id: DependencyId::new(),
+      request,
+      span,
+    }
+  }
+}
+
+impl Dependency for CssValueImportDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::CssCompose
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::CssValueImport
+  }
+
+  fn span(&self) -> Option<ErrorSpan> {
+    self.span
+  }
+
+  fn dependency_debug_name(&self) -> &'static str {
+    "CssValueImportDependency"
+  }
+}
+
+impl ModuleDependency for CssValueImportDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request;
+  }
+}
+
+impl AsDependencyTemplate for CssValueImportDependency {}
+impl AsContextDependency for CssValueImportDependency {}
@@ -12,26 +12,31 @@ use rspack_core::{
     BoxSource, MapOptions, RawSource, ReplaceSource, Source, SourceExt, SourceMap, SourceMapSource,
     SourceMapSourceOptions,
   },
-  BoxDependency, BuildExtraDataType, BuildMetaExportsType, GenerateContext, Module, ModuleType,
-  ParseContext, ParseResult, ParserAndGenerator, SourceType, TemplateContext,
+  BoxDependency, BuildExtraDataType, BuildMetaExportsType, ConstDependency, DependencyTemplate,
+  GenerateContext, Module, ModuleType, ParseContext, ParseResult, ParserAndGenerator, SourceType,
+  StaticExportsDependency, TemplateContext,
 };
 use rspack_core::{ModuleInitFragments, RuntimeGlobals};
 use rspack_error::{IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
 use rustc_hash::FxHashSet;
 use sugar_path::SugarPath;
-use swc_core::{css::parser::parser::ParserConfig, ecma::atoms::JsWord};
+use swc_core::css::{modules::CssClassName, parser::parser::ParserConfig};
+use swc_core::ecma::atoms::JsWord;
 
 use crate::{
-  dependency::CssComposeDependency,
-  swc_css_compiler::{SwcCssCompiler, SwcCssSourceMapGenConfig},
+  dependency::{CssComposeDependency, CssValueImportDependency},
+  swc_css_compiler::{CssTargets, SwcCssCompiler, SwcCssSourceMapGenConfig},
 };
 use crate::{
   plugin::CssConfig,
   utils::{css_modules_exports_to_string, ModulesTransformConfig},
 };
 use crate::{
-  utils::{export_locals_convention, stringify_css_modules_exports_elements},
-  visitors::analyze_dependencies,
+  utils::{
+    export_locals_convention, resolve_css_modules_value, stringify_css_modules_exports_elements,
+    used_css_modules_classes,
+  },
+  visitors::{analyze_dependencies, analyze_local_class_rules, analyze_values},
 };
 
 static REGEX_IS_MODULES: Lazy<Regex> =
@@ -45,10 +50,24 @@ pub(crate) static CSS_MODULE_EXPORTS_ONLY_SOURCE_TYPE_LIST: &[SourceType; 1] =
 
 type CssExportsType = Option<IndexMap<Vec<String>, Vec<(String, Option<String>)>>>;
 
+/// The span of each rule whose selectors contain css modules local classes, with the stringified
+/// local classes of each selector in its selector list.
+pub(crate) type CssLocalClassRules = Vec<(u32, u32, Vec<Vec<String>>)>;
+
+/// Values defined by `@value` and `:export`, as `name -> (value, None)`, and values imported by
+/// `@value`, as `name -> (imported name, Some(request))`.
+pub(crate) type CssModuleValues = IndexMap<String, (String, Option<String>)>;
+
+/// The spans of the values referenced in declarations and at-rule preludes.
+pub(crate) type CssValueReferences = Vec<(u32, u32, String)>;
+
 #[derive(Debug)]
 pub struct CssParserAndGenerator {
   pub config: CssConfig,
+  pub targets: Option<CssTargets>,
   pub exports: CssExportsType,
+  pub local_class_rules: CssLocalClassRules,
+  pub value_references: CssValueReferences,
 }
 
 impl ParserAndGenerator for CssParserAndGenerator {
@@ -103,8 +122,9 @@ impl ParserAndGenerator for CssParserAndGenerator {
     let devtool = &compiler_options.devtool;
     let mut source_map = None;
     let mut diagnostic_vec = vec![];
+    let mut local_classes = None;
 
-    if is_enable_css_modules {
+    if is_enable_css_modules || self.targets.is_some() {
       let mut stylesheet = swc_compiler.parse_file(
         &resource_path.to_string_lossy(),
         source_code,
@@ -115,30 +135,53 @@ impl ParserAndGenerator for CssParserAndGenerator {
         },
       )?;
 
-      let result = swc_core::css::modules::compile(
-        &mut stylesheet,
-        ModulesTransformConfig::new(
-          &resource_data
-            .resource_path
-            .relative(&compiler_options.context),
-          &self.config.modules.local_ident_name,
-          &compiler_options.output,
-        ),
-      );
-      let mut exports: IndexMap<JsWord, _> = result.renamed.into_iter().collect();
-      exports.sort_keys();
-
-      self.exports = Some(IndexMap::from_iter(
-        exports
-          .iter()
-          .map(|(name, elements)| {
-            let mut names = export_locals_convention(name, &self.config.modules.locals_convention);
-            names.sort_unstable();
-            names.dedup();
-            (names, stringify_css_modules_exports_elements(elements))
-          })
-          .collect::<Vec<_>>(),
-      ));
+      if let Some(targets) = self.targets {
+        swc_compiler.lower_syntax(&mut stylesheet, targets);
+      }
+
+      if is_enable_css_modules {
+        let result = swc_core::css::modules::compile(
+          &mut stylesheet,
+          ModulesTransformConfig::new(
+            &resource_data
+              .resource_path
+              .relative(&compiler_options.context),
+            &self.config.modules.local_ident_name,
+            &compiler_options.output,
+          ),
+        );
+        let mut exports: IndexMap<JsWord, _> = result.renamed.into_iter().collect();
+        exports.sort_keys();
+
+        local_classes = Some(
+          exports
+            .values()
+            .flatten()
+            .filter_map(|element| match element {
+              CssClassName::Local { name } => Some(name.value.clone()),
+              _ => None,
+            })
+            .collect::<FxHashSet<JsWord>>(),
+        );
+
+        self.exports = Some(IndexMap::from_iter(
+          exports
+            .iter()
+            .map(|(name, elements)| {
+              let mut names =
+                export_locals_convention(name, &self.config.modules.locals_convention);
+              names.sort_unstable();
+              names.dedup();
+              (names, stringify_css_modules_exports_elements(elements))
+            })
+            .collect::<Vec<_>>(),
+        ));
+      }
+
+      // Prefix after css modules transform, so the prefixed copies of keyframes share the renamed name
+      if let Some(targets) = self.targets {
+        swc_compiler.prefix(&mut stylesheet, targets);
+      }
 
       let (code, map) = swc_compiler.codegen(
         &stylesheet,
@@ -160,10 +203,62 @@ impl ParserAndGenerator for CssParserAndGenerator {
 
     let mut dependencies = analyze_dependencies(
       &new_stylesheet_ast,
+      &source_code,
       code_generation_dependencies,
       &mut diagnostic_vec,
     );
 
+    self.local_class_rules = local_classes
+      .map(|local_classes| analyze_local_class_rules(&new_stylesheet_ast, &local_classes))
+      .unwrap_or_default();
+
+    let mut presentational_dependencies: Vec<Box<dyn DependencyTemplate>> = vec![];
+    if is_enable_css_modules {
+      let analysis = analyze_values(&new_stylesheet_ast, &source_code);
+      let mut value_requests = FxHashSet::default();
+      for (_, from) in analysis.values.values() {
+        if let Some(request) = from
+          && value_requests.insert(request)
+        {
+          dependencies.push(Box::new(CssValueImportDependency::new(
+            request.to_owned(),
+            None,
+          )));
+        }
+      }
+      presentational_dependencies.extend(analysis.removals.into_iter().map(|(start, end)| {
+        Box::new(ConstDependency::new(start, end, "".into(), None)) as Box<dyn DependencyTemplate>
+      }));
+      build_info.css_modules_values = Some(analysis.values);
+      self.value_references = analysis.references;
+    }
+
+    if let Some(exports) = &self.exports {
+      // Let `FlagDependencyUsagePlugin` track which locals are used, the unused ones are dropped
+      // in code generation
+      let names = exports
+        .keys()
+        .flatten()
+        .filter_map(|name| serde_json::from_str::<String>(name).ok())
+        .chain(
+          build_info
+            .css_modules_values
+            .iter()
+            .flatten()
+            .flat_map(|(name, _)| {
+              export_locals_convention(
+                &name.as_str().into(),
+                &self.config.modules.locals_convention,
+              )
+              .into_iter()
+              .filter_map(|name| serde_json::from_str::<String>(&name).ok())
+            }),
+        )
+        .map(JsWord::from)
+        .collect::<Vec<_>>();
+      dependencies.push(Box::new(StaticExportsDependency::new(names, false)));
+    }
+
     let dependencies = if let Some(locals) = &self.exports
       && !locals.is_empty()
     {
@@ -214,7 +309,7 @@ impl ParserAndGenerator for CssParserAndGenerator {
       ParseResult {
         dependencies,
         blocks: vec![],
-        presentational_dependencies: vec![],
+        presentational_dependencies,
         source: new_source,
         analyze_result: Default::default(),
       }
@@ -243,6 +338,7 @@ impl ParserAndGenerator for CssParserAndGenerator {
           runtime_requirements: generate_context.runtime_requirements,
           runtime: generate_context.runtime,
           init_fragments: &mut init_fragments,
+          concatenation_scope: generate_context.concatenation_scope.as_deref_mut(),
         };
 
         module.get_dependencies().iter().for_each(|id| {
@@ -262,14 +358,39 @@ impl ParserAndGenerator for CssParserAndGenerator {
             .for_each(|dependency| dependency.apply(&mut source, &mut context));
         };
 
+        for (start, end, name) in &self.value_references {
+          if let Some(value) = resolve_css_modules_value(name, module, compilation) {
+            source.replace(*start, *end, &value, None);
+          }
+        }
+
+        if let Some(exports) = &self.exports
+          && !self.local_class_rules.is_empty()
+        {
+          let used_classes =
+            used_css_modules_classes(exports, module, compilation, generate_context.runtime);
+          // A rule can't match any element when each of its selectors requires an unused class
+          for (start, end, selectors) in &self.local_class_rules {
+            if selectors.iter().all(|classes| {
+              classes
+                .iter()
+                .any(|class| !used_classes.contains(class.as_str()))
+            }) {
+              source.replace(*start, *end, "", None);
+            }
+          }
+        }
+
         Ok(source.boxed())
       }
       SourceType::JavaScript => {
         let locals = if let Some(exports) = &self.exports {
           css_modules_exports_to_string(
             exports,
+            &self.config.modules.locals_convention,
             module,
             generate_context.compilation,
+            generate_context.runtime,
             generate_context.runtime_requirements,
           )?
         } else if generate_context.compilation.options.dev_server.hot {
@@ -291,7 +412,11 @@ impl ParserAndGenerator for CssParserAndGenerator {
     result
   }
   fn store(&self, extra_data: &mut HashMap<BuildExtraDataType, AlignedVec>) {
-    let data = self.exports.to_owned();
+    let data = (
+      self.exports.to_owned(),
+      self.local_class_rules.to_owned(),
+      self.value_references.to_owned(),
+    );
     extra_data.insert(
       BuildExtraDataType::CssParserAndGenerator,
       to_bytes::<_, 1024>(&data).expect("Failed to store extra data"),
@@ -299,9 +424,12 @@ impl ParserAndGenerator for CssParserAndGenerator {
   }
   fn resume(&mut self, extra_data: &HashMap<BuildExtraDataType, AlignedVec>) {
     if let Some(data) = extra_data.get(&BuildExtraDataType::CssParserAndGenerator) {
-      let data = from_bytes::<Option<IndexMap<Vec<String>, Vec<(String, Option<String>)>>>>(data)
-        .expect("Failed to resume extra data");
-      self.exports = data;
+      let (exports, local_class_rules, value_references) =
+        from_bytes::<(CssExportsType, CssLocalClassRules, CssValueReferences)>(data)
+          .expect("Failed to resume extra data");
+      self.exports = exports;
+      self.local_class_rules = local_class_rules;
+      self.value_references = value_references;
     }
   }
 }
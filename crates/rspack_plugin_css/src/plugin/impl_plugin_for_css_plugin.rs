@@ -15,12 +15,15 @@ use rspack_core::{
   DependencyType, LibIdentOptions, PluginCompilationHookOutput, PluginContext,
   PluginRuntimeRequirementsInTreeOutput, PublicPath, RuntimeGlobals, RuntimeRequirementsInTreeArgs,
 };
-use rspack_error::{IntoTWithDiagnosticArray, Result};
+use rspack_error::{Diagnostic, IntoTWithDiagnosticArray, Result};
 use rspack_hash::RspackHash;
+use rspack_identifier::IdentifierMap;
 use rspack_plugin_runtime::is_enabled_for_chunk;
 
+use crate::dependency::CssImportConditions;
 use crate::parser_and_generator::CssParserAndGenerator;
 use crate::runtime::CssLoadingRuntimeModule;
+use crate::swc_css_compiler::resolve_css_targets;
 use crate::utils::AUTO_PUBLIC_PATH_PLACEHOLDER_REGEX;
 use crate::CssPlugin;
 
@@ -28,12 +31,19 @@ struct CssModuleDebugInfo<'a> {
   pub module: &'a dyn Module,
 }
 
+struct CssModuleRenderInfo<'a> {
+  pub debug_info: CssModuleDebugInfo<'a>,
+  pub import_conditions: Vec<CssImportConditions>,
+}
+
 impl CssPlugin {
   fn render_chunk_to_source(
     compilation: &Compilation,
     chunk: &Chunk,
     ordered_css_modules: &[&dyn Module],
-  ) -> rspack_error::Result<ConcatSource> {
+  ) -> rspack_error::Result<(ConcatSource, Vec<Diagnostic>)> {
+    let mut diagnostics = vec![];
+    let mut import_conditions_cache = IdentifierMap::default();
     let module_sources = ordered_css_modules
       .iter()
       .map(|module| {
@@ -42,11 +52,33 @@ impl CssPlugin {
           .code_generation_results
           .get(module_id, Some(&chunk.runtime));
 
-        Ok(
-          code_gen_result
-            .get(&SourceType::Css)
-            .map(|source| (CssModuleDebugInfo { module: *module }, source)),
-        )
+        Ok(code_gen_result.get(&SourceType::Css).map(|source| {
+          let import_conditions = Self::get_import_conditions(
+            module_id,
+            chunk,
+            compilation,
+            &mut import_conditions_cache,
+          )
+          .unwrap_or_else(|| {
+            diagnostics.push(Diagnostic::warn(
+              "Conflicting @import conditions".to_string(),
+              format!(
+                "{} is imported with different layer, supports or media conditions in chunk {}. \
+                 The module is only rendered once in the chunk, so its conditions are ignored.",
+                module.readable_identifier(&compilation.options.context),
+                chunk.expect_id(),
+              ),
+            ));
+            vec![]
+          });
+          (
+            CssModuleRenderInfo {
+              debug_info: CssModuleDebugInfo { module: *module },
+              import_conditions,
+            },
+            source,
+          )
+        }))
       })
       .collect::<Result<Vec<_>>>()?;
 
@@ -58,11 +90,15 @@ impl CssPlugin {
       .flatten()
       .fold(
         ConcatSource::default,
-        |mut acc, (debug_info, cur_source)| {
-          let (start, end) = Self::render_module_debug_info(compilation, &debug_info);
+        |mut acc, (render_info, cur_source)| {
+          let (start, end) = Self::render_module_debug_info(compilation, &render_info.debug_info);
+          let (conditions_start, conditions_end) =
+            Self::render_import_conditions(&render_info.import_conditions);
           acc.add(start);
+          acc.add(conditions_start);
           acc.add(cur_source.clone());
           acc.add(RawSource::from("\n"));
+          acc.add(conditions_end);
           acc.add(end);
           acc
         },
@@ -72,7 +108,35 @@ impl CssPlugin {
         acc
       });
 
-    Ok(source)
+    Ok((source, diagnostics))
+  }
+
+  /// Wraps the module in `@layer`, `@supports` and `@media` blocks, in the same order as the
+  /// conditions are applied by `@import "..." layer(...) supports(...) media`.
+  fn render_import_conditions(conditions: &[CssImportConditions]) -> (RawSource, RawSource) {
+    let mut start = String::new();
+    let mut end = String::new();
+
+    for condition in conditions {
+      if let Some(layer) = &condition.layer {
+        if layer.is_empty() {
+          start.push_str("@layer {\n");
+        } else {
+          start.push_str(&format!("@layer {layer} {{\n"));
+        }
+        end.push_str("}\n");
+      }
+      if let Some(supports) = &condition.supports {
+        start.push_str(&format!("@supports ({supports}) {{\n"));
+        end.push_str("}\n");
+      }
+      if let Some(media) = &condition.media {
+        start.push_str(&format!("@media {media} {{\n"));
+        end.push_str("}\n");
+      }
+    }
+
+    (RawSource::from(start), RawSource::from(end))
   }
 
   fn render_module_debug_info(
@@ -123,10 +187,14 @@ impl Plugin for CssPlugin {
     _options: &mut CompilerOptions,
   ) -> Result<()> {
     let config = self.config.clone();
+    let targets = resolve_css_targets(&config.targets)?;
     let builder = move || {
       Box::new(CssParserAndGenerator {
         config: config.clone(),
+        targets,
         exports: None,
+        local_class_rules: vec![],
+        value_references: vec![],
       }) as Box<dyn ParserAndGenerator>
     };
 
@@ -159,6 +227,10 @@ impl Plugin for CssPlugin {
       DependencyType::CssCompose,
       params.normal_module_factory.clone(),
     );
+    args.compilation.set_dependency_factory(
+      DependencyType::CssValueImport,
+      params.normal_module_factory.clone(),
+    );
     Ok(())
   }
 
@@ -223,7 +295,8 @@ impl Plugin for CssPlugin {
       return Ok(vec![].with_empty_diagnostic());
     }
 
-    let source = Self::render_chunk_to_source(compilation, chunk, &ordered_css_modules)?;
+    let (source, diagnostics) =
+      Self::render_chunk_to_source(compilation, chunk, &ordered_css_modules)?;
 
     let filename_template = get_css_chunk_filename_template(
       chunk,
@@ -266,7 +339,7 @@ impl Plugin for CssPlugin {
         false,
         false,
       )]
-      .with_empty_diagnostic(),
+      .with_diagnostic(diagnostics),
     )
   }
 
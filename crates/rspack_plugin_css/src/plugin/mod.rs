@@ -8,9 +8,13 @@ use bitflags::bitflags;
 use once_cell::sync::Lazy;
 use regex::Regex;
 use rspack_core::Filename;
-use rspack_core::{Chunk, ChunkGraph, Compilation, Module, ModuleGraph, PathData, SourceType};
+use rspack_core::{
+  Chunk, ChunkGraph, Compilation, Module, ModuleGraph, ModuleIdentifier, PathData, SourceType,
+};
 use rspack_error::error_bail;
-use rspack_identifier::IdentifierSet;
+use rspack_identifier::{IdentifierMap, IdentifierSet};
+
+use crate::dependency::{CssImportConditions, CssImportDependency};
 
 static ESCAPE_LOCAL_IDENT_REGEX: Lazy<Regex> =
   Lazy::new(|| Regex::new(r#"[<>:"/\\|?*\.]"#).expect("Invalid regex"));
@@ -101,6 +105,8 @@ impl Default for LocalsConvention {
 #[derive(Debug, Clone)]
 pub struct CssConfig {
   pub modules: ModulesConfig,
+  /// Browserslist queries, vendor prefixes are added and newer syntax is lowered for them.
+  pub targets: Vec<String>,
 }
 
 impl CssPlugin {
@@ -262,6 +268,111 @@ impl CssPlugin {
     }
     final_modules
   }
+
+  /// Collects the conditions of the `@import` chain which brings `module` into `chunk`, from the
+  /// outermost `@import` to the innermost one. Only the importers in the chunk are followed, and a
+  /// module which is also imported unconditionally is rendered without any. Returns `None` when the
+  /// module is imported with different conditions, which can't be satisfied since the module only
+  /// appears once in the chunk. The conditions of every visited module are memoized in `cache`.
+  pub(crate) fn get_import_conditions(
+    module: &ModuleIdentifier,
+    chunk: &Chunk,
+    compilation: &Compilation,
+    cache: &mut IdentifierMap<Option<Vec<CssImportConditions>>>,
+  ) -> Option<Vec<CssImportConditions>> {
+    Self::get_import_conditions_impl(
+      module,
+      chunk,
+      compilation,
+      cache,
+      &mut IdentifierSet::default(),
+    )
+  }
+
+  fn get_import_conditions_impl(
+    module: &ModuleIdentifier,
+    chunk: &Chunk,
+    compilation: &Compilation,
+    cache: &mut IdentifierMap<Option<Vec<CssImportConditions>>>,
+    visiting: &mut IdentifierSet,
+  ) -> Option<Vec<CssImportConditions>> {
+    if let Some(conditions) = cache.get(module) {
+      return conditions.clone();
+    }
+    let module_graph = &compilation.module_graph;
+    let Some(mgm) = module_graph.module_graph_module_by_identifier(module) else {
+      return Some(vec![]);
+    };
+    // Circular `@import`s don't add any conditions
+    if !visiting.insert(*module) {
+      return Some(vec![]);
+    }
+
+    let mut result: Option<Vec<CssImportConditions>> = None;
+    let mut unconditional = false;
+    let mut conflicting = false;
+    for connection in mgm
+      .incoming_connections
+      .iter()
+      .filter_map(|id| module_graph.connection_by_connection_id(id))
+    {
+      // Only the importers in the chunk bring the module into it, async imports load it elsewhere
+      let is_async = module_graph
+        .get_parent_block(&connection.dependency_id)
+        .is_some();
+      let in_chunk = match &connection.original_module_identifier {
+        Some(parent) => compilation
+          .chunk_graph
+          .is_module_in_chunk(parent, chunk.ukey),
+        None => compilation
+          .chunk_graph
+          .get_chunk_entry_modules(&chunk.ukey)
+          .contains(module),
+      };
+      if is_async || !in_chunk {
+        continue;
+      }
+
+      let dep = module_graph
+        .dependency_by_id(&connection.dependency_id)
+        .and_then(|dep| dep.downcast_ref::<CssImportDependency>())
+        .zip(connection.original_module_identifier.as_ref());
+      let conditions = match dep {
+        Some((dep, parent)) => {
+          // A parent imported with different conditions is rendered without any
+          let mut conditions =
+            Self::get_import_conditions_impl(parent, chunk, compilation, cache, visiting)
+              .unwrap_or_default();
+          if !dep.conditions().is_empty() {
+            conditions.push(dep.conditions().clone());
+          }
+          conditions
+        }
+        None => vec![],
+      };
+
+      if conditions.is_empty() {
+        unconditional = true;
+      }
+      match &result {
+        None => result = Some(conditions),
+        Some(prev) if *prev != conditions => conflicting = true,
+        _ => {}
+      }
+    }
+
+    visiting.remove(module);
+    // An unconditional import wins over any conditions
+    let conditions = if unconditional {
+      Some(vec![])
+    } else if conflicting {
+      None
+    } else {
+      Some(result.unwrap_or_default())
+    };
+    cache.insert(*module, conditions.clone());
+    conditions
+  }
 }
 
 #[derive(Debug)]
@@ -1,5 +1,8 @@
 use std::sync::Arc;
 
+use preset_env_base::query::{targets_to_versions, Query, Targets};
+use preset_env_base::version::{should_enable, Version};
+use preset_env_base::Versions;
 use rspack_core::rspack_sources::{self, SourceExt};
 use rspack_error::{error, Result};
 use swc_core::common::{input::SourceFileInput, source_map::SourceMapGenConfig, FileName};
@@ -8,10 +11,18 @@ use swc_core::css::codegen::{
   writer::basic::{BasicCssWriter, BasicCssWriterConfig},
   CodeGenerator, CodegenConfig, Emit,
 };
+use swc_core::css::compat::{
+  compiler::{Compiler as CompatCompiler, Config as CompatConfig},
+  feature::Features,
+};
 use swc_core::css::minifier;
 use swc_core::css::parser::{lexer::Lexer, parser::ParserConfig};
+use swc_core::css::prefixer::{options::Options as PrefixerOptions, prefixer};
+use swc_core::css::visit::VisitMutWith;
 use swc_core::css::{ast::Stylesheet, parser::parser::Parser};
 
+use crate::visitors::IsPseudoClassLowering;
+
 #[derive(Default)]
 pub struct SwcCssCompiler {
   cm: Arc<swc_core::common::SourceMap>,
@@ -68,6 +79,25 @@ impl SwcCssCompiler {
     }
   }
 
+  /// Lowers the syntax which is not supported by `targets`: nesting, custom media queries and
+  /// `:is()`. This should run before css modules transform so the flattened selectors are renamed.
+  pub fn lower_syntax(&self, ast: &mut Stylesheet, targets: CssTargets) {
+    let mut features = Features::CUSTOM_MEDIA;
+    if should_enable(targets, nesting_versions(), false) {
+      features |= Features::NESTING;
+    }
+    ast.visit_mut_with(&mut CompatCompiler::new(CompatConfig { process: features }));
+
+    if should_enable(targets, is_pseudo_class_versions(), false) {
+      ast.visit_mut_with(&mut IsPseudoClassLowering);
+    }
+  }
+
+  /// Adds the vendor prefixes required by `targets`.
+  pub fn prefix(&self, ast: &mut Stylesheet, targets: CssTargets) {
+    ast.visit_mut_with(&mut prefixer(PrefixerOptions { env: Some(targets) }));
+  }
+
   pub fn minify(
     &self,
     filename: &str,
@@ -124,3 +154,50 @@ impl SourceMapGenConfig for SwcCssSourceMapGenConfig {
     self.emit_columns
   }
 }
+
+/// Browser versions resolved from the browserslist queries of `builtins.css.targets`.
+pub type CssTargets = Versions;
+
+pub fn resolve_css_targets(queries: &[String]) -> Result<Option<CssTargets>> {
+  if queries.is_empty() {
+    return Ok(None);
+  }
+  targets_to_versions(Some(Targets::Query(Query::Multiple(queries.to_vec()))))
+    .map(Some)
+    .map_err(|e| error!("Failed to resolve css targets {queries:?}: {e}"))
+}
+
+fn version(major: u32, minor: u32) -> Option<Version> {
+  Some(Version {
+    major,
+    minor,
+    patch: 0,
+  })
+}
+
+/// The first versions supporting nesting without a leading `&` for type selectors.
+fn nesting_versions() -> Versions {
+  Versions {
+    chrome: version(120, 0),
+    edge: version(120, 0),
+    firefox: version(117, 0),
+    opera: version(106, 0),
+    safari: version(17, 2),
+    ios: version(17, 2),
+    samsung: version(25, 0),
+    ..Default::default()
+  }
+}
+
+fn is_pseudo_class_versions() -> Versions {
+  Versions {
+    chrome: version(88, 0),
+    edge: version(88, 0),
+    firefox: version(78, 0),
+    opera: version(74, 0),
+    safari: version(14, 0),
+    ios: version(14, 0),
+    samsung: version(15, 0),
+    ..Default::default()
+  }
+}
@@ -4,13 +4,18 @@ use heck::{ToKebabCase, ToLowerCamelCase};
 use indexmap::IndexMap;
 use once_cell::sync::Lazy;
 use regex::{Captures, Regex};
-use rspack_core::{Compilation, OutputOptions, PathData, RuntimeGlobals};
+use rspack_core::{
+  Compilation, ExportInfoProvided, ModuleGraphModule, OutputOptions, PathData, RuntimeGlobals,
+  RuntimeSpec, UsageState,
+};
 use rspack_error::{error, Result};
 use rspack_hash::{HashDigest, HashFunction, HashSalt, RspackHash};
+use rustc_hash::FxHashSet;
 use swc_core::css::modules::CssClassName;
 use swc_core::ecma::atoms::JsWord;
 
 use crate::plugin::{LocalIdentName, LocalIdentNameRenderOptions, LocalsConvention};
+use crate::visitors::replace_value_references;
 
 pub const AUTO_PUBLIC_PATH_PLACEHOLDER: &str = "__RSPACK_PLUGIN_CSS_AUTO_PUBLIC_PATH__";
 pub static AUTO_PUBLIC_PATH_PLACEHOLDER_REGEX: Lazy<Regex> =
@@ -49,11 +54,15 @@ impl swc_core::css::modules::TransformConfig for ModulesTransformConfig<'_> {
       self.filename.hash(&mut hasher);
       local.hash(&mut hasher);
       let hash = hasher.digest(self.hash_digest);
-      let hash = hash.rendered(self.hash_digest_length);
-      if hash.as_bytes()[0].is_ascii_digit() {
+      // base64 digests may contain `+`, `/` and `=`, which are not valid in css idents
+      let hash = hash.rendered(self.hash_digest_length).replace(
+        |c: char| !c.is_ascii_alphanumeric() && c != '_' && c != '-',
+        "_",
+      );
+      if hash.starts_with(|c: char| c.is_ascii_digit()) {
         format!("_{hash}")
       } else {
-        hash.into()
+        hash
       }
     };
     self
@@ -111,42 +120,156 @@ pub fn stringify_css_modules_exports_elements(
     .collect::<Vec<_>>()
 }
 
+/// Whether any name of a css modules local is used, the names are stringified as in the exports.
+fn is_css_modules_export_used(
+  names: &[String],
+  module: &dyn rspack_core::Module,
+  compilation: &Compilation,
+  runtime: Option<&RuntimeSpec>,
+) -> bool {
+  let module_graph = &compilation.module_graph;
+  let exports_info = module_graph.get_exports_info(&module.identifier());
+  names.iter().any(|name| {
+    let Ok(name) = serde_json::from_str::<String>(name) else {
+      return true;
+    };
+    exports_info
+      .id
+      .get_read_only_export_info(&name.into(), module_graph)
+      .get_used(runtime)
+      != UsageState::Unused
+  })
+}
+
+/// Returns the stringified classes which are referenced by the used css modules locals,
+/// including the classes they compose.
+pub(crate) fn used_css_modules_classes<'a>(
+  exports: &'a IndexMap<Vec<String>, Vec<(String, Option<String>)>>,
+  module: &dyn rspack_core::Module,
+  compilation: &Compilation,
+  runtime: Option<&RuntimeSpec>,
+) -> FxHashSet<&'a str> {
+  exports
+    .iter()
+    .filter(|(key, _)| is_css_modules_export_used(key, module, compilation, runtime))
+    .flat_map(|(_, elements)| elements)
+    .filter(|(_, from)| from.is_none())
+    .map(|(name, _)| name.as_str())
+    .collect()
+}
+
+/// Finds the module that a dependency of `module` with the given request resolves to.
+fn css_modules_dependency_module<'a>(
+  request: &str,
+  module: &dyn rspack_core::Module,
+  compilation: &'a Compilation,
+) -> Option<&'a ModuleGraphModule> {
+  module.get_dependencies().iter().find_map(|id| {
+    let dependency = compilation.module_graph.dependency_by_id(id);
+    let dependency_request = if let Some(d) = dependency.and_then(|d| d.as_module_dependency()) {
+      Some(d.request())
+    } else {
+      dependency
+        .and_then(|d| d.as_context_dependency())
+        .map(|d| d.request())
+    };
+    if let Some(dependency_request) = dependency_request
+      && dependency_request == request
+    {
+      return compilation
+        .module_graph
+        .module_graph_module_by_dependency_id(id);
+    }
+    None
+  })
+}
+
+/// Limits the depth of nested values, in case of circular `@value` imports.
+const MAX_CSS_MODULES_VALUE_DEPTH: usize = 32;
+
+/// Resolves a css modules value to its final text, following `@value` imports into other css
+/// modules and replacing the values referenced in its definition.
+pub(crate) fn resolve_css_modules_value(
+  name: &str,
+  module: &dyn rspack_core::Module,
+  compilation: &Compilation,
+) -> Option<String> {
+  resolve_css_modules_value_impl(name, module, compilation, 0)
+}
+
+fn resolve_css_modules_value_impl(
+  name: &str,
+  module: &dyn rspack_core::Module,
+  compilation: &Compilation,
+  depth: usize,
+) -> Option<String> {
+  if depth > MAX_CSS_MODULES_VALUE_DEPTH {
+    return None;
+  }
+  let values = module.build_info()?.css_modules_values.as_ref()?;
+  match values.get(name)? {
+    (remote, Some(request)) => {
+      let from = css_modules_dependency_module(request, module, compilation)?;
+      let from = compilation
+        .module_graph
+        .module_by_identifier(&from.module_identifier)?;
+      resolve_css_modules_value_impl(remote, from.as_ref(), compilation, depth + 1)
+    }
+    (value, None) => Some(replace_value_references(value, |symbol| {
+      if symbol != name && values.contains_key(symbol) {
+        resolve_css_modules_value_impl(symbol, module, compilation, depth + 1)
+      } else {
+        None
+      }
+    })),
+  }
+}
+
+/// Whether `name` is known not to be exported by the css module `from`, e.g. it's a `:global`
+/// class of `from`, then it's composed as it is.
+fn is_css_modules_global_name(
+  name: &str,
+  from: &ModuleGraphModule,
+  compilation: &Compilation,
+) -> bool {
+  let Ok(name) = serde_json::from_str::<String>(name) else {
+    return false;
+  };
+  let module_graph = &compilation.module_graph;
+  matches!(
+    module_graph
+      .get_exports_info(&from.module_identifier)
+      .id
+      .get_read_only_export_info(&name.into(), module_graph)
+      .provided,
+    Some(ExportInfoProvided::False)
+  )
+}
+
 pub fn css_modules_exports_to_string(
   exports: &IndexMap<Vec<String>, Vec<(String, Option<String>)>>,
+  locals_convention: &LocalsConvention,
   module: &dyn rspack_core::Module,
   compilation: &Compilation,
+  runtime: Option<&RuntimeSpec>,
   runtime_requirements: &mut RuntimeGlobals,
 ) -> Result<String> {
   runtime_requirements.insert(RuntimeGlobals::MODULE);
   let mut code = String::from("module.exports = {\n");
   for (key, elements) in exports {
+    if !is_css_modules_export_used(key, module, compilation, runtime) {
+      continue;
+    }
     let content = elements
       .iter()
       .map(|(name, from)| match from {
         None => name.to_owned(),
         Some(from_name) => {
-          let from = module
-            .get_dependencies()
-            .iter()
-            .find_map(|id| {
-              let dependency = compilation.module_graph.dependency_by_id(id);
-              let request = if let Some(d) = dependency.and_then(|d| d.as_module_dependency()) {
-                Some(d.request())
-              } else {
-                dependency
-                  .and_then(|d| d.as_context_dependency())
-                  .map(|d| d.request())
-              };
-              if let Some(request) = request
-                && request == from_name
-              {
-                return compilation
-                  .module_graph
-                  .module_graph_module_by_dependency_id(id);
-              }
-              None
-            })
+          let from = css_modules_dependency_module(from_name, module, compilation)
             .expect("should have css from module");
+          if is_css_modules_global_name(name, from, compilation) {
+            return name.to_owned();
+          }
 
           let from = serde_json::to_string(from.id(&compilation.chunk_graph)).expect("TODO:");
           runtime_requirements.insert(RuntimeGlobals::REQUIRE);
@@ -156,9 +279,27 @@ pub fn css_modules_exports_to_string(
       .collect::<Vec<_>>()
       .join(" + \" \" + ");
     for item in key {
+      if !is_css_modules_export_used(std::slice::from_ref(item), module, compilation, runtime) {
+        continue;
+      }
       writeln!(code, "  {}: {},", item, content).map_err(|e| error!(e.to_string()))?;
     }
   }
+  let values = module
+    .build_info()
+    .and_then(|build_info| build_info.css_modules_values.as_ref());
+  for name in values.into_iter().flat_map(|values| values.keys()) {
+    let Some(value) = resolve_css_modules_value(name, module, compilation) else {
+      continue;
+    };
+    let value = serde_json::to_string(&value).map_err(|e| error!(e.to_string()))?;
+    for item in export_locals_convention(&name.as_str().into(), locals_convention) {
+      if !is_css_modules_export_used(std::slice::from_ref(&item), module, compilation, runtime) {
+        continue;
+      }
+      writeln!(code, "  {}: {},", item, value).map_err(|e| error!(e.to_string()))?;
+    }
+  }
   code += "};\n";
   Ok(code)
 }
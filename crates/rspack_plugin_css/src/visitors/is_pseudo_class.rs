@@ -0,0 +1,148 @@
+use swc_core::css::ast::{
+  ComplexSelector, ComplexSelectorChildren, CompoundSelector, ForgivingComplexSelector,
+  PseudoClassSelectorChildren, SelectorList, SubclassSelector, TypeSelector,
+};
+use swc_core::css::visit::{VisitMut, VisitMutWith};
+
+/// Expands `:is()` whose arguments are all compound selectors into a selector list,
+/// e.g. `:is(.a, .b) > .c` into `.a > .c, .b > .c`.
+///
+/// `:is()` containing complex selectors can't be expanded without changing its meaning, and `:is()`
+/// takes the specificity of its most specific argument, so expanding arguments of different
+/// specificity, e.g. `:is(#a, .b)`, would lower the specificity of some of the selectors. Both are
+/// left as they are.
+#[derive(Debug, Default)]
+pub struct IsPseudoClassLowering;
+
+impl VisitMut for IsPseudoClassLowering {
+  fn visit_mut_selector_list(&mut self, n: &mut SelectorList) {
+    n.visit_mut_children_with(self);
+    n.children = n
+      .children
+      .drain(..)
+      .flat_map(expand_is_pseudo_class)
+      .collect();
+  }
+}
+
+fn expand_is_pseudo_class(selector: ComplexSelector) -> Vec<ComplexSelector> {
+  let Some((child_index, subclass_index, alternatives)) = find_lowerable_is(&selector) else {
+    return vec![selector];
+  };
+
+  alternatives
+    .into_iter()
+    .flat_map(|alternative| {
+      let mut expanded = selector.clone();
+      if let ComplexSelectorChildren::CompoundSelector(compound) =
+        &mut expanded.children[child_index]
+      {
+        compound.subclass_selectors.remove(subclass_index);
+        if alternative.type_selector.is_some() {
+          compound.type_selector = alternative.type_selector;
+        }
+        compound.subclass_selectors.splice(
+          subclass_index..subclass_index,
+          alternative.subclass_selectors,
+        );
+      }
+      // Lower the remaining `:is()` in the same selector
+      expand_is_pseudo_class(expanded)
+    })
+    .collect()
+}
+
+/// Returns the position of the first `:is()` which can be expanded, and the compound selectors in
+/// its arguments.
+fn find_lowerable_is(selector: &ComplexSelector) -> Option<(usize, usize, Vec<CompoundSelector>)> {
+  selector
+    .children
+    .iter()
+    .enumerate()
+    .find_map(|(child_index, child)| {
+      let ComplexSelectorChildren::CompoundSelector(compound) = child else {
+        return None;
+      };
+      compound
+        .subclass_selectors
+        .iter()
+        .enumerate()
+        .find_map(|(subclass_index, subclass)| {
+          let SubclassSelector::PseudoClass(pseudo) = subclass else {
+            return None;
+          };
+          if !pseudo.name.value.eq_ignore_ascii_case("is") {
+            return None;
+          }
+          let arguments: Vec<&ComplexSelector> = match pseudo.children.as_deref()? {
+            [PseudoClassSelectorChildren::ForgivingSelectorList(list)] => list
+              .children
+              .iter()
+              .map(|item| match item {
+                ForgivingComplexSelector::ComplexSelector(selector) => Some(selector),
+                ForgivingComplexSelector::ListOfComponentValues(_) => None,
+              })
+              .collect::<Option<_>>()?,
+            [PseudoClassSelectorChildren::SelectorList(list)] => list.children.iter().collect(),
+            _ => return None,
+          };
+          let alternatives = arguments
+            .into_iter()
+            .map(|argument| match argument.children.as_slice() {
+              // Two type selectors can't be merged into one compound selector
+              [ComplexSelectorChildren::CompoundSelector(alternative)]
+                if alternative.nesting_selector.is_none()
+                  && (alternative.type_selector.is_none() || compound.type_selector.is_none()) =>
+              {
+                Some(alternative.clone())
+              }
+              _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+          let first = specificity(alternatives.first()?)?;
+          if alternatives
+            .iter()
+            .any(|alternative| specificity(alternative) != Some(first))
+          {
+            return None;
+          }
+          Some((child_index, subclass_index, alternatives))
+        })
+    })
+}
+
+/// The specificity of a compound selector as `(ids, classes, types)`, `None` when it contains a
+/// pseudo-class taking selectors, whose specificity depends on its arguments.
+fn specificity(compound: &CompoundSelector) -> Option<(u32, u32, u32)> {
+  let mut specificity = (0, 0, 0);
+  if matches!(
+    compound.type_selector.as_deref(),
+    Some(TypeSelector::TagName(_))
+  ) {
+    specificity.2 += 1;
+  }
+  for subclass in &compound.subclass_selectors {
+    match subclass {
+      SubclassSelector::Id(_) => specificity.0 += 1,
+      SubclassSelector::Class(_) | SubclassSelector::Attribute(_) => specificity.1 += 1,
+      SubclassSelector::PseudoClass(pseudo) => {
+        let takes_selectors = pseudo.children.iter().flatten().any(|child| {
+          !matches!(
+            child,
+            PseudoClassSelectorChildren::PreservedToken(_)
+              | PseudoClassSelectorChildren::AnPlusB(_)
+              | PseudoClassSelectorChildren::Ident(_)
+              | PseudoClassSelectorChildren::Str(_)
+              | PseudoClassSelectorChildren::Delimiter(_)
+          )
+        });
+        if takes_selectors {
+          return None;
+        }
+        specificity.1 += 1;
+      }
+      SubclassSelector::PseudoElement(_) => specificity.2 += 1,
+    }
+  }
+  Some(specificity)
+}
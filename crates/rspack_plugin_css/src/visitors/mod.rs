@@ -1,17 +1,26 @@
+mod is_pseudo_class;
+mod values;
+
+pub use is_pseudo_class::IsPseudoClassLowering;
 use once_cell::sync::Lazy;
 use regex::Regex;
 use rspack_core::{BoxDependency, ModuleDependency, SpanExt};
 use rspack_error::miette::{diagnostic, Diagnostic, Severity};
 use rspack_error::DiagnosticExt;
+use rustc_hash::FxHashSet;
 use swc_core::common::Span;
 use swc_core::css::ast::{
-  AtRule, AtRuleName, Function, ImportHref, ImportPrelude, Stylesheet, Token, TokenAndSpan, Url,
-  UrlValue,
+  AtRule, AtRuleName, ComplexSelector, ComplexSelectorChildren, Function, ImportHref,
+  ImportLayerName, ImportPrelude, QualifiedRule, QualifiedRulePrelude, Stylesheet,
+  SubclassSelector, SupportsCondition, Token, TokenAndSpan, Url, UrlValue,
 };
 use swc_core::css::visit::{Visit, VisitWith};
+use swc_core::ecma::atoms::JsWord;
+pub(crate) use values::{analyze_values, replace_value_references};
 
 use crate::{
-  dependency::{CssImportDependency, CssUrlDependency},
+  dependency::{CssImportConditions, CssImportDependency, CssUrlDependency},
+  parser_and_generator::CssLocalClassRules,
   utils::normalize_url,
 };
 
@@ -19,16 +28,18 @@ static IS_MODULE_REQUEST: Lazy<Regex> = Lazy::new(|| Regex::new(r"^~").expect("T
 
 pub fn analyze_dependencies(
   ss: &Stylesheet,
+  source: &str,
   code_generation_dependencies: &mut Vec<Box<dyn ModuleDependency>>,
   diagnostics: &mut Vec<Box<dyn Diagnostic + Send + Sync>>,
 ) -> Vec<BoxDependency> {
   let mut v = Analyzer {
+    source,
     deps: Vec::new(),
     code_generation_dependencies,
     diagnostics,
     nearest_at_import_span: None,
     url_function_span: None,
-    // in_support_contdition: false,
+    in_supports_condition: false,
   };
   ss.visit_with(&mut v);
 
@@ -37,12 +48,13 @@ pub fn analyze_dependencies(
 
 #[derive(Debug)]
 struct Analyzer<'a> {
+  source: &'a str,
   deps: Vec<BoxDependency>,
   code_generation_dependencies: &'a mut Vec<Box<dyn ModuleDependency>>,
   diagnostics: &'a mut Vec<Box<dyn Diagnostic + Send + Sync>>,
   nearest_at_import_span: Option<Span>,
   url_function_span: Option<Span>,
-  // in_support_contdition: bool,
+  in_supports_condition: bool,
 }
 
 fn replace_module_request_prefix(
@@ -79,6 +91,55 @@ impl Analyzer<'_> {
     self.deps.push(dep.clone());
     self.code_generation_dependencies.push(dep);
   }
+
+  fn source_text(&self, span: Span) -> &str {
+    self
+      .source
+      .get(span.real_lo() as usize..span.real_hi() as usize)
+      .unwrap_or_default()
+      .trim()
+  }
+
+  /// Returns the text between the parentheses of `layer(...)` or `supports(...)`.
+  fn function_arguments(&self, f: &Function) -> String {
+    let text = self.source_text(f.span);
+    text
+      .find('(')
+      .zip(text.rfind(')'))
+      .and_then(|(start, end)| text.get(start + 1..end))
+      .unwrap_or_default()
+      .trim()
+      .to_string()
+  }
+
+  fn import_conditions(&self, n: &ImportPrelude) -> CssImportConditions {
+    let layer = n.layer_name.as_ref().map(|box layer| match layer {
+      ImportLayerName::Ident(_) => String::new(),
+      ImportLayerName::Function(f) => self.function_arguments(f),
+    });
+    let (supports, media) = n
+      .import_conditions
+      .as_ref()
+      .map(|box conditions| {
+        (
+          conditions
+            .supports
+            .as_ref()
+            .map(|box f| self.function_arguments(f)),
+          conditions
+            .media
+            .as_ref()
+            .map(|box media| self.source_text(media.span).to_string())
+            .filter(|media| !media.is_empty()),
+        )
+      })
+      .unwrap_or_default();
+    CssImportConditions {
+      layer,
+      supports,
+      media,
+    }
+  }
 }
 
 impl Visit for Analyzer<'_> {
@@ -107,6 +168,7 @@ impl Visit for Analyzer<'_> {
       let specifier = replace_module_request_prefix(specifier, self.diagnostics);
       self.deps.push(Box::new(CssImportDependency::new(
         specifier,
+        self.import_conditions(n),
         Some(span.into()),
         span.real_lo(),
         span.real_hi(),
@@ -114,16 +176,12 @@ impl Visit for Analyzer<'_> {
     }
   }
 
-  // Wait for @supports
-  // fn visit_supports_condition<'ast: 'r, 'r>(
-  //   &mut self,
-  //   n: &'ast swc_core::css::ast::SupportsCondition,
-  //   ast_path: &mut swc_core::css::visit::AstNodePath<'r>,
-  // ) {
-  //   self.in_support_contdition = true;
-  //   n.visit_children_with_path(self, ast_path);
-  //   self.in_support_contdition = false;
-  // }
+  /// `url()` in `@supports` conditions only tests the feature, it is not a request.
+  fn visit_supports_condition(&mut self, n: &SupportsCondition) {
+    self.in_supports_condition = true;
+    n.visit_children_with(self);
+    self.in_supports_condition = false;
+  }
 
   /// Handle `url("...")` in css variables.
   ///
@@ -163,6 +221,9 @@ impl Visit for Analyzer<'_> {
   }
 
   fn visit_token_and_span(&mut self, t: &TokenAndSpan) {
+    if self.in_supports_condition {
+      return;
+    }
     match &t.token {
       Token::Url { value, .. } => self.analyze_url(value.as_ref(), t.span),
       Token::String { value, .. } if let Some(span) = self.url_function_span => {
@@ -174,8 +235,9 @@ impl Visit for Analyzer<'_> {
 
   fn visit_url(&mut self, u: &Url) {
     u.visit_children_with(self);
-    // Wait for @supports
-    // if !self.in_support_contdition {
+    if self.in_supports_condition {
+      return;
+    }
     let specifier = u.value.as_ref().map(|box v| match v {
       UrlValue::Str(s) => s.value.to_string(),
       UrlValue::Raw(r) => r.value.to_string(),
@@ -187,3 +249,63 @@ impl Visit for Analyzer<'_> {
     }
   }
 }
+
+/// Collects the rules whose selectors all contain the css modules `local_classes`, the rules nested
+/// in other rules are removed together with their parent so they are not collected.
+pub(crate) fn analyze_local_class_rules(
+  ss: &Stylesheet,
+  local_classes: &FxHashSet<JsWord>,
+) -> CssLocalClassRules {
+  let mut v = LocalClassRulesAnalyzer {
+    local_classes,
+    rules: Vec::new(),
+  };
+  ss.visit_with(&mut v);
+
+  v.rules
+}
+
+struct LocalClassRulesAnalyzer<'a> {
+  local_classes: &'a FxHashSet<JsWord>,
+  rules: CssLocalClassRules,
+}
+
+impl LocalClassRulesAnalyzer<'_> {
+  /// Classes in pseudo class arguments, like `:not(.a)`, don't need to match, so only the classes
+  /// of the compound selectors are collected.
+  fn local_classes_of(&self, selector: &ComplexSelector) -> Vec<String> {
+    selector
+      .children
+      .iter()
+      .filter_map(|child| match child {
+        ComplexSelectorChildren::CompoundSelector(compound) => Some(compound),
+        ComplexSelectorChildren::Combinator(_) => None,
+      })
+      .flat_map(|compound| &compound.subclass_selectors)
+      .filter_map(|subclass| match subclass {
+        SubclassSelector::Class(class) if self.local_classes.contains(&class.text.value) => {
+          serde_json::to_string(&class.text.value).ok()
+        }
+        _ => None,
+      })
+      .collect()
+  }
+}
+
+impl Visit for LocalClassRulesAnalyzer<'_> {
+  fn visit_qualified_rule(&mut self, n: &QualifiedRule) {
+    if let QualifiedRulePrelude::SelectorList(list) = &n.prelude {
+      let selectors = list
+        .children
+        .iter()
+        .map(|selector| self.local_classes_of(selector))
+        .collect::<Vec<_>>();
+      // A selector without local classes always keeps the rule
+      if selectors.iter().all(|classes| !classes.is_empty()) {
+        self
+          .rules
+          .push((n.span.real_lo(), n.span.real_hi(), selectors));
+      }
+    }
+  }
+}
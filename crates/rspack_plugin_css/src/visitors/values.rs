@@ -0,0 +1,277 @@
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+use rspack_core::SpanExt;
+use swc_core::common::{Span, Spanned};
+use swc_core::css::ast::{
+  AtRule, AtRuleName, ComplexSelectorChildren, ComponentValue, CompoundSelector, Declaration,
+  Dimension, Function, Ident, QualifiedRule, QualifiedRulePrelude, Rule, Stylesheet,
+  SubclassSelector, Token, TokenAndSpan, Url,
+};
+use swc_core::css::visit::{Visit, VisitWith};
+
+use crate::parser_and_generator::{CssModuleValues, CssValueReferences};
+
+static VALUE_IMPORT: Lazy<Regex> = Lazy::new(|| {
+  Regex::new(r#"^(?s)(.+?|\(.+?\))\s+from\s+("[^"]*"|'[^']*'|[\w-]+)$"#).expect("Invalid regex")
+});
+static VALUE_IMPORT_ITEM: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"^([\w-]+)(?:\s+as\s+([\w-]+))?$").expect("Invalid regex"));
+static VALUE_DEFINITION: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"^(?s)([\w-]+)\s*:?\s*(.*)$").expect("Invalid regex"));
+/// The tokens of a value definition, only the identifiers which are not function names, i.e. the
+/// last alternative without a following `(`, can refer to other values.
+static VALUE_TOKEN: Lazy<Regex> = Lazy::new(|| {
+  Regex::new(
+    r#""(?:[^"\\]|\\.)*"|'(?:[^'\\]|\\.)*'|(?i:url)\([^)]*\)|#[\w-]+|-?\.?\d[\w.%-]*|(-?[A-Za-z_][\w-]*)(\()?"#,
+  )
+  .expect("Invalid regex")
+});
+
+static VALUE_KEYWORD: &str = "value";
+static EXPORT_KEYWORD: &str = "export";
+
+#[derive(Debug, Default)]
+pub(crate) struct CssModuleValuesAnalysis {
+  pub values: CssModuleValues,
+  pub references: CssValueReferences,
+  /// Spans of `@value` rules and `:export` blocks, which should be removed from the stylesheet.
+  pub removals: Vec<(u32, u32)>,
+}
+
+/// Collects the ICSS values of a css modules stylesheet: `@value` definitions and imports, and
+/// the `:export` blocks. The references of the values in declarations, `@media` and `@supports`
+/// are collected as well, so they can be replaced in code generation.
+pub(crate) fn analyze_values(ss: &Stylesheet, source: &str) -> CssModuleValuesAnalysis {
+  let mut v = ValuesAnalyzer {
+    source,
+    analysis: Default::default(),
+  };
+  for rule in &ss.rules {
+    match rule {
+      Rule::AtRule(at_rule) if is_value_rule(at_rule) => v.analyze_value_rule(at_rule),
+      Rule::QualifiedRule(rule) if is_export_rule(rule) => v.analyze_export_rule(rule),
+      _ => {}
+    }
+  }
+  if !v.analysis.values.is_empty() {
+    ss.visit_with(&mut v);
+  }
+
+  v.analysis
+}
+
+/// Replaces the values referenced in the text of a value definition, strings, urls, hashes,
+/// numbers and function names are kept as they are.
+pub(crate) fn replace_value_references(
+  text: &str,
+  mut replace: impl FnMut(&str) -> Option<String>,
+) -> String {
+  VALUE_TOKEN
+    .replace_all(text, |caps: &Captures| {
+      caps
+        .get(1)
+        .filter(|_| caps.get(2).is_none())
+        .and_then(|ident| replace(ident.as_str()))
+        .unwrap_or_else(|| caps[0].to_string())
+    })
+    .into_owned()
+}
+
+struct ValuesAnalyzer<'a> {
+  source: &'a str,
+  analysis: CssModuleValuesAnalysis,
+}
+
+fn is_value_rule(n: &AtRule) -> bool {
+  matches!(&n.name, AtRuleName::Ident(ident) if ident.value.eq_ignore_ascii_case(VALUE_KEYWORD))
+}
+
+/// Whether the rule is an ICSS `:export { ... }` block.
+fn is_export_rule(n: &QualifiedRule) -> bool {
+  let QualifiedRulePrelude::SelectorList(list) = &n.prelude else {
+    return false;
+  };
+  let [selector] = list.children.as_slice() else {
+    return false;
+  };
+  let [ComplexSelectorChildren::CompoundSelector(CompoundSelector {
+    nesting_selector: None,
+    type_selector: None,
+    subclass_selectors,
+    ..
+  })] = selector.children.as_slice()
+  else {
+    return false;
+  };
+  matches!(
+    subclass_selectors.as_slice(),
+    [SubclassSelector::PseudoClass(pseudo)]
+      if pseudo.children.is_none() && pseudo.name.value.eq_ignore_ascii_case(EXPORT_KEYWORD)
+  )
+}
+
+impl ValuesAnalyzer<'_> {
+  fn source_text(&self, span: Span) -> &str {
+    self
+      .source
+      .get(span.real_lo() as usize..span.real_hi() as usize)
+      .unwrap_or_default()
+  }
+
+  /// The span of a statement at-rule may not contain the trailing `;`.
+  fn removal_end(&self, span: Span) -> u32 {
+    let end = span.real_hi();
+    let rest = self.source.get(end as usize..).unwrap_or_default();
+    let trimmed = rest.trim_start();
+    if trimmed.starts_with(';') {
+      end + (rest.len() - trimmed.len()) as u32 + 1
+    } else {
+      end
+    }
+  }
+
+  fn analyze_value_rule(&mut self, n: &AtRule) {
+    self
+      .analysis
+      .removals
+      .push((n.span.real_lo(), self.removal_end(n.span)));
+
+    let text = self.source_text(n.span).trim();
+    let text = text
+      .get(1 + VALUE_KEYWORD.len()..)
+      .unwrap_or_default()
+      .trim()
+      .trim_end_matches(';')
+      .trim();
+
+    if let Some(captures) = VALUE_IMPORT.captures(text) {
+      let from = &captures[2];
+      let request = if from.starts_with(['"', '\'']) {
+        from[1..from.len() - 1].to_string()
+      } else if let Some((path, None)) = self.analysis.values.get(from) {
+        // `@value colors: "./colors.css"; @value primary from colors;`
+        path.trim_matches(['"', '\'']).to_string()
+      } else {
+        return;
+      };
+      let items = captures[1].trim();
+      let items = items
+        .strip_prefix('(')
+        .and_then(|items| items.strip_suffix(')'))
+        .unwrap_or(items);
+      for item in items.split(',') {
+        if let Some(captures) = VALUE_IMPORT_ITEM.captures(item.trim()) {
+          let remote = captures[1].to_string();
+          let local = captures
+            .get(2)
+            .map_or_else(|| remote.clone(), |local| local.as_str().to_string());
+          self
+            .analysis
+            .values
+            .insert(local, (remote, Some(request.clone())));
+        }
+      }
+    } else if let Some(captures) = VALUE_DEFINITION.captures(text) {
+      self.analysis.values.insert(
+        captures[1].to_string(),
+        (captures[2].trim().to_string(), None),
+      );
+    }
+  }
+
+  fn analyze_export_rule(&mut self, n: &QualifiedRule) {
+    self
+      .analysis
+      .removals
+      .push((n.span.real_lo(), n.span.real_hi()));
+
+    for component in &n.block.value {
+      let ComponentValue::Declaration(declaration) = component else {
+        continue;
+      };
+      let name = self.source_text(declaration.name.span()).trim().to_string();
+      let value = match (declaration.value.first(), declaration.value.last()) {
+        (Some(first), Some(last)) => self
+          .source
+          .get(first.span().real_lo() as usize..last.span().real_hi() as usize)
+          .unwrap_or_default()
+          .trim()
+          .to_string(),
+        _ => String::new(),
+      };
+      if !name.is_empty() {
+        self.analysis.values.insert(name, (value, None));
+      }
+    }
+  }
+
+  fn references_analyzer(&mut self) -> ValueReferencesAnalyzer<'_> {
+    ValueReferencesAnalyzer {
+      values: &self.analysis.values,
+      references: &mut self.analysis.references,
+    }
+  }
+}
+
+impl Visit for ValuesAnalyzer<'_> {
+  fn visit_at_rule(&mut self, n: &AtRule) {
+    if is_value_rule(n) {
+      return;
+    }
+    if let AtRuleName::Ident(ident) = &n.name
+      && (ident.value.eq_ignore_ascii_case("media") || ident.value.eq_ignore_ascii_case("supports"))
+    {
+      n.prelude.visit_with(&mut self.references_analyzer());
+    }
+    n.block.visit_with(self);
+  }
+
+  fn visit_qualified_rule(&mut self, n: &QualifiedRule) {
+    if is_export_rule(n) {
+      return;
+    }
+    n.visit_children_with(self);
+  }
+
+  fn visit_declaration(&mut self, n: &Declaration) {
+    n.value.visit_with(&mut self.references_analyzer());
+  }
+}
+
+/// Collects the identifiers which refer to values, the names of functions and the units of
+/// dimensions are identifiers as well but never refer to values, and strings and urls don't
+/// contain any identifier.
+struct ValueReferencesAnalyzer<'a> {
+  values: &'a CssModuleValues,
+  references: &'a mut CssValueReferences,
+}
+
+impl ValueReferencesAnalyzer<'_> {
+  fn add_reference(&mut self, name: &str, span: Span) {
+    if self.values.contains_key(name) {
+      self
+        .references
+        .push((span.real_lo(), span.real_hi(), name.to_string()));
+    }
+  }
+}
+
+impl Visit for ValueReferencesAnalyzer<'_> {
+  fn visit_ident(&mut self, n: &Ident) {
+    self.add_reference(&n.value, n.span);
+  }
+
+  fn visit_token_and_span(&mut self, n: &TokenAndSpan) {
+    if let Token::Ident { value, .. } = &n.token {
+      self.add_reference(value, n.span);
+    }
+  }
+
+  fn visit_function(&mut self, n: &Function) {
+    n.value.visit_with(self);
+  }
+
+  fn visit_dimension(&mut self, _: &Dimension) {}
+
+  fn visit_url(&mut self, _: &Url) {}
+}
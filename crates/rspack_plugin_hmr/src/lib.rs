@@ -119,19 +119,23 @@ impl Plugin for HotModuleReplacementPlugin {
     let mut updated_runtime_modules: IdentifierSet = Default::default();
     let mut completely_removed_modules: HashSet<String> = Default::default();
 
-    for (old_uri, (old_hash, old_module_id)) in &old_all_modules {
-      if let Some((now_hash, _)) = now_all_modules.get(old_uri) {
+    let now_module_ids = now_all_modules
+      .iter()
+      .map(|(identifier, (now_hash, module_id))| (module_id.as_str(), (*identifier, now_hash)))
+      .collect::<HashMap<_, _>>();
+    for (old_module_id, old_hash) in &old_all_modules {
+      if let Some((identifier, now_hash)) = now_module_ids.get(old_module_id.as_str()) {
         // updated
-        if now_hash != old_hash {
-          updated_modules.insert(*old_uri);
+        if *now_hash != old_hash {
+          updated_modules.insert(*identifier);
         }
       } else {
         // deleted
         completely_removed_modules.insert(old_module_id.to_string());
       }
     }
-    for identifier in now_all_modules.keys() {
-      if !old_all_modules.contains_key(identifier) {
+    for (module_id, (identifier, _)) in &now_module_ids {
+      if !old_all_modules.contains_key(*module_id) {
         // added
         updated_modules.insert(*identifier);
       }
@@ -142,10 +146,10 @@ impl Plugin for HotModuleReplacementPlugin {
     //   updated_modules, completely_removed_modules
     // );
 
-    for (identifier, old_runtime_module_content) in &old_runtime_modules {
-      if let Some(new_runtime_module_content) = now_runtime_modules.get(identifier) {
+    for (identifier, old_runtime_module_hash) in &old_runtime_modules {
+      if let Some(new_runtime_module_hash) = now_runtime_modules.get(identifier) {
         // updated
-        if new_runtime_module_content != old_runtime_module_content {
+        if new_runtime_module_hash != old_runtime_module_hash {
           updated_runtime_modules.insert(*identifier);
         }
       }
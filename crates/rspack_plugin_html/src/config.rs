@@ -1,4 +1,8 @@
-use std::{collections::HashMap, path::PathBuf, str::FromStr};
+use std::{
+  collections::HashMap,
+  path::{Path, PathBuf},
+  str::FromStr,
+};
 
 use rspack_core::{Compilation, PublicPath};
 #[cfg(feature = "testing")]
@@ -62,6 +66,31 @@ impl FromStr for HtmlScriptLoading {
   }
 }
 
+#[cfg_attr(feature = "testing", derive(JsonSchema))]
+#[derive(Deserialize, Debug, Default, Clone)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct HtmlResourceHintsOptions {
+  /// file extensions to inject, e.g. `js`, `css`, all files are injected if not set
+  pub include: Option<Vec<String>>,
+  /// file extensions not to inject
+  pub exclude: Option<Vec<String>>,
+}
+
+impl HtmlResourceHintsOptions {
+  pub fn is_included(&self, filename: &str) -> bool {
+    let extension = Path::new(filename)
+      .extension()
+      .map(|extension| extension.to_string_lossy())
+      .unwrap_or_default();
+    let matches = |extensions: &Vec<String>| {
+      extensions
+        .iter()
+        .any(|e| e.trim_start_matches('.').eq_ignore_ascii_case(&extension))
+    };
+    self.include.as_ref().map_or(true, matches) && !self.exclude.as_ref().map_or(false, matches)
+  }
+}
+
 #[cfg_attr(feature = "testing", derive(JsonSchema))]
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
@@ -72,7 +101,8 @@ pub struct HtmlRspackPluginOptions {
   /// template html file
   pub template: Option<String>,
   pub template_content: Option<String>,
-  pub template_parameters: Option<HashMap<String, String>>,
+  /// data available in the template, in addition to `htmlRspackPlugin`
+  pub template_parameters: Option<HashMap<String, serde_json::Value>>,
   /// `head`, `body`, `false`
   #[serde(default = "default_inject")]
   pub inject: HtmlInject,
@@ -94,6 +124,12 @@ pub struct HtmlRspackPluginOptions {
   pub title: Option<String>,
   pub favicon: Option<String>,
   pub meta: Option<HashMap<String, HashMap<String, String>>>,
+
+  /// inject `<link rel="preload">` for the async chunks marked with `webpackPreload`,
+  /// `<link rel="modulepreload">` is used for scripts if `output.module` is enabled
+  pub preload: Option<HtmlResourceHintsOptions>,
+  /// inject `<link rel="prefetch">` for the async chunks marked with `webpackPrefetch`
+  pub prefetch: Option<HtmlResourceHintsOptions>,
 }
 
 fn default_filename() -> String {
@@ -125,6 +161,8 @@ impl Default for HtmlRspackPluginOptions {
       title: None,
       favicon: None,
       meta: None,
+      preload: None,
+      prefetch: None,
     }
   }
 }
@@ -8,14 +8,16 @@ use std::{
 use anyhow::Context;
 use async_trait::async_trait;
 use dojang::dojang::Dojang;
+use itertools::Itertools;
 use rayon::prelude::*;
 use rspack_core::{
   parse_to_url,
   rspack_sources::{RawSource, SourceExt},
-  CompilationAsset, Filename, PathData, Plugin,
+  ChunkGroupOrderKey, Compilation, CompilationAsset, Entrypoint, Filename, PathData, Plugin,
 };
-use rspack_error::AnyhowError;
+use rspack_error::{error, AnyhowError};
 use serde::Deserialize;
+use serde_json::{json, Map, Value};
 use swc_html::visit::VisitMutWith;
 
 use crate::{
@@ -95,25 +97,7 @@ impl Plugin for HtmlRspackPlugin {
       )
     };
 
-    // process with template parameters
-    let template_result = if let Some(template_parameters) = &self.config.template_parameters {
-      let mut dj = Dojang::new();
-      dj.add(url.clone(), content)
-        .expect("failed to add template");
-      dj.render(&url, serde_json::json!(template_parameters))
-        .expect("failed to render template")
-    } else {
-      content
-    };
-
-    let ast_with_diagnostic = parser.parse_file(&url, template_result)?;
-
-    let (mut current_ast, diagnostic) = ast_with_diagnostic.split_into_parts();
-
-    if !diagnostic.is_empty() {
-      compilation.push_batch_diagnostic(diagnostic);
-    }
-    let included_assets = compilation
+    let included_entrypoints = compilation
       .entrypoints
       .keys()
       .filter(|&entry_name| {
@@ -127,12 +111,86 @@ impl Plugin for HtmlRspackPlugin {
         included
       })
       .map(|entry_name| compilation.entrypoint_by_name(entry_name))
+      .collect::<Vec<_>>();
+    let included_asset_names = included_entrypoints
+      .iter()
       .flat_map(|entry| entry.get_files(&compilation.chunk_by_ukey))
-      .map(|asset_name| {
-        (
-          asset_name.clone(),
-          compilation.assets().get(&asset_name).expect("TODO:"),
+      .collect::<Vec<_>>();
+
+    let public_path = config.get_public_path(compilation, &self.config.filename);
+
+    let mut resource_hint_tags = vec![];
+    if !matches!(config.inject, HtmlInject::False) {
+      if let Some(preload) = &config.preload {
+        for file in get_async_chunk_files(
+          &included_entrypoints,
+          &ChunkGroupOrderKey::Preload,
+          compilation,
         )
+        .into_iter()
+        .filter(|file| preload.is_included(file))
+        {
+          let href = format!("{public_path}{file}");
+          let extension = Path::new(&file).extension().unwrap_or_default();
+          let tag = if extension.eq_ignore_ascii_case("css") {
+            HTMLPluginTag::create_resource_hint(&href, "preload", Some("style"))
+          } else if extension.eq_ignore_ascii_case("js") || extension.eq_ignore_ascii_case("mjs") {
+            if compilation.options.output.module {
+              HTMLPluginTag::create_resource_hint(&href, "modulepreload", None)
+            } else {
+              HTMLPluginTag::create_resource_hint(&href, "preload", Some("script"))
+            }
+          } else {
+            HTMLPluginTag::create_resource_hint(&href, "preload", None)
+          };
+          resource_hint_tags.push(tag);
+        }
+      }
+      if let Some(prefetch) = &config.prefetch {
+        for file in get_async_chunk_files(
+          &included_entrypoints,
+          &ChunkGroupOrderKey::Prefetch,
+          compilation,
+        )
+        .into_iter()
+        .filter(|file| prefetch.is_included(file))
+        {
+          let href = format!("{public_path}{file}");
+          resource_hint_tags.push(HTMLPluginTag::create_resource_hint(&href, "prefetch", None));
+        }
+      }
+    }
+
+    // process with template parameters, templates without them are kept as they are so that
+    // the ones containing `<%` literally are not broken
+    let template_result = if config.template_parameters.is_some() {
+      let template_parameters = create_template_parameters(
+        config,
+        &public_path,
+        included_asset_names.iter().map(String::as_str),
+        compilation.get_hash(),
+      );
+      let mut dj = Dojang::new();
+      dj.add(url.clone(), content)
+        .map_err(|e| error!("failed to add template `{url}`: {e}"))?;
+      dj.render(&url, template_parameters)
+        .map_err(|e| error!("failed to render template `{url}`: {e}"))?
+    } else {
+      content
+    };
+
+    let ast_with_diagnostic = parser.parse_file(&url, template_result)?;
+
+    let (mut current_ast, diagnostic) = ast_with_diagnostic.split_into_parts();
+
+    if !diagnostic.is_empty() {
+      compilation.push_batch_diagnostic(diagnostic);
+    }
+    let included_assets = included_asset_names
+      .into_iter()
+      .map(|asset_name| {
+        let asset = compilation.assets().get(&asset_name).expect("TODO:");
+        (asset_name, asset)
       })
       .collect::<Vec<_>>();
 
@@ -141,10 +199,7 @@ impl Plugin for HtmlRspackPlugin {
     if !matches!(config.inject, HtmlInject::False) {
       for (asset_name, asset) in included_assets {
         if let Some(extension) = Path::new(&asset_name).extension() {
-          let asset_uri = format!(
-            "{}{asset_name}",
-            config.get_public_path(compilation, &self.config.filename),
-          );
+          let asset_uri = format!("{public_path}{asset_name}");
           let mut tag: Option<HTMLPluginTag> = None;
           if extension.eq_ignore_ascii_case("css") {
             tag = Some(HTMLPluginTag::create_style(&asset_uri, config.inject));
@@ -175,7 +230,10 @@ impl Plugin for HtmlRspackPlugin {
         });
     }
 
-    let tags = tags.into_iter().map(|(tag, _)| tag).collect::<Vec<_>>();
+    let tags = resource_hint_tags
+      .into_iter()
+      .chain(tags.into_iter().map(|(tag, _)| tag))
+      .collect::<Vec<_>>();
     let mut visitor = AssetWriter::new(config, &tags, compilation);
     current_ast.visit_mut_with(&mut visitor);
 
@@ -221,6 +279,83 @@ impl Plugin for HtmlRspackPlugin {
   }
 }
 
+/// Returns the files of the async chunks which are preloaded or prefetched by the chunks of the
+/// entrypoints, sorted by the preload or prefetch order of the chunk groups.
+fn get_async_chunk_files(
+  entrypoints: &[&Entrypoint],
+  order_key: &ChunkGroupOrderKey,
+  compilation: &Compilation,
+) -> Vec<String> {
+  let mut chunks = vec![];
+  for entrypoint in entrypoints {
+    for chunk in &entrypoint.chunks {
+      let chunk = compilation.chunk_by_ukey.expect_get(chunk);
+      if let Some(children) = chunk.get_children_of_type_in_order(order_key, compilation, false) {
+        chunks.extend(children.into_iter().flat_map(|(_, children)| children));
+      }
+    }
+  }
+  chunks
+    .into_iter()
+    .unique()
+    .flat_map(|chunk| {
+      compilation
+        .chunk_by_ukey
+        .expect_get(&chunk)
+        .files
+        .iter()
+        .sorted()
+        .cloned()
+        .collect::<Vec<_>>()
+    })
+    .unique()
+    .collect()
+}
+
+/// Creates the data available in templates: the user defined `templateParameters`, and
+/// `htmlRspackPlugin` with the options, and the public path, the compilation hash and the assets of
+/// the included entrypoints in `htmlRspackPlugin.files`.
+fn create_template_parameters<'a>(
+  config: &HtmlRspackPluginOptions,
+  public_path: &str,
+  asset_names: impl Iterator<Item = &'a str>,
+  hash: Option<&str>,
+) -> Value {
+  let mut js = vec![];
+  let mut css = vec![];
+  for asset_name in asset_names {
+    let Some(extension) = Path::new(asset_name).extension() else {
+      continue;
+    };
+    let asset_uri = format!("{public_path}{asset_name}");
+    if extension.eq_ignore_ascii_case("css") {
+      css.push(asset_uri);
+    } else if extension.eq_ignore_ascii_case("js") || extension.eq_ignore_ascii_case("mjs") {
+      js.push(asset_uri);
+    }
+  }
+
+  let mut parameters = config.template_parameters.clone().unwrap_or_default();
+  parameters.insert(
+    "htmlRspackPlugin".to_string(),
+    json!({
+      "options": {
+        "filename": config.filename,
+        "title": config.title,
+        "publicPath": public_path,
+      },
+      "files": {
+        "publicPath": public_path,
+        "js": js,
+        "css": css,
+        "favicon": config.favicon.as_ref().map(|favicon| format!("{public_path}{favicon}")),
+        "hash": hash,
+      },
+    }),
+  );
+  Value::Object(Map::from_iter(parameters))
+}
+
 fn hash_for_source(source: &str) -> String {
   let mut hasher = DefaultHasher::new();
   source.hash(&mut hasher);
@@ -1,10 +1,11 @@
+use std::hash::Hasher;
 use std::str::FromStr;
 
 use rspack_core::rspack_sources::{BoxSource, Source};
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
 #[cfg(feature = "testing")]
 use schemars::JsonSchema;
 use serde::Deserialize;
-use sha2::{Digest, Sha256, Sha384, Sha512};
 
 use crate::visitors::asset::{HTMLPluginTag, HtmlPluginAttribute};
 
@@ -35,30 +36,33 @@ impl FromStr for HtmlSriHashFunction {
   }
 }
 
-pub fn create_digest_from_asset(hash_func: &HtmlSriHashFunction, asset: &BoxSource) -> String {
-  let byte_content = asset.buffer();
-  match hash_func {
-    HtmlSriHashFunction::Sha384 => {
-      let mut hasher = Sha384::new();
-      hasher.update(byte_content);
-      let digest = &hasher.finalize()[..];
-      format!("sha384-{}", rspack_base64::encode_to_string(digest))
-    }
-    HtmlSriHashFunction::Sha256 => {
-      let mut hasher = Sha256::new();
-      hasher.update(byte_content);
-      let digest = &hasher.finalize()[..];
-      format!("sha256-{}", rspack_base64::encode_to_string(digest))
+impl HtmlSriHashFunction {
+  fn as_str(&self) -> &'static str {
+    match self {
+      HtmlSriHashFunction::Sha256 => "sha256",
+      HtmlSriHashFunction::Sha384 => "sha384",
+      HtmlSriHashFunction::Sha512 => "sha512",
     }
-    HtmlSriHashFunction::Sha512 => {
-      let mut hasher = Sha512::new();
-      hasher.update(byte_content);
-      let digest = &hasher.finalize()[..];
-      format!("sha512-{}", rspack_base64::encode_to_string(digest))
+  }
+}
+
+impl From<HtmlSriHashFunction> for HashFunction {
+  fn from(value: HtmlSriHashFunction) -> Self {
+    match value {
+      HtmlSriHashFunction::Sha256 => HashFunction::SHA256,
+      HtmlSriHashFunction::Sha384 => HashFunction::SHA384,
+      HtmlSriHashFunction::Sha512 => HashFunction::SHA512,
     }
   }
 }
 
+pub fn create_digest_from_asset(hash_func: &HtmlSriHashFunction, asset: &BoxSource) -> String {
+  let mut hasher = RspackHash::new(&(*hash_func).into());
+  hasher.write(&asset.buffer());
+  let digest = hasher.digest(&HashDigest::Base64);
+  format!("{}-{}", hash_func.as_str(), digest.encoded())
+}
+
 pub fn add_sri(tag: &mut HTMLPluginTag, sri: &String) {
   let mut has_crossorigin = false;
   let mut has_integrity = false;
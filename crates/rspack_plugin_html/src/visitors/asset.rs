@@ -72,6 +72,33 @@ impl HTMLPluginTag {
       void_tag: false,
     }
   }
+
+  /// Creates `<link rel="preload">`, `<link rel="prefetch">` or `<link rel="modulepreload">`.
+  pub fn create_resource_hint(href: &str, rel: &str, as_type: Option<&str>) -> HTMLPluginTag {
+    let mut attributes = vec![
+      HtmlPluginAttribute {
+        attr_name: "href".to_string(),
+        attr_value: Some(href.to_string()),
+      },
+      HtmlPluginAttribute {
+        attr_name: "rel".to_string(),
+        attr_value: Some(rel.to_string()),
+      },
+    ];
+    if let Some(as_type) = as_type {
+      attributes.push(HtmlPluginAttribute {
+        attr_name: "as".to_string(),
+        attr_value: Some(as_type.to_string()),
+      });
+    }
+
+    HTMLPluginTag {
+      tag_name: "link".to_string(),
+      append_to: HtmlInject::Head,
+      attributes,
+      void_tag: true,
+    }
+  }
 }
 
 // attributes are presented as plain string.
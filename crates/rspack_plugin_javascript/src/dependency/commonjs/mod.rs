@@ -4,6 +4,8 @@ mod common_js_full_require_dependency;
 mod common_js_require_dependency;
 mod common_js_self_reference_dependency;
 mod module_decorator_dependency;
+mod require_ensure_dependency;
+mod require_ensure_item_dependency;
 mod require_header_dependency;
 mod require_resolve_dependency;
 
@@ -14,5 +16,7 @@ pub use common_js_full_require_dependency::CommonJsFullRequireDependency;
 pub use common_js_require_dependency::CommonJsRequireDependency;
 pub use common_js_self_reference_dependency::CommonJsSelfReferenceDependency;
 pub use module_decorator_dependency::ModuleDecoratorDependency;
+pub use require_ensure_dependency::RequireEnsureDependency;
+pub use require_ensure_item_dependency::RequireEnsureItemDependency;
 pub use require_header_dependency::RequireHeaderDependency;
 pub use require_resolve_dependency::RequireResolveDependency;
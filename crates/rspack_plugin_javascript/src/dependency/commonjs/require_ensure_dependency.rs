@@ -0,0 +1,93 @@
+use rspack_core::{
+  block_promise, AsContextDependency, AsModuleDependency, Dependency, DependencyCategory,
+  DependencyId, DependencyTemplate, DependencyType, RuntimeGlobals, TemplateContext,
+  TemplateReplaceSource,
+};
+
+/// `require.ensure(dependencies, callback, errorCallback, chunkName)`, the call is replaced
+/// by loading the chunks of its block and calling the callback with `__webpack_require__`.
+#[derive(Debug, Clone)]
+pub struct RequireEnsureDependency {
+  id: DependencyId,
+  range: (u32, u32),
+  content_range: (u32, u32),
+  error_handler_range: Option<(u32, u32)>,
+}
+
+impl RequireEnsureDependency {
+  pub fn new(
+    range: (u32, u32),
+    content_range: (u32, u32),
+    error_handler_range: Option<(u32, u32)>,
+  ) -> Self {
+    Self {
+      id: DependencyId::new(),
+      range,
+      content_range,
+      error_handler_range,
+    }
+  }
+}
+
+impl Dependency for RequireEnsureDependency {
+  fn dependency_debug_name(&self) -> &'static str {
+    "RequireEnsureDependency"
+  }
+
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::CommonJS
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::RequireEnsure
+  }
+}
+
+impl AsModuleDependency for RequireEnsureDependency {}
+
+impl AsContextDependency for RequireEnsureDependency {}
+
+impl DependencyTemplate for RequireEnsureDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let TemplateContext {
+      compilation,
+      runtime_requirements,
+      ..
+    } = code_generatable_context;
+    let block = compilation.module_graph.get_parent_block(&self.id);
+    let promise = block_promise(block, runtime_requirements, compilation);
+    runtime_requirements.insert(RuntimeGlobals::REQUIRE);
+
+    source.replace(
+      self.range.0,
+      self.content_range.0,
+      &format!("{promise}.then(("),
+      None,
+    );
+    match self.error_handler_range {
+      Some(error_handler_range) => {
+        source.replace(
+          self.content_range.1,
+          error_handler_range.0,
+          &format!(").bind(null, {}))['catch'](", RuntimeGlobals::REQUIRE),
+          None,
+        );
+        source.replace(error_handler_range.1, self.range.1, ")", None);
+      }
+      None => source.replace(
+        self.content_range.1,
+        self.range.1,
+        &format!(").bind(null, {}))", RuntimeGlobals::REQUIRE),
+        None,
+      ),
+    }
+  }
+}
@@ -0,0 +1,80 @@
+use rspack_core::{
+  AsContextDependency, Dependency, DependencyCategory, DependencyId, DependencyTemplate,
+  DependencyType, ErrorSpan, ExtendedReferencedExport, ModuleDependency, ModuleGraph, RuntimeSpec,
+  TemplateContext, TemplateReplaceSource,
+};
+use swc_core::ecma::atoms::JsWord;
+
+/// An item of the dependencies array of `require.ensure()`, the module is only
+/// added to the chunks of the ensure block and nothing is rendered for it.
+#[derive(Debug, Clone)]
+pub struct RequireEnsureItemDependency {
+  id: DependencyId,
+  request: JsWord,
+  span: Option<ErrorSpan>,
+}
+
+impl RequireEnsureItemDependency {
+  pub fn new(request: JsWord, span: Option<ErrorSpan>) -> Self {
+    Self {
+      id: DependencyId::new(),
+      request,
+      span,
+    }
+  }
+}
+
+impl Dependency for RequireEnsureItemDependency {
+  fn dependency_debug_name(&self) -> &'static str {
+    "RequireEnsureItemDependency"
+  }
+
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::CommonJS
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::RequireEnsureItem
+  }
+
+  fn span(&self) -> Option<ErrorSpan> {
+    self.span
+  }
+}
+
+impl ModuleDependency for RequireEnsureItemDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request.into();
+  }
+
+  fn get_referenced_exports(
+    &self,
+    _module_graph: &ModuleGraph,
+    _runtime: Option<&RuntimeSpec>,
+  ) -> Vec<ExtendedReferencedExport> {
+    vec![]
+  }
+}
+
+impl DependencyTemplate for RequireEnsureItemDependency {
+  fn apply(
+    &self,
+    _source: &mut TemplateReplaceSource,
+    _code_generatable_context: &mut TemplateContext,
+  ) {
+  }
+}
+
+impl AsContextDependency for RequireEnsureItemDependency {}
@@ -6,6 +6,7 @@ mod require_context_dependency;
 pub use common_js_require_context_dependency::CommonJsRequireContextDependency;
 pub use import_context_dependency::ImportContextDependency;
 pub use import_meta_context_dependency::ImportMetaContextDependency;
+use itertools::Itertools;
 pub use require_context_dependency::RequireContextDependency;
 use rspack_core::ContextOptions;
 use rspack_regex::regexp_as_str;
@@ -22,12 +23,23 @@ fn create_resource_identifier_for_context_dependency(
     .as_ref()
     .map(regexp_as_str)
     .unwrap_or_default();
-  let include = options.include.as_deref().unwrap_or_default();
-  let exclude = options.exclude.as_deref().unwrap_or_default();
+  let include = options
+    .include
+    .as_ref()
+    .map(regexp_as_str)
+    .unwrap_or_default();
+  let exclude = options
+    .exclude
+    .as_ref()
+    .map(regexp_as_str)
+    .unwrap_or_default();
   let mode = options.mode.as_str();
   // TODO: need `RawChunkGroupOptions`
-  let id = format!(
+  let mut id = format!(
     "context{context}|ctx request{request} {recursive} `{regexp} {include} {exclude} ``{mode} `"
   );
+  if let Some(referenced_exports) = &options.referenced_exports {
+    id.push_str(&format!(" {}", referenced_exports.iter().join(",")));
+  }
   id
 }
@@ -1,8 +1,7 @@
+pub use rspack_core::DEFAULT_EXPORT;
 use rspack_core::{AsContextDependency, AsModuleDependency, Dependency};
 use rspack_core::{DependencyId, DependencyTemplate};
 use rspack_core::{TemplateContext, TemplateReplaceSource};
-
-pub const DEFAULT_EXPORT: &str = "__WEBPACK_DEFAULT_EXPORT__";
 // pub const NAMESPACE_OBJECT_EXPORT: &'static str = "__WEBPACK_NAMESPACE_OBJECT__";
 
 #[derive(Debug, Clone)]
@@ -62,8 +61,13 @@ impl DependencyTemplate for HarmonyExportExpressionDependency {
   fn apply(
     &self,
     source: &mut TemplateReplaceSource,
-    _code_generatable_context: &mut TemplateContext,
+    code_generatable_context: &mut TemplateContext,
   ) {
+    let default_export = code_generatable_context
+      .concatenation_scope
+      .as_ref()
+      .map(|scope| scope.get_final_name(DEFAULT_EXPORT))
+      .unwrap_or_else(|| DEFAULT_EXPORT.into());
     if self.declaration {
       source.replace(self.start, self.end, "", None);
     } else if let Some(AnonymousFunctionRangeInfo {
@@ -75,7 +79,7 @@ impl DependencyTemplate for HarmonyExportExpressionDependency {
     {
       // hoist anonymous function
       let prefix = format!(
-        "{}function{} {DEFAULT_EXPORT}",
+        "{}function{} {default_export}",
         if *is_async { "async " } else { "" },
         if *is_generator { "*" } else { "" },
       );
@@ -93,7 +97,7 @@ impl DependencyTemplate for HarmonyExportExpressionDependency {
       source.replace(
         self.start,
         self.end,
-        format!("var {DEFAULT_EXPORT} = ").as_str(),
+        format!("var {default_export} = ").as_str(),
         None,
       );
     }
@@ -75,9 +75,15 @@ impl DependencyTemplate for HarmonyExportSpecifierDependency {
       compilation,
       module,
       runtime,
+      concatenation_scope,
       ..
     } = code_generatable_context;
 
+    if let Some(scope) = concatenation_scope {
+      scope.register_export(self.name.clone(), self.value.clone());
+      return;
+    }
+
     let module = compilation
       .module_graph
       .module_by_identifier(&module.identifier())
@@ -71,12 +71,20 @@ pub fn harmony_import_dependency_apply<T: ModuleDependency>(
     compilation,
     module,
     runtime,
+    concatenation_scope,
     ..
   } = code_generatable_context;
   // Only available when module factorization is successful.
   let ref_mgm = compilation
     .module_graph
     .module_graph_module_by_dependency_id(module_dependency.id());
+  // Modules in the same concatenation scope are inlined, no need to import them.
+  if let Some(scope) = concatenation_scope
+    && let Some(ref_mgm) = ref_mgm
+    && scope.is_module_in_scope(&ref_mgm.module_identifier)
+  {
+    return;
+  }
   let is_target_active = if compilation.options.is_new_tree_shaking() {
     let connection = compilation
       .module_graph
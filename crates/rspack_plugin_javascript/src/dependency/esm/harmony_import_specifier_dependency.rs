@@ -170,12 +170,20 @@ impl DependencyTemplate for HarmonyImportSpecifierDependency {
     }
 
     let ids = self.get_ids(&compilation.module_graph);
-    compilation
-      .module_graph
-      .module_identifier_by_dependency_id(&self.id);
+    if let Some(scope) = code_generatable_context.concatenation_scope.as_deref()
+      && let Some(reference_mgm) = reference_mgm
+      && scope.is_module_in_scope(&reference_mgm.module_identifier)
+    {
+      let reference = scope.create_module_reference(&reference_mgm.module_identifier, &ids);
+      if self.shorthand {
+        source.insert(self.end, &format!(": {reference}"), None);
+      } else {
+        source.replace(self.start, self.end, &reference, None);
+      }
+      return;
+    }
     let import_var = get_import_var(&compilation.module_graph, self.id);
 
-    // TODO: scope hoist
     if is_new_treeshaking {
       harmony_import_dependency_apply(
         self,
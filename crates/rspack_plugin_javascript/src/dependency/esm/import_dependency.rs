@@ -1,6 +1,7 @@
 use rspack_core::{module_namespace_promise, DependencyType, ErrorSpan, ImportDependencyTrait};
 use rspack_core::{AsContextDependency, Dependency};
 use rspack_core::{DependencyCategory, DependencyId, DependencyTemplate};
+use rspack_core::{ExtendedReferencedExport, ModuleGraph, RuntimeSpec};
 use rspack_core::{ModuleDependency, TemplateContext, TemplateReplaceSource};
 use swc_core::ecma::atoms::JsWord;
 
@@ -67,6 +68,14 @@ impl ModuleDependency for ImportDependency {
   fn set_request(&mut self, request: String) {
     self.request = request.into();
   }
+
+  fn get_referenced_exports(
+    &self,
+    module_graph: &ModuleGraph,
+    runtime: Option<&RuntimeSpec>,
+  ) -> Vec<ExtendedReferencedExport> {
+    ImportDependencyTrait::get_referenced_exports(self, module_graph, runtime)
+  }
 }
 
 impl ImportDependencyTrait for ImportDependency {
@@ -1,11 +1,12 @@
 use rspack_core::{
   module_namespace_promise, AsContextDependency, Dependency, DependencyCategory, DependencyId,
   DependencyTemplate, DependencyType, ErrorSpan, ExtendedReferencedExport, ImportDependencyTrait,
-  ModuleDependency, ModuleGraph, ReferencedExport, RuntimeSpec, TemplateContext,
-  TemplateReplaceSource,
+  ModuleDependency, ModuleGraph, RuntimeSpec, TemplateContext, TemplateReplaceSource,
 };
 use swc_core::ecma::atoms::JsWord;
 
+/// `import()` which doesn't create a chunk, the module is included eagerly by the importer
+/// with `webpackMode: "eager"`, or must be provided by other chunks with `webpackMode: "weak"`.
 #[derive(Debug, Clone)]
 pub struct ImportEagerDependency {
   start: u32,
@@ -14,6 +15,7 @@ pub struct ImportEagerDependency {
   request: JsWord,
   span: Option<ErrorSpan>,
   referenced_exports: Option<Vec<JsWord>>,
+  weak: bool,
 }
 
 impl ImportEagerDependency {
@@ -23,6 +25,7 @@ impl ImportEagerDependency {
     request: JsWord,
     span: Option<ErrorSpan>,
     referenced_exports: Option<Vec<JsWord>>,
+    weak: bool,
   ) -> Self {
     Self {
       start,
@@ -31,6 +34,7 @@ impl ImportEagerDependency {
       span,
       id: DependencyId::new(),
       referenced_exports,
+      weak,
     }
   }
 }
@@ -45,7 +49,11 @@ impl Dependency for ImportEagerDependency {
   }
 
   fn dependency_type(&self) -> &DependencyType {
-    &DependencyType::DynamicImportEager
+    if self.weak {
+      &DependencyType::DynamicImportWeak
+    } else {
+      &DependencyType::DynamicImportEager
+    }
   }
 
   fn span(&self) -> Option<ErrorSpan> {
@@ -72,14 +80,14 @@ impl ModuleDependency for ImportEagerDependency {
 
   fn get_referenced_exports(
     &self,
-    _module_graph: &ModuleGraph,
-    _runtime: Option<&RuntimeSpec>,
+    module_graph: &ModuleGraph,
+    runtime: Option<&RuntimeSpec>,
   ) -> Vec<ExtendedReferencedExport> {
-    if let Some(referenced_exports) = &self.referenced_exports {
-      vec![ReferencedExport::new(referenced_exports.clone(), false).into()]
-    } else {
-      vec![ExtendedReferencedExport::Array(vec![])]
-    }
+    ImportDependencyTrait::get_referenced_exports(self, module_graph, runtime)
+  }
+
+  fn weak(&self) -> bool {
+    self.weak
   }
 }
 
@@ -108,7 +116,7 @@ impl DependencyTemplate for ImportEagerDependency {
         block,
         &self.request,
         self.dependency_type().as_str().as_ref(),
-        false,
+        self.weak,
       )
       .as_str(),
       None,
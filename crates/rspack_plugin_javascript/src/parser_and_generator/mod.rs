@@ -187,6 +187,7 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
       scan_dependencies(
         program,
         context.unresolved_mark,
+        context.top_level_mark,
         resource_data,
         compiler_options,
         module_type,
@@ -205,8 +206,10 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
 
     let analyze_result = if compiler_options.builtins.tree_shaking.enable() {
       let mut all_dependencies = dependencies.clone();
-      for mut block in blocks.clone() {
+      let mut queue = blocks.clone();
+      while let Some(mut block) = queue.pop() {
         all_dependencies.extend(block.take_dependencies());
+        queue.extend(block.take_blocks());
       }
       JsModule::new(&ast, &all_dependencies, module_identifier, compiler_options).analyze()
     } else {
@@ -311,6 +314,7 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
         runtime_requirements: generate_context.runtime_requirements,
         init_fragments: &mut init_fragments,
         runtime: generate_context.runtime,
+        concatenation_scope: generate_context.concatenation_scope.as_deref_mut(),
       };
 
       module.get_dependencies().iter().for_each(|dependency_id| {
@@ -328,6 +332,23 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
         .iter()
         .for_each(|block_id| self.source_block(compilation, block_id, &mut source, &mut context));
 
+      // rename the top level bindings which conflict with other modules in the concatenated module
+      if let Some(scope) = context.concatenation_scope.as_deref()
+        && let Some(build_info) = module.build_info()
+      {
+        for reference in &build_info.top_level_references {
+          let final_name = scope.get_final_name(&reference.name);
+          if final_name == reference.name {
+            continue;
+          }
+          if reference.shorthand {
+            source.insert(reference.end, &format!(": {final_name}"), None);
+          } else {
+            source.replace(reference.start, reference.end, &final_name, None);
+          }
+        }
+      }
+
       render_init_fragments(source.boxed(), init_fragments, generate_context)
     } else {
       panic!(
@@ -90,6 +90,8 @@ impl JavascriptParserPlugin for RequireContextDependencyParserPlugin {
             request: request_expr.string().to_string(),
             namespace_object: rspack_core::ContextNameSpaceObject::Unset,
             chunk_name: None,
+            referenced_exports: None,
+            fetch_priority: None,
           },
           Some(expr.span.into()),
         )));
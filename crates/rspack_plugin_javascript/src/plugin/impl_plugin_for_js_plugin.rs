@@ -92,6 +92,11 @@ impl Plugin for JsPlugin {
       DependencyType::RequireResolve,
       params.normal_module_factory.clone(),
     );
+    // RequireEnsurePlugin
+    args.compilation.set_dependency_factory(
+      DependencyType::RequireEnsureItem,
+      params.normal_module_factory.clone(),
+    );
     // RequireContextPlugin
     args.compilation.set_dependency_factory(
       DependencyType::RequireContext,
@@ -119,6 +124,10 @@ impl Plugin for JsPlugin {
       DependencyType::DynamicImportEager,
       params.normal_module_factory.clone(),
     );
+    args.compilation.set_dependency_factory(
+      DependencyType::DynamicImportWeak,
+      params.normal_module_factory.clone(),
+    );
     args.compilation.set_dependency_factory(
       DependencyType::ImportContext,
       params.context_module_factory.clone(),
@@ -138,6 +147,11 @@ impl Plugin for JsPlugin {
       DependencyType::CjsSelfReference,
       Arc::new(SelfModuleFactory {}),
     );
+    // LoaderPlugin
+    args.compilation.set_dependency_factory(
+      DependencyType::LoaderImport,
+      params.normal_module_factory.clone(),
+    );
     Ok(())
   }
 
@@ -5,12 +5,14 @@ pub mod impl_plugin_for_js_plugin;
 pub mod infer_async_modules_plugin;
 pub mod inner_graph_plugin;
 mod mangle_exports_plugin;
+mod module_concatenation_plugin;
 mod side_effects_flag_plugin;
 use std::hash::Hash;
 
 pub use flag_dependency_exports_plugin::*;
 pub use flag_dependency_usage_plugin::*;
 pub use mangle_exports_plugin::*;
+pub use module_concatenation_plugin::*;
 use rspack_core::rspack_sources::{BoxSource, ConcatSource, RawSource, SourceExt};
 use rspack_core::{
   render_init_fragments, ChunkRenderContext, ChunkUkey, Compilation, JsChunkHashArgs,
@@ -20,7 +22,10 @@ use rspack_error::Result;
 use rspack_hash::RspackHash;
 pub use side_effects_flag_plugin::*;
 
-use crate::runtime::{render_chunk_modules, render_iife, render_runtime_modules, stringify_array};
+use crate::runtime::{
+  get_top_level_entry, render_chunk_modules, render_iife, render_runtime_modules,
+  render_top_level_entry, stringify_array,
+};
 use crate::utils::is_diff_mode;
 
 #[derive(Debug)]
@@ -297,15 +302,32 @@ impl JsPlugin {
     let runtime_requirements = compilation
       .chunk_graph
       .get_tree_runtime_requirements(&args.chunk_ukey);
-    let (module_source, chunk_init_fragments) =
-      render_chunk_modules(compilation, &args.chunk_ukey)?;
     let (header, startup) = self.render_bootstrap(&args.chunk_ukey, args.compilation);
     let mut sources = ConcatSource::default();
-    sources.add(RawSource::from("var __webpack_modules__ = "));
-    sources.add(module_source);
-    sources.add(RawSource::from("\n"));
-    sources.add(header);
-    sources.add(render_runtime_modules(compilation, &args.chunk_ukey)?);
+    let (startup, chunk_init_fragments) =
+      if let Some((module, _)) = get_top_level_entry(compilation, &args.chunk_ukey) {
+        // the entry module is rendered without the module wrapper, and its exports are declared by
+        // the library in startup, the runtime is only rendered when the runtime modules require it
+        let (module_source, chunk_init_fragments) =
+          render_top_level_entry(compilation, &args.chunk_ukey, &module)?;
+        if !runtime_requirements.is_empty() {
+          sources.add(RawSource::from("var __webpack_modules__ = {};\n"));
+          sources.add(header);
+          sources.add(render_runtime_modules(compilation, &args.chunk_ukey)?);
+        }
+        sources.add(module_source);
+        sources.add(RawSource::from("\n"));
+        (RawSource::from("").boxed(), chunk_init_fragments)
+      } else {
+        let (module_source, chunk_init_fragments) =
+          render_chunk_modules(compilation, &args.chunk_ukey)?;
+        sources.add(RawSource::from("var __webpack_modules__ = "));
+        sources.add(module_source);
+        sources.add(RawSource::from("\n"));
+        sources.add(header);
+        sources.add(render_runtime_modules(compilation, &args.chunk_ukey)?);
+        (startup, chunk_init_fragments)
+      };
     if chunk.has_entry_module(&compilation.chunk_graph) {
       let last_entry_module = compilation
         .chunk_graph
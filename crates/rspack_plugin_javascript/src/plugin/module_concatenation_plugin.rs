@@ -0,0 +1,455 @@
+use std::collections::VecDeque;
+
+use rspack_core::{
+  get_import_var, BuildMetaExportsType, ChunkUkey, Compilation, CompilationParams,
+  ConcatenatedModule, ConcatenatedModules, DependencyType, ModuleGraph, ModuleIdentifier,
+  OptimizeChunksArgs, Plugin, PluginThisCompilationHookOutput, ThisCompilationArgs,
+};
+use rspack_error::Result;
+use rspack_identifier::{IdentifierMap, IdentifierSet};
+use rustc_hash::FxHashMap as HashMap;
+
+/// Concatenate harmony modules which are only imported by other harmony modules of the same
+/// chunks into a `ConcatenatedModule`, also known as scope hoisting.
+///
+/// Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/optimize/ModuleConcatenationPlugin.js
+#[derive(Debug, Default)]
+pub struct ModuleConcatenationPlugin;
+
+fn is_harmony_import(dependency_type: &DependencyType) -> bool {
+  matches!(
+    dependency_type,
+    DependencyType::EsmImport(_) | DependencyType::EsmImportSpecifier
+  )
+}
+
+impl ModuleConcatenationPlugin {
+  /// Returns the reason why the module can't be a part of a concatenated module.
+  fn bailout_reason(
+    compilation: &Compilation,
+    module_identifier: &ModuleIdentifier,
+  ) -> Option<String> {
+    let module_graph = &compilation.module_graph;
+    let module = module_graph.module_by_identifier(module_identifier)?;
+    // `include_module_ids` contains all modules unless `builtins.treeShaking` removes some of them
+    if !compilation.include_module_ids.contains(module_identifier) {
+      return Some("Module is removed by tree shaking".to_string());
+    }
+    if module.as_normal_module().is_none() {
+      return Some("Module is not a normal module".to_string());
+    }
+    if !module.module_type().is_js_like() {
+      return Some("Module is not a JavaScript module".to_string());
+    }
+    let (Some(build_info), Some(build_meta)) = (module.build_info(), module.build_meta()) else {
+      return Some("Module is not built".to_string());
+    };
+    if !matches!(build_meta.exports_type, BuildMetaExportsType::Namespace) {
+      return Some("Module is not an ECMAScript module".to_string());
+    }
+    if let Some(reason) = &build_info.module_concatenation_bailout {
+      return Some(reason.clone());
+    }
+    if build_info.top_level_declarations.is_none() {
+      return Some("Module's top level declarations are unknown".to_string());
+    }
+    if build_meta.has_top_level_await || module_graph.is_async(module_identifier) == Some(true) {
+      return Some("Module is an async module".to_string());
+    }
+    for dep_id in module.get_dependencies() {
+      let Some(dep) = module_graph.dependency_by_id(dep_id) else {
+        continue;
+      };
+      match dep.dependency_type() {
+        DependencyType::EsmExportImportedSpecifier => {
+          return Some("Module re-exports from other modules".to_string());
+        }
+        DependencyType::ModuleHotAccept
+        | DependencyType::ModuleHotDecline
+        | DependencyType::ImportMetaHotAccept
+        | DependencyType::ImportMetaHotDecline => {
+          return Some("Module uses Hot Module Replacement".to_string());
+        }
+        _ => {}
+      }
+    }
+    None
+  }
+
+  /// Modules imported by harmony imports of the module, in source order.
+  fn get_imports(
+    module_graph: &ModuleGraph,
+    module_identifier: &ModuleIdentifier,
+  ) -> Vec<ModuleIdentifier> {
+    let Some(module) = module_graph.module_by_identifier(module_identifier) else {
+      return vec![];
+    };
+    let mut imports = ConcatenatedModules::default();
+    for dep_id in module.get_dependencies() {
+      let is_harmony_import = module_graph
+        .dependency_by_id(dep_id)
+        .is_some_and(|dep| is_harmony_import(dep.dependency_type()));
+      if is_harmony_import
+        && let Some(target) = module_graph.module_identifier_by_dependency_id(dep_id)
+      {
+        imports.insert(*target);
+      }
+    }
+    imports.into_iter().collect()
+  }
+
+  fn get_module_chunks(compilation: &Compilation, module: &ModuleIdentifier) -> Vec<ChunkUkey> {
+    let mut chunks = compilation
+      .chunk_graph
+      .chunk_graph_module_by_module_identifier
+      .get(module)
+      .map(|cgm| cgm.chunks.iter().copied().collect::<Vec<_>>())
+      .unwrap_or_default();
+    chunks.sort_unstable();
+    chunks
+  }
+
+  /// Whether the chunks are the entry chunks of `library.type: "module"`.
+  fn is_module_library_chunks(compilation: &Compilation, chunks: &[ChunkUkey]) -> bool {
+    chunks.iter().all(|chunk_ukey| {
+      compilation
+        .chunk_by_ukey
+        .expect_get(chunk_ukey)
+        .get_entry_options(&compilation.chunk_group_by_ukey)
+        .and_then(|options| options.library.as_ref())
+        .or(compilation.options.output.library.as_ref())
+        .map_or(false, |library| library.library_type == "module")
+    })
+  }
+
+  /// Try to add the module to the config, returns the problem when it can't be added.
+  /// The config is left untouched when the module isn't added.
+  fn try_to_add(
+    compilation: &Compilation,
+    config: &mut ConcatenatedModules,
+    module: &ModuleIdentifier,
+    root_chunks: &[ChunkUkey],
+    possible_inners: &IdentifierSet,
+    invalid: &IdentifierMap<String>,
+  ) -> Option<String> {
+    if config.contains(module) {
+      return None;
+    }
+    if let Some(reason) = invalid.get(module) {
+      return Some(reason.clone());
+    }
+    if !possible_inners.contains(module) {
+      return Some("Module is an entry point".to_string());
+    }
+    if Self::get_module_chunks(compilation, module) != root_chunks {
+      return Some("Module is not in the same chunks as the root module".to_string());
+    }
+
+    let module_graph = &compilation.module_graph;
+    let len = config.len();
+    config.insert(*module);
+
+    let mgm = module_graph
+      .module_graph_module_by_identifier(module)
+      .expect("should have module graph module");
+    let Ok(connections) = mgm.incoming_connections_unordered(module_graph) else {
+      config.truncate(len);
+      return Some("Module's incoming connections are unknown".to_string());
+    };
+    let mut origins = vec![];
+    for connection in connections {
+      let Some(origin) = connection.original_module_identifier else {
+        config.truncate(len);
+        return Some("Module is referenced from the outside".to_string());
+      };
+      let problem = match module_graph.dependency_by_id(&connection.dependency_id) {
+        Some(dep) if !is_harmony_import(dep.dependency_type()) => Some(format!(
+          "Module is referenced by a non harmony import from {origin}"
+        )),
+        Some(dep)
+          if matches!(dep.dependency_type(), DependencyType::EsmImportSpecifier)
+            && dep.get_ids(module_graph).is_empty() =>
+        {
+          Some(format!(
+            "Module is referenced as a namespace object from {origin}"
+          ))
+        }
+        Some(_) => None,
+        None => Some(format!("Module is referenced from {origin}")),
+      };
+      if let Some(problem) = problem {
+        config.truncate(len);
+        return Some(problem);
+      }
+      origins.push(origin);
+    }
+    origins.sort_unstable();
+    origins.dedup();
+    for origin in origins {
+      if let Some(problem) = Self::try_to_add(
+        compilation,
+        config,
+        &origin,
+        root_chunks,
+        possible_inners,
+        invalid,
+      ) {
+        config.truncate(len);
+        return Some(format!("Cannot concat with {origin}: {problem}"));
+      }
+    }
+    None
+  }
+
+  /// Sort the modules of the config in evaluation order, the root module is the last one.
+  fn sort_modules(
+    module_graph: &ModuleGraph,
+    root: ModuleIdentifier,
+    config: &ConcatenatedModules,
+  ) -> ConcatenatedModules {
+    fn visit(
+      module_graph: &ModuleGraph,
+      module: ModuleIdentifier,
+      config: &ConcatenatedModules,
+      visited: &mut IdentifierSet,
+      order: &mut ConcatenatedModules,
+    ) {
+      if !visited.insert(module) {
+        return;
+      }
+      for import in ModuleConcatenationPlugin::get_imports(module_graph, &module) {
+        if config.contains(&import) {
+          visit(module_graph, import, config, visited, order);
+        }
+      }
+      order.insert(module);
+    }
+
+    let mut visited = IdentifierSet::default();
+    let mut order = ConcatenatedModules::default();
+    visit(module_graph, root, config, &mut visited, &mut order);
+    // modules which are not reachable from the root by harmony imports are evaluated first
+    let mut modules = config
+      .iter()
+      .filter(|m| !order.contains(*m))
+      .copied()
+      .collect::<ConcatenatedModules>();
+    modules.extend(order);
+    modules
+  }
+
+  /// Modules in the same scope importing different modules may end up with the same import var,
+  /// give them distinct names.
+  fn dedupe_import_vars(module_graph: &ModuleGraph, modules: &ConcatenatedModules) {
+    let mut import_vars: HashMap<String, ModuleIdentifier> = HashMap::default();
+    for module_identifier in modules {
+      let Some(module) = module_graph.module_by_identifier(module_identifier) else {
+        continue;
+      };
+      for dep_id in module.get_dependencies() {
+        let is_harmony_import = module_graph
+          .dependency_by_id(dep_id)
+          .is_some_and(|dep| is_harmony_import(dep.dependency_type()));
+        if !is_harmony_import {
+          continue;
+        }
+        let Some(target) = module_graph.module_identifier_by_dependency_id(dep_id) else {
+          continue;
+        };
+        if modules.contains(target) {
+          continue;
+        }
+        let import_var = get_import_var(module_graph, *dep_id);
+        match import_vars.get(&import_var) {
+          Some(existing) if existing == target => {}
+          Some(_) => {
+            let mut index = 1;
+            let new_import_var = loop {
+              let candidate = format!("{import_var}_{index}");
+              if !import_vars.contains_key(&candidate) {
+                break candidate;
+              }
+              index += 1;
+            };
+            if let Some(mut map) = module_graph.import_var_map.get_mut(module_identifier) {
+              map.insert(Some(target.to_string()), new_import_var.clone());
+            }
+            import_vars.insert(new_import_var, *target);
+          }
+          None => {
+            import_vars.insert(import_var, *target);
+          }
+        }
+      }
+    }
+  }
+}
+
+#[async_trait::async_trait]
+impl Plugin for ModuleConcatenationPlugin {
+  fn name(&self) -> &'static str {
+    "ModuleConcatenationPlugin"
+  }
+
+  async fn this_compilation(
+    &self,
+    args: ThisCompilationArgs<'_>,
+    _params: &CompilationParams,
+  ) -> PluginThisCompilationHookOutput {
+    // the module graph is reused when rebuilding, restore the concatenated modules
+    let module_graph = &mut args.this_compilation.module_graph;
+    let concatenated = module_graph
+      .modules()
+      .iter()
+      .filter(|(_, module)| module.as_concatenated_module().is_some())
+      .map(|(identifier, _)| *identifier)
+      .collect::<Vec<_>>();
+    for identifier in concatenated {
+      let module = module_graph
+        .module_identifier_to_module
+        .remove(&identifier)
+        .expect("should have module");
+      let concatenated_module = module
+        .into_any()
+        .downcast::<ConcatenatedModule>()
+        .expect("should be a concatenated module");
+      module_graph
+        .module_identifier_to_module
+        .insert(identifier, concatenated_module.into_root_module());
+    }
+    Ok(())
+  }
+
+  async fn optimize_chunk_modules(&self, args: OptimizeChunksArgs<'_>) -> Result<()> {
+    let compilation = args.compilation;
+    if !compilation.options.optimization.concatenate_modules {
+      return Ok(());
+    }
+
+    let mut module_identifiers = compilation
+      .module_graph
+      .modules()
+      .keys()
+      .copied()
+      .collect::<Vec<_>>();
+    module_identifiers.sort_unstable_by_key(|m| {
+      (
+        compilation
+          .module_graph
+          .get_pre_order_index(m)
+          .unwrap_or(u32::MAX),
+        *m,
+      )
+    });
+
+    let mut bailouts: IdentifierMap<Vec<String>> = IdentifierMap::default();
+    let mut invalid = IdentifierMap::default();
+    let mut roots = vec![];
+    let mut possible_inners = IdentifierSet::default();
+    for module_identifier in &module_identifiers {
+      if let Some(reason) = Self::bailout_reason(compilation, module_identifier) {
+        bailouts
+          .entry(*module_identifier)
+          .or_default()
+          .push(reason.clone());
+        invalid.insert(*module_identifier, reason);
+        continue;
+      }
+      if Self::get_module_chunks(compilation, module_identifier).is_empty() {
+        continue;
+      }
+      roots.push(*module_identifier);
+      if !compilation
+        .entry_module_identifiers
+        .contains(module_identifier)
+      {
+        possible_inners.insert(*module_identifier);
+      }
+    }
+
+    let mut configs = vec![];
+    let mut used_as_inner = IdentifierSet::default();
+    for root in roots {
+      if used_as_inner.contains(&root) {
+        continue;
+      }
+      let root_chunks = Self::get_module_chunks(compilation, &root);
+      let mut config = ConcatenatedModules::default();
+      config.insert(root);
+      let mut candidates = VecDeque::from(Self::get_imports(&compilation.module_graph, &root));
+      while let Some(candidate) = candidates.pop_front() {
+        // imports of modules removed by tree shaking are not rendered, no need to concat them
+        if config.contains(&candidate) || !compilation.include_module_ids.contains(&candidate) {
+          continue;
+        }
+        let len = config.len();
+        match Self::try_to_add(
+          compilation,
+          &mut config,
+          &candidate,
+          &root_chunks,
+          &possible_inners,
+          &invalid,
+        ) {
+          Some(problem) => {
+            let readable_identifier = compilation
+              .module_graph
+              .module_by_identifier(&candidate)
+              .map(|m| {
+                m.readable_identifier(&compilation.options.context)
+                  .to_string()
+              })
+              .unwrap_or_else(|| candidate.to_string());
+            bailouts.entry(root).or_default().push(format!(
+              "Cannot concat with {readable_identifier}: {problem}"
+            ));
+          }
+          None => {
+            for added in config.iter().skip(len) {
+              candidates.extend(Self::get_imports(&compilation.module_graph, added));
+            }
+          }
+        }
+      }
+      // a single entry module of ES module libraries is wrapped as well, so it can be rendered at
+      // the top level of the chunk
+      if config.len() > 1
+        || (compilation.entry_module_identifiers.contains(&root)
+          && Self::is_module_library_chunks(compilation, &root_chunks))
+      {
+        used_as_inner.extend(config.iter().filter(|m| **m != root).copied());
+        configs.push((root, config));
+      }
+    }
+
+    for mgm in compilation
+      .module_graph
+      .module_identifier_to_module_graph_module
+      .values_mut()
+    {
+      mgm.optimization_bailout = bailouts.remove(&mgm.module_identifier).unwrap_or_default();
+    }
+
+    for (root, config) in configs {
+      let modules = Self::sort_modules(&compilation.module_graph, root, &config);
+      Self::dedupe_import_vars(&compilation.module_graph, &modules);
+      for module in modules.iter().filter(|m| **m != root) {
+        for chunk in Self::get_module_chunks(compilation, module) {
+          compilation
+            .chunk_graph
+            .disconnect_chunk_and_module(&chunk, *module);
+        }
+      }
+      let module_graph = &mut compilation.module_graph;
+      let root_module = module_graph
+        .module_identifier_to_module
+        .remove(&root)
+        .expect("should have root module");
+      let concatenated_module = ConcatenatedModule::new(root_module, modules, module_graph);
+      module_graph
+        .module_identifier_to_module
+        .insert(root, Box::new(concatenated_module));
+    }
+    Ok(())
+  }
+}
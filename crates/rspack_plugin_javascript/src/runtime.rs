@@ -1,8 +1,8 @@
 use rayon::prelude::*;
 use rspack_core::rspack_sources::{BoxSource, ConcatSource, RawSource, SourceExt};
 use rspack_core::{
-  BoxModule, ChunkInitFragments, ChunkUkey, Compilation, RenderModuleContentArgs, RuntimeGlobals,
-  SourceType,
+  BoxModule, ChunkInitFragments, ChunkUkey, CodeGenerationDataTopLevelExports, Compilation,
+  ModuleIdentifier, RenderModuleContentArgs, RuntimeGlobals, SourceType,
 };
 use rspack_error::{error, Result};
 use rustc_hash::FxHashSet as HashSet;
@@ -98,6 +98,55 @@ pub fn render_chunk_modules(
   Ok((sources.boxed(), chunk_init_fragments))
 }
 
+/// Returns the entry module of the chunk and its exports when it's rendered at the top level of the
+/// chunk without the module wrapper, see `CodeGenerationDataTopLevelExports`.
+pub fn get_top_level_entry<'a>(
+  compilation: &'a Compilation,
+  chunk_ukey: &ChunkUkey,
+) -> Option<(ModuleIdentifier, &'a CodeGenerationDataTopLevelExports)> {
+  let [module] = compilation.chunk_graph.get_chunk_entry_modules(chunk_ukey)[..] else {
+    return None;
+  };
+  let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+  compilation
+    .code_generation_results
+    .get(&module, Some(&chunk.runtime))
+    .data
+    .get::<CodeGenerationDataTopLevelExports>()
+    .map(|exports| (module, exports))
+}
+
+/// Renders the entry module at the top level of the chunk, see `get_top_level_entry`.
+pub fn render_top_level_entry(
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+  module_identifier: &ModuleIdentifier,
+) -> Result<(BoxSource, ChunkInitFragments)> {
+  let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+  let module = compilation
+    .module_graph
+    .module_by_identifier(module_identifier)
+    .ok_or_else(|| error!("{module_identifier} should be in the module graph"))?;
+  let code_gen_result = compilation
+    .code_generation_results
+    .get(module_identifier, Some(&chunk.runtime));
+  let Some(origin_source) = code_gen_result.get(&SourceType::JavaScript) else {
+    return Ok((RawSource::from("").boxed(), ChunkInitFragments::default()));
+  };
+  let render_module_result =
+    compilation
+      .plugin_driver
+      .render_module_content(RenderModuleContentArgs {
+        compilation,
+        module,
+        module_source: origin_source.clone(),
+        chunk_init_fragments: ChunkInitFragments::default(),
+      })?;
+  let mut chunk_init_fragments = code_gen_result.chunk_init_fragments.clone();
+  chunk_init_fragments.extend(render_module_result.chunk_init_fragments);
+  Ok((render_module_result.module_source, chunk_init_fragments))
+}
+
 fn render_module(
   source: BoxSource,
   module: &BoxModule,
@@ -340,7 +340,6 @@ impl Visit for ApiScanner<'_> {
     {
       if root.sym == "require" {
         not_supported_expr!(is_require_extensions, "require.extensions");
-        not_supported_expr!(is_require_ensure, "require.ensure");
         not_supported_expr!(is_require_config, "require.config");
         not_supported_expr!(is_require_version, "require.vesrion");
         not_supported_expr!(is_require_amd, "require.amd");
@@ -424,7 +423,6 @@ impl Visit for ApiScanner<'_> {
     {
       if root.sym == "require" {
         not_supported_call!(is_require_config, "require.config()");
-        not_supported_call!(is_require_ensure, "require.ensure()");
         not_supported_call!(is_require_include, "require.include()");
         not_supported_call!(is_require_onerror, "require.onError()");
         not_supported_call!(is_require_main_require, "require.main.require()");
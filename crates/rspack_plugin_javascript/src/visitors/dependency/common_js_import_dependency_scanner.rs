@@ -314,6 +314,8 @@ impl<'a> Visit for CommonJsImportDependencyScanner<'a> {
             category: DependencyCategory::CommonJS,
             request: context,
             namespace_object: ContextNameSpaceObject::Unset,
+            referenced_exports: None,
+            fetch_priority: None,
           },
           Some(call_expr.span.into()),
         )));
@@ -608,6 +608,11 @@ mod test {
       all_star_exports: Default::default(),
       need_create_require: false,
       json_data: None,
+      top_level_declarations: None,
+      top_level_references: Default::default(),
+      used_names: Default::default(),
+      module_concatenation_bailout: None,
+      css_modules_values: None,
     };
     let mut import_map = Default::default();
     let mut deps = vec![];
@@ -7,17 +7,19 @@ use rspack_core::{ContextNameSpaceObject, ContextOptions, DependencyCategory, Sp
 use rspack_error::miette::Diagnostic;
 use rspack_regex::{regexp_as_str, RspackRegex};
 use swc_core::common::comments::Comments;
-use swc_core::common::Spanned;
-use swc_core::ecma::ast::{CallExpr, Callee, Expr, Lit};
+use swc_core::common::{Spanned, SyntaxContext};
+use swc_core::ecma::ast::{CallExpr, Callee, Expr, ExprOrSpread, Lit};
 use swc_core::ecma::atoms::JsWord;
 use swc_core::ecma::visit::{noop_visit_type, Visit, VisitWith};
 
 use super::context_helper::scanner_context_module;
-use super::{is_import_meta_context_call, parse_order_string};
+use super::{expr_matcher, is_import_meta_context_call, parse_order_string};
 use crate::dependency::{ImportContextDependency, ImportDependency};
 use crate::dependency::{ImportEagerDependency, ImportMetaContextDependency};
+use crate::dependency::{RequireEnsureDependency, RequireEnsureItemDependency};
 use crate::no_visit_ignored_stmt;
 use crate::utils::{get_bool_by_obj_prop, get_literal_str_by_obj_prop, get_regex_by_obj_prop};
+use crate::visitors::extract_member_root;
 use crate::webpack_comment::try_extract_webpack_magic_comment;
 
 pub struct ImportScanner<'a> {
@@ -29,6 +31,7 @@ pub struct ImportScanner<'a> {
   pub options: Option<&'a JavascriptParserOptions>,
   pub warning_diagnostics: &'a mut Vec<Box<dyn Diagnostic + Send + Sync>>,
   pub ignored: &'a mut Vec<DependencyLocation>,
+  unresolved_ctxt: SyntaxContext,
 }
 
 fn create_import_meta_context_dependency(node: &CallExpr) -> Option<ImportMetaContextDependency> {
@@ -85,6 +88,8 @@ fn create_import_meta_context_dependency(node: &CallExpr) -> Option<ImportMetaCo
       request: context,
       namespace_object: ContextNameSpaceObject::Unset,
       mode,
+      referenced_exports: None,
+      fetch_priority: None,
     }
   } else {
     ContextOptions {
@@ -98,6 +103,8 @@ fn create_import_meta_context_dependency(node: &CallExpr) -> Option<ImportMetaCo
       category: DependencyCategory::Esm,
       request: context,
       namespace_object: ContextNameSpaceObject::Unset,
+      referenced_exports: None,
+      fetch_priority: None,
     }
   };
   Some(ImportMetaContextDependency::new(
@@ -119,6 +126,7 @@ impl<'a> ImportScanner<'a> {
     options: Option<&'a JavascriptParserOptions>,
     warning_diagnostics: &'a mut Vec<Box<dyn Diagnostic + Send + Sync>>,
     ignored: &'a mut Vec<DependencyLocation>,
+    unresolved_ctxt: SyntaxContext,
   ) -> Self {
     Self {
       module_identifier,
@@ -129,8 +137,102 @@ impl<'a> ImportScanner<'a> {
       options,
       warning_diagnostics,
       ignored,
+      unresolved_ctxt,
     }
   }
+
+  /// `require.ensure(dependencies, callback, errorCallback, chunkName)`, dependencies and blocks
+  /// created inside the callback belong to the ensure block, `import()` becomes a nested block.
+  ///
+  /// Returns `false` when the call is not a valid `require.ensure()`.
+  fn scan_require_ensure(&mut self, node: &CallExpr) -> bool {
+    let is_require_ensure = node.callee.as_expr().is_some_and(|expr| {
+      expr_matcher::is_require_ensure(expr)
+        && extract_member_root(expr).is_some_and(|root| root.span.ctxt == self.unresolved_ctxt)
+    });
+    if !is_require_ensure
+      || !(2..=4).contains(&node.args.len())
+      || node.args.iter().any(|arg| arg.spread.is_some())
+    {
+      return false;
+    }
+    fn as_string(arg: &ExprOrSpread) -> Option<String> {
+      match arg.expr.as_ref() {
+        Expr::Lit(Lit::Str(str)) => Some(str.value.to_string()),
+        _ => None,
+      }
+    }
+
+    let (error_callback, chunk_name) = match &node.args[..] {
+      [_, _, error_callback, chunk_name] => {
+        let Some(chunk_name) = as_string(chunk_name) else {
+          return false;
+        };
+        (Some(error_callback), Some(chunk_name))
+      }
+      [_, _, arg] => match as_string(arg) {
+        Some(chunk_name) => (None, Some(chunk_name)),
+        None => (Some(arg), None),
+      },
+      _ => (None, None),
+    };
+    let requests = match node.args[0].expr.as_ref() {
+      Expr::Array(array) => array
+        .elems
+        .iter()
+        .map(|item| {
+          item
+            .as_ref()
+            .filter(|item| item.spread.is_none())
+            .and_then(as_string)
+        })
+        .collect::<Option<Vec<_>>>(),
+      _ => as_string(&node.args[0]).map(|request| vec![request]),
+    };
+    let Some(requests) = requests else {
+      return false;
+    };
+
+    let span = ErrorSpan::from(node.span);
+    let callback = &node.args[1];
+    let mut block = AsyncDependenciesBlock::new(
+      self.module_identifier,
+      format!("{}:{}", span.start, span.end),
+      Some(DependencyLocation::new(span.start, span.end)),
+    );
+    block.set_group_options(GroupOptions::ChunkGroup(ChunkGroupOptions::new(
+      chunk_name, None, None,
+    )));
+    block.add_dependency(Box::new(RequireEnsureDependency::new(
+      (node.span.real_lo(), node.span.real_hi()),
+      (
+        callback.expr.span().real_lo(),
+        callback.expr.span().real_hi(),
+      ),
+      error_callback.map(|arg| (arg.expr.span().real_lo(), arg.expr.span().real_hi())),
+    )));
+    for request in requests {
+      block.add_dependency(Box::new(RequireEnsureItemDependency::new(
+        request.into(),
+        Some(span),
+      )));
+    }
+
+    let dependencies = std::mem::take(self.dependencies);
+    let blocks = std::mem::take(self.blocks);
+    callback.visit_with(self);
+    for dependency in std::mem::replace(self.dependencies, dependencies) {
+      block.add_dependency(dependency);
+    }
+    for nested_block in std::mem::replace(self.blocks, blocks) {
+      block.add_block(nested_block);
+    }
+    if let Some(error_callback) = error_callback {
+      error_callback.visit_with(self);
+    }
+    self.blocks.push(block);
+    true
+  }
 }
 
 impl Visit for ImportScanner<'_> {
@@ -145,6 +247,9 @@ impl Visit for ImportScanner<'_> {
       self.dependencies.push(Box::new(dep));
       return;
     }
+    if self.scan_require_ensure(node) {
+      return;
+    }
     let Callee::Import(import_call) = &node.callee else {
       node.visit_children_with(self);
       return;
@@ -158,155 +263,129 @@ impl Visit for ImportScanner<'_> {
       return;
     }
 
-    let mode = self
-      .options
-      .map(|o| o.dynamic_import_mode)
-      .unwrap_or_default();
+    let magic_comment_options = try_extract_webpack_magic_comment(
+      &self.comments,
+      dyn_imported.span(),
+      self.warning_diagnostics,
+    );
+    if magic_comment_options
+      .get_webpack_ignore()
+      .unwrap_or_default()
+    {
+      return;
+    }
 
-    let dynamic_import_preload = self
-      .options
-      .map(|o| o.dynamic_import_preload)
-      .and_then(|o| o.get_order());
+    let mode = magic_comment_options.get_webpack_mode().unwrap_or_else(|| {
+      self
+        .options
+        .map(|o| o.dynamic_import_mode)
+        .unwrap_or_default()
+    });
+    let chunk_name = magic_comment_options
+      .get_webpack_chunk_name()
+      .map(|x| x.to_owned());
+    let chunk_preload = magic_comment_options
+      .get_webpack_preload()
+      .and_then(|x| parse_order_string(x.as_str()))
+      .or_else(|| {
+        self
+          .options
+          .map(|o| o.dynamic_import_preload)
+          .and_then(|o| o.get_order())
+      });
+    let chunk_prefetch = magic_comment_options
+      .get_webpack_prefetch()
+      .and_then(|x| parse_order_string(x.as_str()))
+      .or_else(|| {
+        self
+          .options
+          .map(|o| o.dynamic_import_prefetch)
+          .and_then(|o| o.get_order())
+      });
+    let fetch_priority = magic_comment_options.get_webpack_fetch_priority();
+    let referenced_exports = magic_comment_options.get_webpack_exports();
 
-    let dynamic_import_prefetch = self
-      .options
-      .map(|o| o.dynamic_import_prefetch)
-      .and_then(|o| o.get_order());
+    let request = match dyn_imported.expr.as_ref() {
+      Expr::Lit(Lit::Str(imported)) => Some(imported.value.clone()),
+      Expr::Tpl(tpl) if tpl.quasis.len() == 1 => Some(JsWord::from(
+        tpl
+          .quasis
+          .first()
+          .expect("should have one quasis")
+          .raw
+          .to_string(),
+      )),
+      _ => None,
+    };
 
-    match dyn_imported.expr.as_ref() {
-      Expr::Lit(Lit::Str(imported)) => {
-        if matches!(mode, DynamicImportMode::Eager) {
-          let dep = ImportEagerDependency::new(
-            node.span.real_lo(),
-            node.span.real_hi(),
-            imported.value.clone(),
-            Some(node.span.into()),
-            // TODO scan dynamic import referenced exports
-            None,
-          );
-          self.dependencies.push(Box::new(dep));
-          return;
-        }
-        let magic_comment_options = try_extract_webpack_magic_comment(
-          &self.comments,
-          imported.span,
-          self.warning_diagnostics,
-        );
-        if magic_comment_options
-          .get_webpack_ignore()
-          .unwrap_or_default()
-        {
-          return;
-        }
-        let chunk_name = magic_comment_options
-          .get_webpack_chunk_name()
-          .map(|x| x.to_owned());
-        let chunk_prefetch = magic_comment_options
-          .get_webpack_prefetch()
-          .and_then(|x| parse_order_string(x.as_str()));
-        let chunk_preload = magic_comment_options
-          .get_webpack_preload()
-          .and_then(|x| parse_order_string(x.as_str()));
-        let span = ErrorSpan::from(node.span);
-        let dep = Box::new(ImportDependency::new(
-          node.span.real_lo(),
-          node.span.real_hi(),
-          imported.value.clone(),
-          Some(span),
-          // TODO scan dynamic import referenced exports
-          None,
-        ));
-        let mut block = AsyncDependenciesBlock::new(
-          self.module_identifier,
-          format!("{}:{}", span.start, span.end),
-          Some(DependencyLocation::new(span.start, span.end)),
-        );
-        block.set_group_options(GroupOptions::ChunkGroup(ChunkGroupOptions::new(
-          chunk_name,
-          chunk_preload.or(dynamic_import_preload),
-          chunk_prefetch.or(dynamic_import_prefetch),
-        )));
-        block.add_dependency(dep);
-        self.blocks.push(block);
-      }
-      Expr::Tpl(tpl) if tpl.quasis.len() == 1 => {
-        let magic_comment_options =
-          try_extract_webpack_magic_comment(&self.comments, tpl.span, self.warning_diagnostics);
-        let chunk_name = magic_comment_options
-          .get_webpack_chunk_name()
-          .map(|x| x.to_owned());
-        let chunk_prefetch = magic_comment_options
-          .get_webpack_prefetch()
-          .and_then(|x| parse_order_string(x.as_str()));
-        let chunk_preload = magic_comment_options
-          .get_webpack_preload()
-          .and_then(|x| parse_order_string(x.as_str()));
-        let request = JsWord::from(
-          tpl
-            .quasis
-            .first()
-            .expect("should have one quasis")
-            .raw
-            .to_string(),
-        );
-        let span = ErrorSpan::from(node.span);
-        let dep = Box::new(ImportDependency::new(
+    if let Some(request) = request {
+      let span = ErrorSpan::from(node.span);
+      if matches!(mode, DynamicImportMode::Eager | DynamicImportMode::Weak) {
+        let dep = ImportEagerDependency::new(
           node.span.real_lo(),
           node.span.real_hi(),
           request,
           Some(span),
-          None,
-        ));
-        let mut block = AsyncDependenciesBlock::new(
-          self.module_identifier,
-          format!("{}:{}", span.start, span.end),
-          Some(DependencyLocation::new(span.start, span.end)),
-        );
-        block.set_group_options(GroupOptions::ChunkGroup(ChunkGroupOptions::new(
-          chunk_name,
-          chunk_preload.or(dynamic_import_preload),
-          chunk_prefetch.or(dynamic_import_prefetch),
-        )));
-        block.add_dependency(dep);
-        self.blocks.push(block);
-      }
-      _ => {
-        let Some((context, reg)) = scanner_context_module(dyn_imported.expr.as_ref()) else {
-          return;
-        };
-        let magic_comment_options = try_extract_webpack_magic_comment(
-          &self.comments,
-          dyn_imported.span(),
-          self.warning_diagnostics,
+          referenced_exports,
+          matches!(mode, DynamicImportMode::Weak),
         );
-        let chunk_name = magic_comment_options
-          .get_webpack_chunk_name()
-          .map(|x| x.to_owned());
-        self
-          .dependencies
-          .push(Box::new(ImportContextDependency::new(
-            import_call.span.real_lo(),
-            import_call.span.real_hi(),
-            node.span.real_hi(),
-            ContextOptions {
-              chunk_name,
-              mode: ContextMode::Lazy,
-              recursive: true,
-              reg_exp: context_reg_exp(&reg, ""),
-              reg_str: reg,
-              include: None,
-              exclude: None,
-              category: DependencyCategory::Esm,
-              request: context,
-              namespace_object: if self.build_meta.strict_harmony_module {
-                ContextNameSpaceObject::Strict
-              } else {
-                ContextNameSpaceObject::Bool(true)
-              },
-            },
-            Some(node.span.into()),
-          )));
+        self.dependencies.push(Box::new(dep));
+        return;
       }
+      let dep = Box::new(ImportDependency::new(
+        node.span.real_lo(),
+        node.span.real_hi(),
+        request,
+        Some(span),
+        referenced_exports,
+      ));
+      let mut block = AsyncDependenciesBlock::new(
+        self.module_identifier,
+        format!("{}:{}", span.start, span.end),
+        Some(DependencyLocation::new(span.start, span.end)),
+      );
+      block.set_group_options(GroupOptions::ChunkGroup(
+        ChunkGroupOptions::new(chunk_name, chunk_preload, chunk_prefetch)
+          .fetch_priority_optional(fetch_priority),
+      ));
+      block.add_dependency(dep);
+      self.blocks.push(block);
+      return;
     }
+
+    let Some((context, reg)) = scanner_context_module(dyn_imported.expr.as_ref()) else {
+      return;
+    };
+    self
+      .dependencies
+      .push(Box::new(ImportContextDependency::new(
+        import_call.span.real_lo(),
+        import_call.span.real_hi(),
+        node.span.real_hi(),
+        ContextOptions {
+          chunk_name,
+          mode: match mode {
+            DynamicImportMode::Lazy => ContextMode::Lazy,
+            DynamicImportMode::LazyOnce => ContextMode::LazyOnce,
+            DynamicImportMode::Eager => ContextMode::Eager,
+            DynamicImportMode::Weak => ContextMode::AsyncWeak,
+          },
+          recursive: true,
+          reg_exp: context_reg_exp(&reg, ""),
+          reg_str: reg,
+          include: magic_comment_options.get_webpack_include(),
+          exclude: magic_comment_options.get_webpack_exclude(),
+          category: DependencyCategory::Esm,
+          request: context,
+          namespace_object: if self.build_meta.strict_harmony_module {
+            ContextNameSpaceObject::Strict
+          } else {
+            ContextNameSpaceObject::Bool(true)
+          },
+          referenced_exports,
+          fetch_priority,
+        },
+        Some(node.span.into()),
+      )));
   }
 }
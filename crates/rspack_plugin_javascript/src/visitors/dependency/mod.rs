@@ -12,6 +12,7 @@ mod harmony_top_level_this;
 mod hot_module_replacement_scanner;
 mod import_meta_scanner;
 mod import_scanner;
+mod module_concatenation_scanner;
 mod node_stuff_scanner;
 mod url_scanner;
 mod util;
@@ -41,7 +42,8 @@ use self::{
   harmony_top_level_this::HarmonyTopLevelThis,
   hot_module_replacement_scanner::HotModuleReplacementScanner,
   import_meta_scanner::ImportMetaScanner, import_scanner::ImportScanner,
-  node_stuff_scanner::NodeStuffScanner, url_scanner::UrlScanner, worker_scanner::WorkerScanner,
+  module_concatenation_scanner::ModuleConcatenationScanner, node_stuff_scanner::NodeStuffScanner,
+  url_scanner::UrlScanner, worker_scanner::WorkerScanner,
 };
 
 pub struct ScanDependenciesResult {
@@ -67,6 +69,7 @@ pub enum ExtraSpanInfo {
 pub fn scan_dependencies(
   program: &Program,
   unresolved_mark: Mark,
+  top_level_mark: Mark,
   resource_data: &ResourceData,
   compiler_options: &CompilerOptions,
   module_type: &ModuleType,
@@ -186,6 +189,16 @@ pub fn scan_dependencies(
       })
     }
 
+    if build_meta.esm && compiler_options.optimization.concatenate_modules {
+      program.visit_with(&mut ModuleConcatenationScanner::new(
+        build_info,
+        &import_map,
+        unresolved_ctxt,
+        SyntaxContext::empty().apply_mark(top_level_mark),
+        &mut ignored,
+      ));
+    }
+
     let mut worker_syntax_scanner = rspack_core::needs_refactor::WorkerSyntaxScanner::new(
       rspack_core::needs_refactor::DEFAULT_WORKER_SYNTAX,
     );
@@ -242,6 +255,7 @@ pub fn scan_dependencies(
       .and_then(|p| p.get_javascript(module_type)),
     &mut warning_diagnostics,
     &mut ignored,
+    unresolved_ctxt,
   ));
 
   if compiler_options.dev_server.hot {
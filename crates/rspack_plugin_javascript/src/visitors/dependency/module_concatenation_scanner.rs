@@ -0,0 +1,152 @@
+use rspack_core::{BuildInfo, DependencyLocation, RuntimeGlobals, SpanExt, TopLevelReference};
+use rustc_hash::FxHashSet as HashSet;
+use swc_core::common::SyntaxContext;
+use swc_core::ecma::ast::{
+  DefaultDecl, ExportDefaultDecl, ExportDefaultExpr, Ident, ImportDecl, NamedExport, ObjectPatProp,
+  Prop,
+};
+use swc_core::ecma::atoms::JsWord;
+use swc_core::ecma::visit::{noop_visit_type, Visit, VisitWith};
+
+use super::harmony_import_dependency_scanner::ImportMap;
+use crate::dependency::DEFAULT_EXPORT;
+use crate::no_visit_ignored_stmt;
+
+/// Free variables which make a module can't be concatenated with others.
+const BAILOUT_FREE_VARIABLES: [&str; 4] = [
+  "module",
+  "exports",
+  "__webpack_module__",
+  "__webpack_exports__",
+];
+
+// Collect the top level bindings and their references of a harmony module,
+// which are renamed when the module is concatenated by `ModuleConcatenationPlugin`.
+pub struct ModuleConcatenationScanner<'a> {
+  build_info: &'a mut BuildInfo,
+  import_map: &'a ImportMap,
+  unresolved_ctxt: SyntaxContext,
+  top_level_ctxt: SyntaxContext,
+  declarations: HashSet<JsWord>,
+  ignored: &'a mut Vec<DependencyLocation>,
+}
+
+impl<'a> ModuleConcatenationScanner<'a> {
+  pub fn new(
+    build_info: &'a mut BuildInfo,
+    import_map: &'a ImportMap,
+    unresolved_ctxt: SyntaxContext,
+    top_level_ctxt: SyntaxContext,
+    ignored: &'a mut Vec<DependencyLocation>,
+  ) -> Self {
+    Self {
+      build_info,
+      import_map,
+      unresolved_ctxt,
+      top_level_ctxt,
+      declarations: Default::default(),
+      ignored,
+    }
+  }
+
+  fn add_reference(&mut self, ident: &Ident, shorthand: bool) {
+    if ident.span.ctxt == self.top_level_ctxt {
+      // imported bindings are replaced by `HarmonyImportSpecifierDependency`
+      if self.import_map.contains_key(&ident.to_id()) {
+        return;
+      }
+      if &*ident.sym == RuntimeGlobals::REQUIRE.name() {
+        self.bailout(format!(
+          "Module declares the top level variable `{}`",
+          ident.sym
+        ));
+      }
+      self.declarations.insert(ident.sym.clone());
+      self
+        .build_info
+        .top_level_references
+        .push(TopLevelReference {
+          name: ident.sym.clone(),
+          start: ident.span.real_lo(),
+          end: ident.span.real_hi(),
+          shorthand,
+        });
+    } else if ident.span.ctxt == self.unresolved_ctxt {
+      if ident.sym == "eval" {
+        self.bailout("Module uses eval()".to_string());
+      } else if BAILOUT_FREE_VARIABLES.contains(&ident.sym.as_ref()) {
+        self.bailout(format!("Module uses the free variable `{}`", ident.sym));
+      }
+      self.build_info.used_names.insert(ident.sym.clone());
+    } else if ident.span.ctxt != SyntaxContext::empty() {
+      // bindings of nested scopes
+      self.build_info.used_names.insert(ident.sym.clone());
+    }
+  }
+
+  fn bailout(&mut self, reason: String) {
+    self
+      .build_info
+      .module_concatenation_bailout
+      .get_or_insert(reason);
+  }
+}
+
+impl Visit for ModuleConcatenationScanner<'_> {
+  noop_visit_type!();
+  no_visit_ignored_stmt!();
+
+  fn visit_module(&mut self, module: &swc_core::ecma::ast::Module) {
+    module.visit_children_with(self);
+    self.build_info.top_level_declarations = Some(std::mem::take(&mut self.declarations));
+  }
+
+  fn visit_ident(&mut self, ident: &Ident) {
+    self.add_reference(ident, false);
+  }
+
+  fn visit_prop(&mut self, prop: &Prop) {
+    if let Prop::Shorthand(ident) = prop {
+      self.add_reference(ident, true);
+    } else {
+      prop.visit_children_with(self);
+    }
+  }
+
+  fn visit_object_pat_prop(&mut self, prop: &ObjectPatProp) {
+    if let ObjectPatProp::Assign(assign) = prop {
+      self.add_reference(&assign.key, true);
+      assign.value.visit_with(self);
+    } else {
+      prop.visit_children_with(self);
+    }
+  }
+
+  // import declarations are removed, the imported bindings are inlined
+  fn visit_import_decl(&mut self, _: &ImportDecl) {}
+
+  fn visit_named_export(&mut self, named_export: &NamedExport) {
+    // `export { a as b }` is removed and `a` is exported by the concatenated module
+    if named_export.src.is_none() {
+      return;
+    }
+    named_export.visit_children_with(self);
+  }
+
+  fn visit_export_default_expr(&mut self, expr: &ExportDefaultExpr) {
+    self.declarations.insert(DEFAULT_EXPORT.into());
+    expr.visit_children_with(self);
+  }
+
+  fn visit_export_default_decl(&mut self, decl: &ExportDefaultDecl) {
+    let anonymous = match &decl.decl {
+      DefaultDecl::Class(class) => class.ident.is_none(),
+      DefaultDecl::Fn(f) => f.ident.is_none(),
+      DefaultDecl::TsInterfaceDecl(_) => false,
+    };
+    if anonymous {
+      self.declarations.insert(DEFAULT_EXPORT.into());
+    }
+    decl.visit_children_with(self);
+  }
+}
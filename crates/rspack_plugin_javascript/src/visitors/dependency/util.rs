@@ -105,9 +105,9 @@ pub(crate) mod expr_matcher {
     is_import_meta_url: "import.meta.url",
     is_import_meta: "import.meta",
     is_object_define_property: "Object.defineProperty",
+    is_require_ensure: "require.ensure",
     // unsupported
     is_require_extensions: "require.extensions",
-    is_require_ensure: "require.ensure",
     is_require_config: "require.config",
     is_require_version: "require.vesrion",
     is_require_amd: "require.amd",
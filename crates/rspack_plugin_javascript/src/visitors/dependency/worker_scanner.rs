@@ -79,6 +79,7 @@ impl<'a> WorkerScanner<'a> {
     block.set_group_options(GroupOptions::Entrypoint(Box::new(EntryOptions {
       name,
       runtime: Some(runtime),
+      depend_on: None,
       chunk_loading: Some(self.output_options.worker_chunk_loading.clone()),
       async_chunks: None,
       public_path: None,
@@ -1,10 +1,16 @@
+use std::iter::Peekable;
+use std::str::Chars;
+
 use once_cell::sync::Lazy;
 use regex::Captures;
+use rspack_core::{DynamicImportMode, FetchPriority};
 use rspack_error::miette::{diagnostic, Diagnostic, Severity};
 use rspack_error::DiagnosticExt;
+use rspack_regex::RspackRegex;
 use rustc_hash::FxHashMap;
 use swc_core::common::comments::{CommentKind, Comments};
 use swc_core::common::Span;
+use swc_core::ecma::atoms::JsWord;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum WebpackComment {
@@ -12,6 +18,11 @@ pub enum WebpackComment {
   Prefetch,
   Preload,
   Ignore,
+  Mode,
+  Include,
+  Exclude,
+  Exports,
+  FetchPriority,
 }
 
 pub struct WebpackCommentMap(FxHashMap<WebpackComment, String>);
@@ -48,6 +59,118 @@ impl WebpackCommentMap {
       }
     })
   }
+
+  pub fn get_webpack_mode(&self) -> Option<DynamicImportMode> {
+    self
+      .0
+      .get(&WebpackComment::Mode)
+      .and_then(|item| parse_mode(item))
+  }
+
+  pub fn get_webpack_include(&self) -> Option<RspackRegex> {
+    self
+      .0
+      .get(&WebpackComment::Include)
+      .and_then(|item| parse_regexp(item))
+  }
+
+  pub fn get_webpack_exclude(&self) -> Option<RspackRegex> {
+    self
+      .0
+      .get(&WebpackComment::Exclude)
+      .and_then(|item| parse_regexp(item))
+  }
+
+  pub fn get_webpack_exports(&self) -> Option<Vec<JsWord>> {
+    self
+      .0
+      .get(&WebpackComment::Exports)
+      .and_then(|item| parse_string_or_string_array(item))
+  }
+
+  pub fn get_webpack_fetch_priority(&self) -> Option<FetchPriority> {
+    self
+      .0
+      .get(&WebpackComment::FetchPriority)
+      .and_then(|item| parse_fetch_priority(item))
+  }
+}
+
+fn parse_mode(value: &str) -> Option<DynamicImportMode> {
+  match value {
+    "lazy" => Some(DynamicImportMode::Lazy),
+    "lazy-once" => Some(DynamicImportMode::LazyOnce),
+    "eager" => Some(DynamicImportMode::Eager),
+    "weak" => Some(DynamicImportMode::Weak),
+    _ => None,
+  }
+}
+
+fn parse_fetch_priority(value: &str) -> Option<FetchPriority> {
+  match value {
+    "low" => Some(FetchPriority::Low),
+    "high" => Some(FetchPriority::High),
+    "auto" => Some(FetchPriority::Auto),
+    _ => None,
+  }
+}
+
+/// Parse a regexp literal such as `/\.js$/i`.
+fn parse_regexp(value: &str) -> Option<RspackRegex> {
+  let captures = REGEXP_LITERAL.captures(value)?;
+  RspackRegex::with_flags(
+    captures.name("source")?.as_str(),
+    captures.name("flags").map_or("", |m| m.as_str()),
+  )
+  .ok()
+}
+
+/// Parse a quoted string at the start of `chars`, escape sequences are unescaped.
+fn parse_quoted(chars: &mut Peekable<Chars>) -> Option<String> {
+  let quote = chars.next().filter(|c| matches!(c, '"' | '\'' | '`'))?;
+  let mut value = String::new();
+  loop {
+    match chars.next()? {
+      '\\' => value.push(chars.next()?),
+      c if c == quote => return Some(value),
+      c => value.push(c),
+    }
+  }
+}
+
+fn skip_whitespace(chars: &mut Peekable<Chars>) {
+  while chars.next_if(|c| c.is_whitespace()).is_some() {}
+}
+
+fn parse_string(value: &str) -> Option<String> {
+  let mut chars = value.trim().chars().peekable();
+  let value = parse_quoted(&mut chars)?;
+  chars.next().is_none().then_some(value)
+}
+
+/// Parse a string such as `"a"` or an array of strings such as `["a", 'b']`,
+/// items may contain commas and escaped quotes.
+fn parse_string_or_string_array(value: &str) -> Option<Vec<JsWord>> {
+  let value = value.trim();
+  if !value.starts_with('[') {
+    return parse_string(value).map(|item| vec![JsWord::from(item)]);
+  }
+  let mut chars = value[1..].chars().peekable();
+  let mut items = vec![];
+  loop {
+    skip_whitespace(&mut chars);
+    if chars.next_if_eq(&']').is_some() {
+      break;
+    }
+    items.push(JsWord::from(parse_quoted(&mut chars)?));
+    skip_whitespace(&mut chars);
+    match chars.next()? {
+      ',' => {}
+      ']' => break,
+      _ => return None,
+    }
+  }
+  chars.next().is_none().then_some(items)
 }
 
 fn add_magic_comment_warning(
@@ -73,12 +196,23 @@ fn add_magic_comment_warning(
 // _3 for `xxx`
 // _4 for number
 // _5 for true/false
-// TODO: regexp/array
+// _6 for /xxx/flags, `/` is allowed in character classes
+// _7 for ["xxx", 'xxx'], items may contain `,` and `]`
+// _8 for anything else, which is a malformed value
 static WEBPACK_MAGIC_COMMENT_REGEXP: Lazy<regex::Regex> = Lazy::new(|| {
-  regex::Regex::new(r#"(?P<_0>webpack[a-zA-Z\d_-]+)\s*:\s*("(?P<_1>(\./)?([\w0-9_\-\[\]\(\)]+/)*?[\w0-9_\-\[\]\(\)]+)"|'(?P<_2>(\./)?([\w0-9_\-\[\]\(\)]+/)*?[\w0-9_\-\[\]\(\)]+)'|`(?P<_3>(\./)?([\w0-9_\-\[\]\(\)]+/)*?[\w0-9_\-\[\]\(\)]+)`|(?P<_4>[\d.-]+)|(?P<_5>true|false))"#)
+  regex::Regex::new(r#"(?P<_0>webpack[a-zA-Z\d_-]+)\s*:\s*("(?P<_1>[^"]*)"|'(?P<_2>[^']*)'|`(?P<_3>[^`]*)`|(?P<_4>[\d.-]+)|(?P<_5>true|false)|(?P<_6>/(?:[^/\\\[\n]|\\.|\[(?:[^\]\\\n]|\\.)*\])+/[a-z]*)|(?P<_7>\[(?:\s*(?:"(?:[^"\\]|\\.)*"|'(?:[^'\\]|\\.)*'|`(?:[^`\\]|\\.)*`)\s*,?)*\s*\])|(?P<_8>[^,\s]+))"#)
     .expect("invalid regex")
 });
 
+/// Chunk names are relative paths of word characters, `[`, `]`, `(` and `)`,
+/// `..` segments are rejected so that chunks can't be emitted outside of the output path.
+static CHUNK_NAME: Lazy<regex::Regex> = Lazy::new(|| {
+  regex::Regex::new(r"^(\./)?([\w\-\[\]\(\)]+/)*?[\w\-\[\]\(\)]+$").expect("invalid regex")
+});
+
+static REGEXP_LITERAL: Lazy<regex::Regex> =
+  Lazy::new(|| regex::Regex::new(r"^/(?P<source>.+)/(?P<flags>[a-z]*)$").expect("invalid regex"));
+
 pub fn try_extract_webpack_magic_comment(
   comments: &Option<&dyn Comments>,
   span: Span,
@@ -100,13 +234,19 @@ pub fn try_extract_webpack_magic_comment(
                 .name("_1")
                 .or(captures.name("_2"))
                 .or(captures.name("_3"))
+                && CHUNK_NAME.is_match(item_value_match.as_str())
               {
                 result.insert(
                   WebpackComment::ChunkName,
                   item_value_match.as_str().to_string(),
                 );
               } else {
-                add_magic_comment_warning(item_name, "a string", &captures, warning_diagnostics);
+                add_magic_comment_warning(
+                  item_name,
+                  "a string of `/` separated word characters, `-`, `[]` or `()`",
+                  &captures,
+                  warning_diagnostics,
+                );
               }
             }
             "webpackPrefetch" => {
@@ -154,6 +294,81 @@ pub fn try_extract_webpack_magic_comment(
                 );
               }
             }
+            "webpackMode" => {
+              if let Some(item_value_match) = captures
+                .name("_1")
+                .or(captures.name("_2"))
+                .or(captures.name("_3"))
+                && parse_mode(item_value_match.as_str()).is_some()
+              {
+                result.insert(WebpackComment::Mode, item_value_match.as_str().to_string());
+              } else {
+                add_magic_comment_warning(
+                  item_name,
+                  "'lazy', 'lazy-once', 'eager' or 'weak'",
+                  &captures,
+                  warning_diagnostics,
+                );
+              }
+            }
+            "webpackInclude" | "webpackExclude" => {
+              if let Some(item_value_match) = captures.name("_6")
+                && parse_regexp(item_value_match.as_str()).is_some()
+              {
+                result.insert(
+                  if item_name == "webpackInclude" {
+                    WebpackComment::Include
+                  } else {
+                    WebpackComment::Exclude
+                  },
+                  item_value_match.as_str().to_string(),
+                );
+              } else {
+                add_magic_comment_warning(
+                  item_name,
+                  "a regular expression",
+                  &captures,
+                  warning_diagnostics,
+                );
+              }
+            }
+            "webpackExports" => {
+              if let Some(item_value_match) = captures.get(2)
+                && parse_string_or_string_array(item_value_match.as_str()).is_some()
+              {
+                result.insert(
+                  WebpackComment::Exports,
+                  item_value_match.as_str().to_string(),
+                );
+              } else {
+                add_magic_comment_warning(
+                  item_name,
+                  "a string or an array of strings",
+                  &captures,
+                  warning_diagnostics,
+                );
+              }
+            }
+            "webpackFetchPriority" => {
+              if let Some(item_value_match) = captures
+                .name("_1")
+                .or(captures.name("_2"))
+                .or(captures.name("_3"))
+                && parse_fetch_priority(item_value_match.as_str()).is_some()
+              {
+                result.insert(
+                  WebpackComment::FetchPriority,
+                  item_value_match.as_str().to_string(),
+                );
+              } else {
+                add_magic_comment_warning(
+                  item_name,
+                  r#""low", "high" or "auto""#,
+                  &captures,
+                  warning_diagnostics,
+                );
+              }
+            }
             _ => {
               // TODO: other magic comment
             }
@@ -164,3 +379,118 @@ pub fn try_extract_webpack_magic_comment(
   });
   result
 }
+
+#[cfg(test)]
+mod tests {
+  use swc_core::common::comments::{Comment, SingleThreadedComments};
+  use swc_core::common::{BytePos, DUMMY_SP};
+
+  use super::*;
+
+  fn extract(text: &str) -> (WebpackCommentMap, Vec<Box<dyn Diagnostic + Send + Sync>>) {
+    let comments = SingleThreadedComments::default();
+    let span = Span::new(BytePos(1), BytePos(2), Default::default());
+    comments.add_leading(
+      span.lo,
+      Comment {
+        kind: CommentKind::Block,
+        span: DUMMY_SP,
+        text: text.into(),
+      },
+    );
+    let mut warnings = vec![];
+    let result = try_extract_webpack_magic_comment(&Some(&comments), span, &mut warnings);
+    (result, warnings)
+  }
+
+  #[test]
+  fn should_extract_regexp_and_array_values() {
+    let (result, warnings) = extract(
+      r#" webpackMode: "lazy-once", webpackInclude: /\.js$/i, webpackExclude: /\/test\//, webpackExports: ["a", 'b'], webpackFetchPriority: "high" "#,
+    );
+    assert!(warnings.is_empty());
+    assert!(matches!(
+      result.get_webpack_mode(),
+      Some(DynamicImportMode::LazyOnce)
+    ));
+    let include = result.get_webpack_include().expect("should have include");
+    assert!(include.test("./A.JS"));
+    assert!(!include.test("./a.ts"));
+    let exclude = result.get_webpack_exclude().expect("should have exclude");
+    assert!(exclude.test("/src/test/a.js"));
+    assert_eq!(
+      result.get_webpack_exports(),
+      Some(vec![JsWord::from("a"), JsWord::from("b")])
+    );
+    assert_eq!(
+      result.get_webpack_fetch_priority(),
+      Some(FetchPriority::High)
+    );
+  }
+
+  #[test]
+  fn should_extract_string_exports() {
+    let (result, warnings) = extract(r#" webpackExports: "default" "#);
+    assert!(warnings.is_empty());
+    assert_eq!(
+      result.get_webpack_exports(),
+      Some(vec![JsWord::from("default")])
+    );
+  }
+
+  #[test]
+  fn should_tokenize_array_and_regexp_values() {
+    let (result, warnings) =
+      extract(r#" webpackInclude: /[/\\]src[/]/, webpackExports: ["a,b", 'c]', "d\"e", ] "#);
+    assert!(warnings.is_empty());
+    let include = result.get_webpack_include().expect("should have include");
+    assert!(include.test("/app/src/a.js"));
+    assert!(!include.test("/app/lib/a.js"));
+    assert_eq!(
+      result.get_webpack_exports(),
+      Some(vec![
+        JsWord::from("a,b"),
+        JsWord::from("c]"),
+        JsWord::from("d\"e")
+      ])
+    );
+  }
+
+  #[test]
+  fn should_reject_invalid_chunk_names() {
+    let (result, warnings) = extract(r#" webpackChunkName: "pages/[request]" "#);
+    assert!(warnings.is_empty());
+    assert_eq!(
+      result.get_webpack_chunk_name().map(String::as_str),
+      Some("pages/[request]")
+    );
+
+    let (result, warnings) = extract(r#" webpackChunkName: "../../outside" "#);
+    assert!(result.get_webpack_chunk_name().is_none());
+    assert_eq!(warnings.len(), 1);
+  }
+
+  #[test]
+  fn should_warn_malformed_values() {
+    let (result, warnings) = extract(
+      r#" webpackMode: "sync", webpackInclude: "a.js", webpackExports: [a], webpackFetchPriority: high "#,
+    );
+    assert!(result.get_webpack_mode().is_none());
+    assert!(result.get_webpack_include().is_none());
+    assert!(result.get_webpack_exports().is_none());
+    assert!(result.get_webpack_fetch_priority().is_none());
+    let warnings = warnings
+      .iter()
+      .map(|warning| warning.to_string())
+      .collect::<Vec<_>>();
+    assert_eq!(
+      warnings,
+      vec![
+        r#"`webpackMode` expected 'lazy', 'lazy-once', 'eager' or 'weak', but received: "sync"."#,
+        r#"`webpackInclude` expected a regular expression, but received: "a.js"."#,
+        r#"`webpackExports` expected a string or an array of strings, but received: [a]."#,
+        r#"`webpackFetchPriority` expected "low", "high" or "auto", but received: high."#,
+      ]
+    );
+  }
+}
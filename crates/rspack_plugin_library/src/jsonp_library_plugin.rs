@@ -0,0 +1,114 @@
+use std::hash::Hash;
+
+use rspack_core::{
+  rspack_sources::{ConcatSource, RawSource, SourceExt},
+  AdditionalChunkRuntimeRequirementsArgs, Chunk, ChunkUkey, Compilation, Filename, JsChunkHashArgs,
+  LibraryName, LibraryNonUmdObject, LibraryOptions, LibraryType, PathData, Plugin,
+  PluginAdditionalChunkRuntimeRequirementsOutput, PluginContext, PluginJsChunkHashHookOutput,
+  PluginRenderHookOutput, RenderArgs, RuntimeGlobals, SourceType,
+};
+use rspack_error::{error_bail, Diagnostic, Result};
+
+use crate::utils::{get_options_for_chunk, COMMON_LIBRARY_NAME_MESSAGE};
+
+#[derive(Debug)]
+struct JsonpLibraryPluginParsed<'a> {
+  name: &'a str,
+}
+
+#[derive(Debug)]
+pub struct JsonpLibraryPlugin {
+  library_type: LibraryType,
+}
+
+impl JsonpLibraryPlugin {
+  pub fn new(library_type: LibraryType) -> Self {
+    Self { library_type }
+  }
+
+  fn parse_options<'a>(&self, library: &'a LibraryOptions) -> Result<JsonpLibraryPluginParsed<'a>> {
+    let Some(LibraryName::NonUmdObject(LibraryNonUmdObject::String(name))) = &library.name else {
+      error_bail!("Jsonp library name must be a simple string. {COMMON_LIBRARY_NAME_MESSAGE}")
+    };
+    Ok(JsonpLibraryPluginParsed { name })
+  }
+
+  fn get_options_for_chunk<'a>(
+    &self,
+    compilation: &'a Compilation,
+    chunk_ukey: &'a ChunkUkey,
+  ) -> Result<Option<JsonpLibraryPluginParsed<'a>>> {
+    get_options_for_chunk(compilation, chunk_ukey)
+      .filter(|library| library.library_type == self.library_type)
+      .map(|library| self.parse_options(library))
+      .transpose()
+  }
+}
+
+/// The callback name may contain placeholders, e.g. `[name]Loaded`.
+fn render_callback_name(name: &str, chunk: &Chunk, compilation: &Compilation) -> String {
+  compilation.get_path(
+    &Filename::from(name.to_string()),
+    PathData::default().chunk(chunk).content_hash_optional(
+      chunk
+        .content_hash
+        .get(&SourceType::JavaScript)
+        .map(|i| i.rendered(compilation.options.output.hash_digest_length)),
+    ),
+  )
+}
+
+impl Plugin for JsonpLibraryPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.JsonpLibraryPlugin"
+  }
+
+  fn additional_chunk_runtime_requirements(
+    &self,
+    _ctx: PluginContext,
+    args: &mut AdditionalChunkRuntimeRequirementsArgs,
+  ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    match self
+      .get_options_for_chunk(args.compilation, args.chunk)
+      .map(|options| options.is_some())
+    {
+      Ok(true) => {
+        args
+          .runtime_requirements
+          .insert(RuntimeGlobals::RETURN_EXPORTS_FROM_RUNTIME);
+      }
+      Ok(false) => {}
+      // Report invalid options once per chunk, the other hooks skip the chunk
+      Err(e) => args.compilation.push_diagnostic(Diagnostic::from(e)),
+    }
+    Ok(())
+  }
+
+  fn render(&self, _ctx: PluginContext, args: &RenderArgs) -> PluginRenderHookOutput {
+    let compilation = args.compilation;
+    let Ok(Some(options)) = self.get_options_for_chunk(compilation, args.chunk) else {
+      return Ok(None);
+    };
+    let name = render_callback_name(options.name, args.chunk(), compilation);
+    let mut source = ConcatSource::default();
+    source.add(RawSource::from(format!("{name}(")));
+    source.add(args.source.clone());
+    source.add(RawSource::from(")"));
+    Ok(Some(source.boxed()))
+  }
+
+  fn js_chunk_hash(
+    &self,
+    _ctx: PluginContext,
+    args: &mut JsChunkHashArgs,
+  ) -> PluginJsChunkHashHookOutput {
+    let compilation = args.compilation;
+    let Ok(Some(options)) = self.get_options_for_chunk(compilation, args.chunk_ukey) else {
+      return Ok(());
+    };
+    self.name().hash(&mut args.hasher);
+    let chunk = compilation.chunk_by_ukey.expect_get(args.chunk_ukey);
+    render_callback_name(options.name, chunk, compilation).hash(&mut args.hasher);
+    Ok(())
+  }
+}
@@ -3,6 +3,7 @@
 mod amd_library_plugin;
 mod assign_library_plugin;
 mod export_property_library_plugin;
+mod jsonp_library_plugin;
 mod module_library_plugin;
 mod system_library_plugin;
 mod umd_library_plugin;
@@ -11,6 +12,7 @@ mod utils;
 pub use amd_library_plugin::AmdLibraryPlugin;
 pub use assign_library_plugin::*;
 pub use export_property_library_plugin::ExportPropertyLibraryPlugin;
+pub use jsonp_library_plugin::JsonpLibraryPlugin;
 pub use module_library_plugin::ModuleLibraryPlugin;
 use rspack_core::{BoxPlugin, PluginExt};
 pub use system_library_plugin::SystemLibraryPlugin;
@@ -107,6 +109,10 @@ pub fn enable_library_plugin(library_type: String, plugins: &mut Vec<BoxPlugin>)
       plugins.push(ExportPropertyLibraryPlugin::new(library_type.clone(), ns_object_used).boxed());
       plugins.push(AmdLibraryPlugin::new("amd-require" == library_type, library_type).boxed());
     }
+    "jsonp" => {
+      plugins.push(ExportPropertyLibraryPlugin::new(library_type.clone(), ns_object_used).boxed());
+      plugins.push(JsonpLibraryPlugin::new(library_type).boxed());
+    }
     "module" => {
       plugins.push(ExportPropertyLibraryPlugin::new(library_type.clone(), ns_object_used).boxed());
       plugins.push(ModuleLibraryPlugin::default().boxed());
@@ -2,13 +2,51 @@ use std::hash::Hash;
 
 use rspack_core::rspack_sources::{ConcatSource, RawSource, SourceExt};
 use rspack_core::{
-  property_access, to_identifier, ChunkUkey, Compilation, JsChunkHashArgs, LibraryOptions, Plugin,
-  PluginContext, PluginJsChunkHashHookOutput, PluginRenderStartupHookOutput, RenderStartupArgs,
+  property_access, to_identifier, ChunkUkey, Compilation, ExternalRequest, JsChunkHashArgs,
+  LibraryOptions, ModuleIdentifier, Plugin, PluginContext, PluginJsChunkHashHookOutput,
+  PluginRenderStartupHookOutput, RenderStartupArgs,
 };
-use rspack_error::{error_bail, Result};
+use rspack_error::{error, error_bail, Result};
+use rspack_plugin_javascript::dependency::HarmonyExportImportedSpecifierDependency;
+use rspack_plugin_javascript::runtime::get_top_level_entry;
 
 use crate::utils::{get_options_for_chunk, COMMON_LIBRARY_NAME_MESSAGE};
 
+/// Returns the requests of the `module` externals which are re-exported by `export *` in the entry
+/// module. Their exports are unknown at build time, so they are re-exported from the output.
+fn star_reexported_module_externals<'a>(
+  compilation: &'a Compilation,
+  module: &ModuleIdentifier,
+) -> Vec<&'a str> {
+  let module_graph = &compilation.module_graph;
+  let Some(module) = module_graph.module_by_identifier(module) else {
+    return vec![];
+  };
+  module
+    .get_dependencies()
+    .iter()
+    .filter(|id| {
+      module_graph
+        .dependency_by_id(id)
+        .and_then(|dep| dep.downcast_ref::<HarmonyExportImportedSpecifierDependency>())
+        .map_or(false, |dep| dep.export_all)
+    })
+    .filter_map(|id| module_graph.module_graph_module_by_dependency_id(id))
+    .filter_map(|mgm| module_graph.module_by_identifier(&mgm.module_identifier))
+    .filter_map(|module| module.as_external_module())
+    .filter(|module| module.get_external_type() == "module")
+    .filter_map(|module| match &module.request {
+      ExternalRequest::Single(request) => Some(request.primary()),
+      ExternalRequest::Map(map) => map.get("module").map(|request| request.primary()),
+    })
+    .fold(vec![], |mut requests, request| {
+      if !requests.contains(&request) {
+        requests.push(request);
+      }
+      requests
+    })
+}
+
 #[derive(Debug, Default)]
 pub struct ModuleLibraryPlugin;
 
@@ -48,7 +86,18 @@ impl Plugin for ModuleLibraryPlugin {
     let mut source = ConcatSource::default();
     source.add(args.source.clone());
     let mut exports = vec![];
-    if args.compilation.options.is_new_tree_shaking() {
+    if let Some((module, top_level_exports)) = get_top_level_entry(args.compilation, args.chunk)
+      && module == args.module
+    {
+      // the entry module is rendered at the top level, its bindings are exported directly
+      for (name, local) in top_level_exports.inner() {
+        exports.push(if name == local {
+          name.to_string()
+        } else {
+          format!("{local} as {name}")
+        });
+      }
+    } else if args.compilation.options.is_new_tree_shaking() {
       let exports_info = args.compilation.module_graph.get_exports_info(&args.module);
       for id in exports_info.get_ordered_exports() {
         let info = id.get_export_info(&args.compilation.module_graph);
@@ -83,6 +132,12 @@ impl Plugin for ModuleLibraryPlugin {
         exports.join(", ")
       )));
     }
+    for request in star_reexported_module_externals(args.compilation, &args.module) {
+      source.add(RawSource::from(format!(
+        "export * from {};\n",
+        serde_json::to_string(request).map_err(|e| error!(e.to_string()))?
+      )));
+    }
     Ok(Some(source.boxed()))
   }
 
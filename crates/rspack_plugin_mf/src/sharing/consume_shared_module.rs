@@ -75,6 +75,14 @@ impl ConsumeSharedModule {
       build_meta: None,
     }
   }
+
+  pub fn context(&self) -> &Context {
+    &self.context
+  }
+
+  pub fn options(&self) -> &ConsumeOptions {
+    &self.options
+  }
 }
 
 impl Identifiable for ConsumeSharedModule {
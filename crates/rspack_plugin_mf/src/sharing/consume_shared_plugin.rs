@@ -13,12 +13,15 @@ use rspack_core::{
   PluginThisCompilationHookOutput, ResolveOptionsWithDependencyType, ResolveResult, Resolver,
   RuntimeGlobals, ThisCompilationArgs,
 };
-use rspack_error::{error, Diagnostic};
+use rspack_error::{error, Diagnostic, Result};
 use rustc_hash::FxHashMap;
 
 use super::{
   consume_shared_module::ConsumeSharedModule,
   consume_shared_runtime_module::ConsumeSharedRuntimeModule,
+  provide_shared_module::ProvideSharedModule,
+  provide_shared_plugin::ProvideVersion,
+  version::{Range, Version},
 };
 
 #[derive(Debug, Clone)]
@@ -309,6 +312,75 @@ impl ConsumeSharedPlugin {
   }
 }
 
+/// Check the required versions of the strict and singleton consumes against the versions provided
+/// in this compilation, which are otherwise only reported at runtime.
+fn check_provided_versions(title: &str, compilation: &Compilation) -> Vec<Diagnostic> {
+  let mut provided: FxHashMap<(&str, &str), Vec<(Version, &str, &str)>> = FxHashMap::default();
+  for module in compilation.module_graph.modules().values() {
+    if let Some(module) = module.downcast_ref::<ProvideSharedModule>()
+      && let ProvideVersion::Version(version) = module.version()
+      && let Some(parsed) = Version::parse(version)
+    {
+      provided
+        .entry((module.share_scope(), module.share_key()))
+        .or_default()
+        .push((parsed, version.as_str(), module.request()));
+    }
+  }
+
+  let mut diagnostics = vec![];
+  for module in compilation.module_graph.modules().values() {
+    let Some(module) = module.downcast_ref::<ConsumeSharedModule>() else {
+      continue;
+    };
+    let options = module.options();
+    if !options.singleton && !options.strict_version {
+      continue;
+    }
+    let (Some(ConsumeVersion::Version(required_version)), Some(versions)) = (
+      &options.required_version,
+      provided.get(&(options.share_scope.as_str(), options.share_key.as_str())),
+    ) else {
+      continue;
+    };
+    let Some(range) = Range::parse(required_version) else {
+      continue;
+    };
+    let key = &options.share_key;
+    let context = module.context();
+    let message = if options.singleton {
+      // Same as the runtime, the highest version is used for singletons
+      let Some((version, raw_version, request)) = versions.iter().max_by(|a, b| a.0.cmp(&b.0))
+      else {
+        continue;
+      };
+      if range.satisfy(version) {
+        continue;
+      }
+      format!("Unsatisfied version {raw_version} from {request} of shared singleton module {key} (required {required_version} in {context})")
+    } else {
+      if versions
+        .iter()
+        .any(|(version, _, _)| range.satisfy(version))
+      {
+        continue;
+      }
+      let available_versions = versions
+        .iter()
+        .map(|(_, version, request)| format!("{version} from {request}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+      format!("No satisfying version ({required_version}) of shared module {key} found in shared scope {} (required in {context}).\nAvailable versions: {available_versions}", options.share_scope)
+    };
+    diagnostics.push(if options.strict_version {
+      Diagnostic::error(title.to_string(), message)
+    } else {
+      Diagnostic::warn(title.to_string(), message)
+    });
+  }
+  diagnostics
+}
+
 #[async_trait]
 impl Plugin for ConsumeSharedPlugin {
   fn name(&self) -> &'static str {
@@ -403,6 +475,12 @@ impl Plugin for ConsumeSharedPlugin {
     Ok(None)
   }
 
+  async fn finish_modules(&self, compilation: &mut Compilation) -> Result<()> {
+    let diagnostics = check_provided_versions(self.name(), compilation);
+    compilation.push_batch_diagnostic(diagnostics);
+    Ok(())
+  }
+
   fn additional_tree_runtime_requirements(
     &self,
     _ctx: PluginContext,
@@ -9,3 +9,4 @@ pub mod provide_shared_module_factory;
 pub mod provide_shared_plugin;
 pub mod share_runtime_module;
 pub mod share_runtime_plugin;
+pub mod version;
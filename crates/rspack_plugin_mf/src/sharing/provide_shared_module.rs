@@ -63,6 +63,22 @@ impl ProvideSharedModule {
       build_meta: None,
     }
   }
+
+  pub fn share_scope(&self) -> &str {
+    &self.share_scope
+  }
+
+  pub fn share_key(&self) -> &str {
+    &self.name
+  }
+
+  pub fn version(&self) -> &ProvideVersion {
+    &self.version
+  }
+
+  pub fn request(&self) -> &str {
+    &self.request
+  }
 }
 
 impl Identifiable for ProvideSharedModule {
@@ -6,15 +6,16 @@ use regex::Regex;
 use rspack_core::{
   BoxModule, Compilation, CompilationArgs, CompilationParams, DependencyType, EntryOptions,
   NormalModuleCreateData, Plugin, PluginCompilationHookOutput, PluginContext,
-  PluginNormalModuleFactoryModuleHookOutput,
+  PluginNormalModuleFactoryModuleHookOutput, ProvidedSharedModule,
 };
 use rspack_error::{Diagnostic, Result};
+use rspack_identifier::Identifiable;
 use rspack_loader_runner::ResourceData;
 use rustc_hash::FxHashMap;
 use tokio::sync::RwLock;
 
 use super::{
-  provide_shared_dependency::ProvideSharedDependency,
+  provide_shared_dependency::ProvideSharedDependency, provide_shared_module::ProvideSharedModule,
   provide_shared_module_factory::ProvideSharedModuleFactory,
 };
 
@@ -242,4 +243,29 @@ impl Plugin for ProvideSharedPlugin {
     self.prefix_match_provides.write().await.clear();
     Ok(())
   }
+
+  fn stats_shared(
+    &self,
+    compilation: &Compilation,
+    shared: &mut Vec<ProvidedSharedModule>,
+  ) -> Result<()> {
+    shared.extend(
+      compilation
+        .module_graph
+        .modules()
+        .values()
+        .filter_map(|module| module.downcast_ref::<ProvideSharedModule>())
+        .map(|module| ProvidedSharedModule {
+          share_scope: module.share_scope().to_string(),
+          share_key: module.share_key().to_string(),
+          version: match module.version() {
+            ProvideVersion::Version(version) => Some(version.to_string()),
+            ProvideVersion::False => None,
+          },
+          request: module.request().to_string(),
+          module_identifier: module.identifier(),
+        }),
+    );
+    Ok(())
+  }
 }
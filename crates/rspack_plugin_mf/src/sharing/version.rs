@@ -0,0 +1,329 @@
+//! Build time counterpart of `parseRange`, `versionLt` and `satisfy` in `consumesCommon.js`,
+//! see https://docs.npmjs.com/misc/semver#range-grammar for the grammar.
+
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PreRelease {
+  Numeric(u64),
+  Alphanumeric(String),
+}
+
+impl PartialOrd for PreRelease {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for PreRelease {
+  fn cmp(&self, other: &Self) -> Ordering {
+    match (self, other) {
+      (Self::Numeric(a), Self::Numeric(b)) => a.cmp(b),
+      (Self::Numeric(_), Self::Alphanumeric(_)) => Ordering::Less,
+      (Self::Alphanumeric(_), Self::Numeric(_)) => Ordering::Greater,
+      (Self::Alphanumeric(a), Self::Alphanumeric(b)) => a.cmp(b),
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Version {
+  numbers: [u64; 3],
+  pre_release: Vec<PreRelease>,
+}
+
+impl Version {
+  pub fn parse(version: &str) -> Option<Self> {
+    let partial = Partial::parse(version)?;
+    (partial.numbers.len() == 3).then(|| partial.to_version())
+  }
+}
+
+impl PartialOrd for Version {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for Version {
+  fn cmp(&self, other: &Self) -> Ordering {
+    self.numbers.cmp(&other.numbers).then_with(|| {
+      // a version without pre-release is greater than any of its pre-releases
+      match (self.pre_release.is_empty(), other.pre_release.is_empty()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => self.pre_release.cmp(&other.pre_release),
+      }
+    })
+  }
+}
+
+/// A version with optional minor and patch, `1`, `1.2` and `1.x` are all partials.
+#[derive(Debug)]
+struct Partial {
+  numbers: Vec<u64>,
+  pre_release: Vec<PreRelease>,
+}
+
+impl Partial {
+  fn parse(partial: &str) -> Option<Self> {
+    let partial = partial.trim();
+    let partial = partial.strip_prefix('v').unwrap_or(partial);
+    let partial = partial.split_once('+').map_or(partial, |(p, _build)| p);
+    let (numbers, pre_release) = match partial.split_once('-') {
+      Some((numbers, pre_release)) => (numbers, Some(pre_release)),
+      None => (partial, None),
+    };
+    let mut parsed = vec![];
+    if !numbers.is_empty() {
+      for number in numbers.split('.') {
+        if matches!(number, "*" | "x" | "X") {
+          break;
+        }
+        parsed.push(number.parse().ok()?);
+      }
+    }
+    if parsed.len() > 3 {
+      return None;
+    }
+    let pre_release = pre_release
+      .map(|pre_release| {
+        pre_release
+          .split('.')
+          .map(|item| match item.parse() {
+            Ok(number) => PreRelease::Numeric(number),
+            Err(_) => PreRelease::Alphanumeric(item.to_string()),
+          })
+          .collect()
+      })
+      .unwrap_or_default();
+    Some(Self {
+      numbers: parsed,
+      pre_release,
+    })
+  }
+
+  /// The lowest version matched by the partial.
+  fn to_version(&self) -> Version {
+    let mut numbers = [0; 3];
+    numbers[..self.numbers.len()].copy_from_slice(&self.numbers);
+    Version {
+      numbers,
+      pre_release: self.pre_release.clone(),
+    }
+  }
+
+  /// The lowest version greater than all versions matched by the partial,
+  /// `None` if the partial matches any version.
+  fn next(&self) -> Option<Version> {
+    self.bump(self.numbers.len().checked_sub(1)?)
+  }
+
+  /// Increase the number at `index` and reset the following ones,
+  /// `None` if the number at `index` is a wildcard.
+  fn bump(&self, index: usize) -> Option<Version> {
+    if index >= self.numbers.len() {
+      return None;
+    }
+    let mut numbers = [0; 3];
+    numbers[..index].copy_from_slice(&self.numbers[..index]);
+    numbers[index] = self.numbers[index] + 1;
+    Some(Version {
+      numbers,
+      pre_release: vec![],
+    })
+  }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Operator {
+  Gt,
+  Gte,
+  Lt,
+  Lte,
+  Eq,
+}
+
+#[derive(Debug)]
+struct Comparator {
+  operator: Operator,
+  version: Version,
+}
+
+impl Comparator {
+  fn new(operator: Operator, version: Version) -> Self {
+    Self { operator, version }
+  }
+
+  fn test(&self, version: &Version) -> bool {
+    let ordering = version.cmp(&self.version);
+    match self.operator {
+      Operator::Gt => ordering.is_gt(),
+      Operator::Gte => ordering.is_ge(),
+      Operator::Lt => ordering.is_lt(),
+      Operator::Lte => ordering.is_le(),
+      Operator::Eq => ordering.is_eq(),
+    }
+  }
+}
+
+/// Comparators that must all be satisfied.
+type ComparatorSet = Vec<Comparator>;
+
+fn parse_simple(operator: &str, partial: &str) -> Option<ComparatorSet> {
+  let partial = Partial::parse(partial)?;
+  let lower = || Comparator::new(Operator::Gte, partial.to_version());
+  let upper =
+    |version: Option<Version>| version.map(|version| Comparator::new(Operator::Lt, version));
+  let set = match operator {
+    "^" => {
+      // bump the first non-zero number, or the last specified one
+      let index = partial
+        .numbers
+        .iter()
+        .position(|number| *number != 0)
+        .unwrap_or(partial.numbers.len().saturating_sub(1));
+      std::iter::once(lower())
+        .chain(upper(partial.bump(index)))
+        .collect()
+    }
+    "~" => {
+      let index = partial.numbers.len().clamp(1, 2) - 1;
+      std::iter::once(lower())
+        .chain(upper(partial.bump(index)))
+        .collect()
+    }
+    ">=" => vec![lower()],
+    "<" => vec![Comparator::new(Operator::Lt, partial.to_version())],
+    ">" if partial.numbers.len() == 3 => vec![Comparator::new(Operator::Gt, partial.to_version())],
+    // `>1.2` is `>=1.3.0`
+    ">" => match partial.next() {
+      Some(version) => vec![Comparator::new(Operator::Gte, version)],
+      // `>*` matches nothing
+      None => vec![Comparator::new(Operator::Lt, Version::parse("0.0.0")?)],
+    },
+    "<=" if partial.numbers.len() == 3 => {
+      vec![Comparator::new(Operator::Lte, partial.to_version())]
+    }
+    // `<=1.2` is `<1.3.0`
+    "<=" => upper(partial.next()).into_iter().collect(),
+    "=" | "" if partial.numbers.len() == 3 => {
+      vec![Comparator::new(Operator::Eq, partial.to_version())]
+    }
+    // `1.2` is `1.2.x`
+    "=" | "" => std::iter::once(lower())
+      .chain(upper(partial.next()))
+      .collect(),
+    _ => return None,
+  };
+  Some(set)
+}
+
+fn parse_comparator_set(range: &str) -> Option<ComparatorSet> {
+  let range = range.trim();
+  if let Some((from, to)) = range.split_once(" - ") {
+    // `1.2 - 2.3` is `>=1.2.0 <2.4.0`
+    let mut set = parse_simple(">=", from)?;
+    set.extend(parse_simple("<=", to)?);
+    return Some(set);
+  }
+  let mut set = vec![];
+  let mut operator = "";
+  for token in range.split_whitespace() {
+    let operator_len = ["<=", ">=", "^", "~", "<", ">", "="]
+      .into_iter()
+      .find(|operator| token.starts_with(operator))
+      .map_or(0, str::len);
+    let (token_operator, partial) = token.split_at(operator_len);
+    if !token_operator.is_empty() {
+      operator = token_operator;
+    }
+    // `^ 1.2` is allowed, the operator is separated from its partial
+    if partial.is_empty() {
+      continue;
+    }
+    set.extend(parse_simple(operator, partial)?);
+    operator = "";
+  }
+  Some(set)
+}
+
+/// A version range like `^1.2.0 || >=2.1.0 <3`.
+#[derive(Debug)]
+pub struct Range(Vec<ComparatorSet>);
+
+impl Range {
+  pub fn parse(range: &str) -> Option<Self> {
+    range
+      .split("||")
+      .map(parse_comparator_set)
+      .collect::<Option<Vec<_>>>()
+      .map(Self)
+  }
+
+  pub fn satisfy(&self, version: &Version) -> bool {
+    self
+      .0
+      .iter()
+      .any(|set| set.iter().all(|comparator| comparator.test(version)))
+  }
+}
+
+/// Whether `version` satisfies `range`, versions or ranges which can't be parsed never match.
+pub fn satisfy(range: &str, version: &str) -> bool {
+  let (Some(range), Some(version)) = (Range::parse(range), Version::parse(version)) else {
+    return false;
+  };
+  range.satisfy(&version)
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn compare_versions() {
+    let v = |v: &str| Version::parse(v).expect("should be a valid version");
+    assert!(v("1.2.3") < v("1.10.0"));
+    assert!(v("1.0.0-alpha") < v("1.0.0"));
+    assert!(v("1.0.0-alpha") < v("1.0.0-alpha.1"));
+    assert!(v("1.0.0-alpha.2") < v("1.0.0-alpha.10"));
+    assert!(v("1.0.0-1") < v("1.0.0-alpha"));
+    assert_eq!(v("v1.2.3+build"), v("1.2.3"));
+    assert!(Version::parse("1.2").is_none());
+  }
+
+  #[test]
+  fn satisfy_ranges() {
+    assert!(satisfy("*", "1.2.3"));
+    assert!(satisfy("", "1.2.3"));
+    assert!(satisfy("1.2.3", "1.2.3"));
+    assert!(!satisfy("1.2.3", "1.2.4"));
+    assert!(satisfy("1.x", "1.9.0"));
+    assert!(!satisfy("1.2", "1.3.0"));
+    assert!(satisfy("^1.2.3", "1.9.0"));
+    assert!(!satisfy("^1.2.3", "2.0.0"));
+    assert!(!satisfy("^1.2.3", "1.2.2"));
+    assert!(satisfy("^0.2.3", "0.2.9"));
+    assert!(!satisfy("^0.2.3", "0.3.0"));
+    assert!(!satisfy("^0.0.3", "0.0.4"));
+    assert!(satisfy("^0", "0.9.0"));
+    assert!(satisfy("^*", "2.0.0"));
+    assert!(satisfy("~*", "2.0.0"));
+    assert!(satisfy("~1.2.3", "1.2.9"));
+    assert!(!satisfy("~1.2.3", "1.3.0"));
+    assert!(satisfy("~1", "1.9.0"));
+    assert!(satisfy(">=1.2.0 <2", "1.5.0"));
+    assert!(!satisfy(">=1.2.0 <2", "2.0.0"));
+    assert!(satisfy(">1.2", "1.3.0"));
+    assert!(!satisfy(">1.2", "1.2.9"));
+    assert!(satisfy("<=1.2", "1.2.9"));
+    assert!(satisfy("1.2 - 2.3", "2.3.5"));
+    assert!(!satisfy("1.2 - 2.3", "2.4.0"));
+    assert!(satisfy("^1.0.0 || ^2.0.0", "2.1.0"));
+    assert!(!satisfy("^1.0.0 || ^2.0.0", "3.0.0"));
+    assert!(satisfy("^ 1.0.0", "1.1.0"));
+    assert!(!satisfy("not a range", "1.0.0"));
+  }
+}
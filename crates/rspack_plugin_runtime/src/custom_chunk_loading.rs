@@ -0,0 +1,73 @@
+use std::fmt;
+
+use async_trait::async_trait;
+use rspack_core::{
+  BoxRuntimeModule, ChunkLoading, ChunkLoadingType, Plugin, PluginContext,
+  PluginRuntimeRequirementsInTreeOutput, RuntimeGlobals, RuntimeRequirementsInTreeArgs,
+};
+
+use crate::runtime_module::is_enabled_for_chunk;
+
+pub type CreateChunkLoadingRuntimeModule = Box<dyn Fn() -> BoxRuntimeModule + Send + Sync>;
+
+/// Registers a chunk loading type which is not built in, the chunks whose `chunkLoading`
+/// is the registered type will load their async chunks with the given runtime module.
+pub struct CustomChunkLoadingPlugin {
+  chunk_loading: ChunkLoading,
+  runtime_requirements: RuntimeGlobals,
+  create_runtime_module: CreateChunkLoadingRuntimeModule,
+}
+
+impl CustomChunkLoadingPlugin {
+  /// `runtime_requirements` are the runtime globals used by the runtime module, they are
+  /// added to the chunks which load their async chunks with it.
+  pub fn new(
+    chunk_loading_type: impl Into<String>,
+    runtime_requirements: RuntimeGlobals,
+    create_runtime_module: CreateChunkLoadingRuntimeModule,
+  ) -> Self {
+    Self {
+      chunk_loading: ChunkLoading::Enable(ChunkLoadingType::Custom(chunk_loading_type.into())),
+      runtime_requirements,
+      create_runtime_module,
+    }
+  }
+}
+
+impl fmt::Debug for CustomChunkLoadingPlugin {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("CustomChunkLoadingPlugin")
+      .field("chunk_loading", &self.chunk_loading)
+      .field("runtime_requirements", &self.runtime_requirements)
+      .finish()
+  }
+}
+
+#[async_trait]
+impl Plugin for CustomChunkLoadingPlugin {
+  fn name(&self) -> &'static str {
+    "CustomChunkLoadingPlugin"
+  }
+
+  fn runtime_requirements_in_tree(
+    &self,
+    _ctx: PluginContext,
+    args: &mut RuntimeRequirementsInTreeArgs,
+  ) -> PluginRuntimeRequirementsInTreeOutput {
+    let compilation = &mut args.compilation;
+    let chunk = args.chunk;
+    if !is_enabled_for_chunk(chunk, &self.chunk_loading, compilation)
+      || !args
+        .runtime_requirements
+        .contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
+    {
+      return Ok(());
+    }
+
+    args
+      .runtime_requirements_mut
+      .insert(self.runtime_requirements);
+    compilation.add_runtime_module(chunk, (self.create_runtime_module)());
+    Ok(())
+  }
+}
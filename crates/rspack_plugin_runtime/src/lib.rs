@@ -20,9 +20,12 @@ mod module_chunk_loading;
 pub use module_chunk_loading::ModuleChunkLoadingPlugin;
 mod import_scripts_chunk_loading;
 pub use import_scripts_chunk_loading::ImportScriptsChunkLoadingPlugin;
+mod custom_chunk_loading;
+pub use custom_chunk_loading::CustomChunkLoadingPlugin;
 mod runtime_module;
 pub use runtime_module::{
   chunk_has_css, is_enabled_for_chunk, render_condition_map, stringify_chunks, BooleanMatcher,
+  CustomChunkLoadingRuntimeModule,
 };
 mod startup_chunk_dependencies;
 pub use startup_chunk_dependencies::StartupChunkDependenciesPlugin;
@@ -62,5 +65,7 @@ pub fn enable_chunk_loading_plugin(loading_type: ChunkLoadingType, plugins: &mut
       plugins.push(ImportScriptsChunkLoadingPlugin.boxed());
     }
     ChunkLoadingType::Import => plugins.push(ModuleChunkLoadingPlugin.boxed()),
+    // enabled by the plugin which registers the custom type, e.g. `CustomChunkLoadingPlugin`
+    ChunkLoadingType::Custom(_) => {}
   }
 }
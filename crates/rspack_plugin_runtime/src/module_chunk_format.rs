@@ -5,7 +5,8 @@ use rspack_core::rspack_sources::{ConcatSource, RawSource, SourceExt};
 use rspack_core::{
   AdditionalChunkRuntimeRequirementsArgs, ChunkKind, JsChunkHashArgs, Plugin,
   PluginAdditionalChunkRuntimeRequirementsOutput, PluginContext, PluginJsChunkHashHookOutput,
-  PluginRenderChunkHookOutput, RenderChunkArgs, RenderStartupArgs, RuntimeGlobals,
+  PluginRenderChunkHookOutput, PluginRenderStartupHookOutput, RenderChunkArgs, RenderStartupArgs,
+  RuntimeGlobals,
 };
 use rspack_plugin_javascript::runtime::render_chunk_runtime_modules;
 use rustc_hash::FxHashSet as HashSet;
@@ -35,6 +36,14 @@ impl Plugin for ModuleChunkFormatPlugin {
     let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
 
     if chunk.has_runtime(&compilation.chunk_group_by_ukey) {
+      // The initial chunks the entries depend on are imported statically and installed in startup
+      if chunk.has_entry_module(&compilation.chunk_graph)
+        && compilation
+          .chunk_graph
+          .has_chunk_entry_dependent_chunks(chunk_ukey, &compilation.chunk_group_by_ukey)
+      {
+        runtime_requirements.insert(RuntimeGlobals::EXTERNAL_INSTALL_CHUNK);
+      }
       return Ok(());
     }
 
@@ -56,9 +65,12 @@ impl Plugin for ModuleChunkFormatPlugin {
     _ctx: PluginContext,
     args: &mut JsChunkHashArgs,
   ) -> PluginJsChunkHashHookOutput {
-    if args
-      .chunk()
-      .has_runtime(&args.compilation.chunk_group_by_ukey)
+    let chunk = args.chunk();
+    if chunk.has_runtime(&args.compilation.chunk_group_by_ukey)
+      && !args
+        .compilation
+        .chunk_graph
+        .has_chunk_entry_dependent_chunks(args.chunk_ukey, &args.compilation.chunk_group_by_ukey)
     {
       return Ok(());
     }
@@ -78,6 +90,45 @@ impl Plugin for ModuleChunkFormatPlugin {
     Ok(())
   }
 
+  fn render_startup(
+    &self,
+    _ctx: PluginContext,
+    args: &RenderStartupArgs,
+  ) -> PluginRenderStartupHookOutput {
+    let compilation = args.compilation;
+    let chunk = compilation.chunk_by_ukey.expect_get(args.chunk);
+    // Startup of the chunks without runtime is rendered in `render_chunk`
+    if !chunk.has_runtime(&compilation.chunk_group_by_ukey)
+      || !compilation
+        .chunk_graph
+        .has_chunk_entry_dependent_chunks(args.chunk, &compilation.chunk_group_by_ukey)
+    {
+      return Ok(None);
+    }
+
+    let base_chunk_output_name = get_chunk_output_name(chunk, compilation);
+    let mut sources = ConcatSource::default();
+    for (index, chunk_ukey) in compilation
+      .chunk_graph
+      .get_chunk_entry_dependent_chunks_iterable(
+        args.chunk,
+        &compilation.chunk_by_ukey,
+        &compilation.chunk_group_by_ukey,
+      )
+      .enumerate()
+    {
+      let chunk = compilation.chunk_by_ukey.expect_get(&chunk_ukey);
+      let other_chunk_output_name = get_chunk_output_name(chunk, compilation);
+      sources.add(RawSource::from(format!(
+        "import * as __webpack_chunk_${index}__ from '{}';\n{}(__webpack_chunk_${index}__);\n",
+        get_relative_path(&base_chunk_output_name, &other_chunk_output_name),
+        RuntimeGlobals::EXTERNAL_INSTALL_CHUNK
+      )));
+    }
+    sources.add(args.source.clone());
+    Ok(Some(sources.boxed()))
+  }
+
   async fn render_chunk(
     &self,
     _ctx: PluginContext,
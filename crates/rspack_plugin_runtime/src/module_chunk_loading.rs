@@ -1,13 +1,25 @@
 use async_trait::async_trait;
 use rspack_core::{
-  ChunkLoading, ChunkLoadingType, Plugin, PluginContext, PluginRuntimeRequirementsInTreeOutput,
-  RuntimeGlobals, RuntimeModuleExt, RuntimeRequirementsInTreeArgs,
+  ChunkLoading, ChunkLoadingType, ChunkUkey, Compilation, Plugin, PluginContext,
+  PluginRuntimeRequirementsInTreeOutput, RuntimeGlobals, RuntimeModuleExt,
+  RuntimeRequirementsInTreeArgs,
 };
 
 use crate::runtime_module::{
   is_enabled_for_chunk, ExportWebpackRequireRuntimeModule, ModuleChunkLoadingRuntimeModule,
 };
 
+/// Whether the entry chunks without runtime import `__webpack_require__` from the runtime chunk.
+/// Otherwise the default export is not needed, and it would conflict with the exports of a
+/// `module` library.
+fn is_imported_by_other_entry_chunks(runtime_chunk: &ChunkUkey, compilation: &Compilation) -> bool {
+  compilation.entrypoints.values().any(|entrypoint| {
+    let entrypoint = compilation.chunk_group_by_ukey.expect_get(entrypoint);
+    entrypoint.get_runtime_chunk() == *runtime_chunk
+      && entrypoint.get_entry_point_chunk() != *runtime_chunk
+  })
+}
+
 #[derive(Debug)]
 pub struct ModuleChunkLoadingPlugin;
 
@@ -38,7 +50,9 @@ impl Plugin for ModuleChunkLoadingPlugin {
         }
         RuntimeGlobals::EXTERNAL_INSTALL_CHUNK if is_enabled_for_chunk => {
           has_chunk_loading = true;
-          compilation.add_runtime_module(chunk, ExportWebpackRequireRuntimeModule::new().boxed());
+          if is_imported_by_other_entry_chunks(chunk, compilation) {
+            compilation.add_runtime_module(chunk, ExportWebpackRequireRuntimeModule::new().boxed());
+          }
         }
         RuntimeGlobals::ON_CHUNKS_LOADED | RuntimeGlobals::BASE_URI if is_enabled_for_chunk => {
           has_chunk_loading = true;
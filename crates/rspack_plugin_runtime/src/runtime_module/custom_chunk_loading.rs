@@ -0,0 +1,41 @@
+use rspack_core::{
+  impl_runtime_module,
+  rspack_sources::{BoxSource, RawSource, SourceExt},
+  Compilation, RuntimeModule, RuntimeModuleStage,
+};
+use rspack_identifier::Identifier;
+
+/// The runtime module of a chunk loading type registered with its source, which is
+/// expected to add a handler to `__webpack_require__.f`.
+#[derive(Debug, Eq)]
+pub struct CustomChunkLoadingRuntimeModule {
+  id: Identifier,
+  source: String,
+}
+
+impl CustomChunkLoadingRuntimeModule {
+  pub fn new(chunk_loading_type: &str, source: String) -> Self {
+    Self {
+      id: Identifier::from(format!(
+        "webpack/runtime/{chunk_loading_type}_chunk_loading"
+      )),
+      source,
+    }
+  }
+}
+
+impl RuntimeModule for CustomChunkLoadingRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, _compilation: &Compilation) -> BoxSource {
+    RawSource::from(self.source.clone()).boxed()
+  }
+
+  fn stage(&self) -> RuntimeModuleStage {
+    RuntimeModuleStage::Attach
+  }
+}
+
+impl_runtime_module!(CustomChunkLoadingRuntimeModule);
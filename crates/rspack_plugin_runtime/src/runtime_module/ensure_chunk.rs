@@ -30,13 +30,20 @@ impl RuntimeModule for EnsureChunkRuntimeModule {
   fn generate(&self, compilation: &Compilation) -> BoxSource {
     let chunk_ukey = self.chunk.expect("should have chunk");
     let runtime_requirements = get_chunk_runtime_requirements(compilation, &chunk_ukey);
-    RawSource::from(
-      match runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS) {
-        true => include_str!("runtime/ensure_chunk.js"),
-        false => include_str!("runtime/ensure_chunk_with_inline.js"),
-      },
-    )
-    .boxed()
+    match runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS) {
+      true => {
+        let fetch_priority = if runtime_requirements.contains(RuntimeGlobals::HAS_FETCH_PRIORITY) {
+          ", fetchPriority"
+        } else {
+          ""
+        };
+        RawSource::from(
+          include_str!("runtime/ensure_chunk.js").replace("$FETCH_PRIORITY$", fetch_priority),
+        )
+        .boxed()
+      }
+      false => RawSource::from(include_str!("runtime/ensure_chunk_with_inline.js")).boxed(),
+    }
   }
 
   fn attach(&mut self, chunk: ChunkUkey) {
@@ -59,6 +59,7 @@ impl RuntimeModule for JsonpChunkLoadingRuntimeModule {
     let with_callback = runtime_requirements.contains(RuntimeGlobals::CHUNK_CALLBACK);
     let with_prefetch = runtime_requirements.contains(RuntimeGlobals::PREFETCH_CHUNK_HANDLERS);
     let with_preload = runtime_requirements.contains(RuntimeGlobals::PRELOAD_CHUNK_HANDLERS);
+    let with_fetch_priority = runtime_requirements.contains(RuntimeGlobals::HAS_FETCH_PRIORITY);
     let cross_origin_loading = &compilation.options.output.cross_origin_loading;
     let script_type = &compilation.options.output.script_type;
 
@@ -93,6 +94,11 @@ impl RuntimeModule for JsonpChunkLoadingRuntimeModule {
     )));
 
     if with_loading {
+      let fetch_priority = if with_fetch_priority {
+        ", fetchPriority"
+      } else {
+        ""
+      };
       let body = if matches!(has_js_matcher, BooleanMatcher::Condition(false)) {
         "installedChunks[chunkId] = 0;".to_string()
       } else {
@@ -106,11 +112,12 @@ impl RuntimeModule for JsonpChunkLoadingRuntimeModule {
               "else installedChunks[chunkId] = 0;\n"
             },
           )
+          .replace("$FETCH_PRIORITY$", fetch_priority)
       };
 
       source.add(RawSource::from(format!(
         r#"
-        {}.j = function (chunkId, promises) {{
+        {}.j = function (chunkId, promises{fetch_priority}) {{
           {body}
         }}
         "#,
@@ -1,12 +1,16 @@
 use itertools::Itertools;
 use rayon::prelude::*;
 use rspack_core::rspack_sources::{BoxSource, ConcatSource, RawSource, SourceExt};
-use rspack_core::{impl_runtime_module, ChunkUkey, Compilation, RuntimeModule};
+use rspack_core::{
+  impl_runtime_module, ChunkUkey, Compilation, DependenciesBlock, RuntimeGlobals, RuntimeModule,
+};
 use rspack_identifier::Identifier;
 use rspack_plugin_javascript::runtime::stringify_array;
 use rustc_hash::FxHashMap as HashMap;
 use rustc_hash::FxHashSet as HashSet;
 
+use crate::get_chunk_runtime_requirements;
+
 #[derive(Debug, Eq)]
 pub struct LoadChunkWithBlockRuntimeModule {
   id: Identifier,
@@ -31,13 +35,23 @@ impl RuntimeModule for LoadChunkWithBlockRuntimeModule {
     let chunk_ukey = self.chunk.expect("should have chunk");
     let runtime = &compilation.chunk_by_ukey.expect_get(&chunk_ukey).runtime;
 
-    let blocks = compilation
+    let mut blocks = HashSet::default();
+    let mut queue = compilation
       .module_graph
       .modules()
       .par_iter()
       .map(|(_, module)| module.get_blocks())
       .flatten()
-      .collect::<HashSet<_>>();
+      .copied()
+      .collect::<Vec<_>>();
+    while let Some(block_id) = queue.pop() {
+      if !blocks.insert(block_id) {
+        continue;
+      }
+      if let Some(block) = compilation.module_graph.block_by_id(&block_id) {
+        queue.extend(block.get_blocks().iter().copied());
+      }
+    }
     let map = blocks
       .par_iter()
       .filter_map(|block_id| {
@@ -73,8 +87,22 @@ impl RuntimeModule for LoadChunkWithBlockRuntimeModule {
       "var map = {};\n",
       &stringify_map(&map)
     )));
-    source.add(RawSource::from(
-      "
+    if get_chunk_runtime_requirements(compilation, &chunk_ukey)
+      .contains(RuntimeGlobals::HAS_FETCH_PRIORITY)
+    {
+      source.add(RawSource::from(
+        "
+__webpack_require__.el = function(module, fetchPriority) {
+  var chunkIds = map[module];
+  if (chunkIds === undefined) return Promise.resolve();
+  if (chunkIds.length > 1) return Promise.all(chunkIds.map(function (chunkId) { return __webpack_require__.e(chunkId, fetchPriority); }));
+  return __webpack_require__.e(chunkIds[0], fetchPriority);
+}
+",
+      ));
+    } else {
+      source.add(RawSource::from(
+        "
 __webpack_require__.el = function(module) {
   var chunkIds = map[module];
   if (chunkIds === undefined) return Promise.resolve();
@@ -82,7 +110,8 @@ __webpack_require__.el = function(module) {
   return __webpack_require__.e(chunkIds[0]);
 }
 ",
-    ));
+      ));
+    }
 
     source.boxed()
   }
@@ -10,14 +10,16 @@ pub struct LoadScriptRuntimeModule {
   id: Identifier,
   unique_name: String,
   with_create_script_url: bool,
+  with_fetch_priority: bool,
 }
 
 impl LoadScriptRuntimeModule {
-  pub fn new(unique_name: String, with_create_script_url: bool) -> Self {
+  pub fn new(unique_name: String, with_create_script_url: bool, with_fetch_priority: bool) -> Self {
     Self {
       id: Identifier::from("webpack/runtime/load_script"),
       unique_name,
       with_create_script_url,
+      with_fetch_priority,
     }
   }
 }
@@ -84,6 +86,25 @@ impl RuntimeModule for LoadScriptRuntimeModule {
             None => "",
           },
         )
+        .replace(
+          "$FETCH_PRIORITY$",
+          if self.with_fetch_priority {
+            ", fetchPriority"
+          } else {
+            ""
+          },
+        )
+        .replace(
+          "$SET_FETCH_PRIORITY$",
+          if self.with_fetch_priority {
+            r#"
+		if (fetchPriority) {
+			script.setAttribute("fetchpriority", fetchPriority);
+		}"#
+          } else {
+            ""
+          },
+        )
         .replace(
           "$UNIQUE_PREFIX$",
           unique_prefix.unwrap_or_default().as_str(),
@@ -393,6 +393,7 @@ impl Plugin for RuntimePlugin {
           LoadScriptRuntimeModule::new(
             compilation.options.output.unique_name.clone(),
             compilation.options.output.trusted_types.is_some(),
+            runtime_requirements.contains(RuntimeGlobals::HAS_FETCH_PRIORITY),
           )
           .boxed(),
         ),
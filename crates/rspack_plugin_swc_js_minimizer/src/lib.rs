@@ -5,6 +5,7 @@ mod minify;
 use std::collections::HashMap;
 use std::hash::Hash;
 use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{mpsc, Mutex};
 
 use async_trait::async_trait;
@@ -14,11 +15,14 @@ use regex::Regex;
 use rspack_core::rspack_sources::{ConcatSource, MapOptions, RawSource, SourceExt, SourceMap};
 use rspack_core::rspack_sources::{Source, SourceMapSource, SourceMapSourceOptions};
 use rspack_core::{
-  AssetInfo, CompilationAsset, JsChunkHashArgs, Plugin, PluginContext, PluginJsChunkHashHookOutput,
-  PluginProcessAssetsOutput, ProcessAssetsArgs,
+  cache::MinifyResult, AssetInfo, CompilationAsset, JsChunkHashArgs, Logger, Plugin, PluginContext,
+  PluginJsChunkHashHookOutput, PluginProcessAssetsOutput, ProcessAssetsArgs,
 };
 use rspack_error::miette::IntoDiagnostic;
 use rspack_error::{Diagnostic, Result};
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+use rspack_identifier::Identifier;
+use rspack_plugin_javascript::ExtractedCommentsInfo;
 use rspack_regex::RspackRegex;
 use rspack_util::try_any_sync;
 use swc_config::config_types::BoolOrDataConfig;
@@ -174,6 +178,14 @@ impl Plugin for SwcJsMinimizerRspackPlugin {
           .unwrap_or_else(|_| panic!("`{condition}` is invalid extractComments condition"))
       });
     let emit_source_map_columns = !compilation.options.devtool.cheap();
+    let cache = compilation.cache.clone();
+    let options_hash = {
+      let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+      minify_options.hash(&mut hasher);
+      hasher.digest(&HashDigest::Hex)
+    };
+    let minified_count = AtomicUsize::new(0);
+    let cached_count = AtomicUsize::new(0);
 
     compilation
       .assets_mut()
@@ -232,14 +244,55 @@ impl Plugin for SwcJsMinimizerRspackPlugin {
               banner
             }
           });
-          let output = match minify(
-            &js_minify_options,
-            input,
-            filename,
-            &all_extracted_comments,
-            &extract_comments_option,
-          ) {
-            Ok(r) => r,
+          // The minified code only depends on the input code, the filename and the options, the
+          // input source map is applied after minification
+          let cache_id = {
+            let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+            options_hash.encoded().hash(&mut hasher);
+            filename.hash(&mut hasher);
+            input.hash(&mut hasher);
+            is_module.hash(&mut hasher);
+            emit_source_map_columns.hash(&mut hasher);
+            input_source_map.is_some().hash(&mut hasher);
+            Identifier::from(hasher.digest(&HashDigest::Hex).encoded())
+          };
+          let output = match cache.minify_occasion.use_cache(cache_id, || {
+            minify(
+              &js_minify_options,
+              input,
+              filename,
+              &all_extracted_comments,
+              &extract_comments_option,
+            )
+            .map(|output| MinifyResult {
+              code: output.code,
+              map: output.map,
+              extracted_comments: all_extracted_comments
+                .lock()
+                .expect("all_extract_comments lock failed")
+                .get(filename.as_str())
+                .map(|info| (info.source.source().into_owned(), info.comments_file_name.clone())),
+            })
+          }) {
+            Ok((output, from_cache)) => {
+              minified_count.fetch_add(1, Ordering::Relaxed);
+              if from_cache {
+                cached_count.fetch_add(1, Ordering::Relaxed);
+              }
+              if from_cache && let Some((comments, comments_file_name)) = &output.extracted_comments {
+                all_extracted_comments
+                  .lock()
+                  .expect("all_extract_comments lock failed")
+                  .insert(
+                    filename.to_string(),
+                    ExtractedCommentsInfo {
+                      source: RawSource::from(comments.clone()).boxed(),
+                      comments_file_name: comments_file_name.clone(),
+                    },
+                  );
+              }
+              output
+            }
             Err(e) => {
               tx.send(e.into()).into_diagnostic()?;
               return Ok(())
@@ -281,6 +334,14 @@ impl Plugin for SwcJsMinimizerRspackPlugin {
 
     compilation.push_batch_diagnostic(rx.into_iter().flatten().collect::<Vec<_>>());
 
+    let minified_count = minified_count.into_inner();
+    if minified_count > 0 {
+      compilation.get_logger(self.name()).log(format!(
+        "{} of {minified_count} assets are restored from the minify cache",
+        cached_count.into_inner()
+      ));
+    }
+
     // write all extracted comments to assets
     all_extracted_comments
       .lock()
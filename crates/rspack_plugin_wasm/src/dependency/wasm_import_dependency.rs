@@ -3,6 +3,7 @@ use rspack_core::{
   DependencyType, ErrorSpan, ExtendedReferencedExport, ModuleDependency, ModuleGraph, RuntimeSpec,
 };
 use swc_core::ecma::atoms::JsWord;
+use wasmparser::FuncType;
 
 use crate::WasmNode;
 
@@ -11,24 +12,35 @@ pub struct WasmImportDependency {
   id: DependencyId,
   name: JsWord,
   request: String,
-  // only_direct_import: bool,
   /// the WASM AST node
   pub desc: WasmNode,
+  /// the signature of the imported function
+  pub signature: Option<FuncType>,
+  /// why the import can only be satisfied by another WebAssembly module
+  pub only_direct_import: Option<String>,
 
   span: Option<ErrorSpan>,
 }
 
 impl WasmImportDependency {
-  pub fn new(request: String, name: String, desc: WasmNode) -> Self {
+  pub fn new(
+    request: String,
+    name: String,
+    desc: WasmNode,
+    signature: Option<FuncType>,
+    only_direct_import: Option<String>,
+  ) -> Self {
     Self {
       id: DependencyId::new(),
       name: name.into(),
       request,
       desc,
-      // only_direct_import,
+      signature,
+      only_direct_import,
       span: None,
     }
   }
+
   pub fn name(&self) -> &str {
     &self.name
   }
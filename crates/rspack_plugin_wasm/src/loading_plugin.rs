@@ -1,15 +1,29 @@
 use rspack_core::{
-  BoxPlugin, Plugin, PluginContext, PluginExt, PluginRuntimeRequirementsInTreeOutput,
-  RuntimeGlobals, RuntimeModuleExt, RuntimeRequirementsInTreeArgs, WasmLoadingType,
+  BoxPlugin, ChunkUkey, Compilation, ModuleType, Plugin, PluginContext, PluginExt,
+  PluginRuntimeRequirementsInTreeOutput, RuntimeGlobals, RuntimeModuleExt,
+  RuntimeRequirementsInTreeArgs, SourceType, WasmLoading, WasmLoadingType,
 };
 
-use crate::AsyncWasmLoadingRuntimeModule;
+use crate::{AsyncWasmLoadingRuntimeModule, WasmChunkLoadingRuntimeModule};
 
-pub fn enable_wasm_loading_plugin(wasm_loading_type: WasmLoadingType) -> BoxPlugin {
+pub fn enable_wasm_loading_plugin(
+  wasm_loading_type: WasmLoadingType,
+  plugins: &mut Vec<BoxPlugin>,
+) {
   match wasm_loading_type {
-    WasmLoadingType::Fetch => FetchCompileAsyncWasmPlugin.boxed(),
-    WasmLoadingType::AsyncNode => ReadFileCompileAsyncWasmPlugin::new(false).boxed(),
-    WasmLoadingType::AsyncNodeModule => ReadFileCompileAsyncWasmPlugin::new(true).boxed(),
+    WasmLoadingType::Fetch => {
+      plugins.push(FetchCompileWasmPlugin.boxed());
+      plugins.push(FetchCompileAsyncWasmPlugin.boxed());
+    }
+    WasmLoadingType::AsyncNode => {
+      plugins.push(ReadFileCompileWasmPlugin.boxed());
+      plugins.push(ReadFileCompileAsyncWasmPlugin::new(false).boxed());
+    }
+    WasmLoadingType::AsyncNodeModule => {
+      plugins.push(ReadFileCompileAsyncWasmPlugin::new(true).boxed())
+    }
+    // enabled by the plugin which registers the custom type, e.g. `CustomWasmLoadingPlugin`
+    WasmLoadingType::Custom(_) => {}
   }
 }
 
@@ -92,3 +106,182 @@ impl Plugin for ReadFileCompileAsyncWasmPlugin {
     Ok(())
   }
 }
+
+/// Whether the async chunks of the chunk contain synchronous WebAssembly modules.
+fn has_sync_wasm_module_in_async_chunks(chunk: &ChunkUkey, compilation: &Compilation) -> bool {
+  compilation
+    .chunk_by_ukey
+    .expect_get(chunk)
+    .get_all_async_chunks(&compilation.chunk_group_by_ukey)
+    .iter()
+    .any(|chunk| {
+      compilation
+        .chunk_graph
+        .get_chunk_modules_iterable_by_source_type(
+          chunk,
+          SourceType::Wasm,
+          &compilation.module_graph,
+        )
+        .any(|module| *module.module_type() == ModuleType::WasmSync)
+    })
+}
+
+#[derive(Debug)]
+pub struct FetchCompileWasmPlugin;
+
+#[async_trait::async_trait]
+impl Plugin for FetchCompileWasmPlugin {
+  fn name(&self) -> &'static str {
+    "FetchCompileWasmPlugin"
+  }
+
+  fn runtime_requirements_in_tree(
+    &self,
+    _ctx: PluginContext,
+    args: &mut RuntimeRequirementsInTreeArgs,
+  ) -> PluginRuntimeRequirementsInTreeOutput {
+    if !args
+      .runtime_requirements
+      .contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
+      || !has_sync_wasm_module_in_async_chunks(args.chunk, args.compilation)
+    {
+      return Ok(());
+    }
+
+    let runtime_requirements_mut = &mut args.runtime_requirements_mut;
+    runtime_requirements_mut.insert(RuntimeGlobals::MODULE_CACHE);
+    runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
+    args.compilation.add_runtime_module(
+      args.chunk,
+      WasmChunkLoadingRuntimeModule::new(
+        format!("fetch({} + $PATH)", RuntimeGlobals::PUBLIC_PATH),
+        true,
+      )
+      .boxed(),
+    );
+
+    Ok(())
+  }
+}
+
+#[derive(Debug)]
+pub struct ReadFileCompileWasmPlugin;
+
+#[async_trait::async_trait]
+impl Plugin for ReadFileCompileWasmPlugin {
+  fn name(&self) -> &'static str {
+    "ReadFileCompileWasmPlugin"
+  }
+
+  fn runtime_requirements_in_tree(
+    &self,
+    _ctx: PluginContext,
+    args: &mut RuntimeRequirementsInTreeArgs,
+  ) -> PluginRuntimeRequirementsInTreeOutput {
+    if !args
+      .runtime_requirements
+      .contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
+      || !has_sync_wasm_module_in_async_chunks(args.chunk, args.compilation)
+    {
+      return Ok(());
+    }
+
+    args
+      .runtime_requirements_mut
+      .insert(RuntimeGlobals::MODULE_CACHE);
+    args.compilation.add_runtime_module(
+      args.chunk,
+      WasmChunkLoadingRuntimeModule::new(
+        include_str!("runtime/read_file_compile_async_wasm.js").to_string(),
+        false,
+      )
+      .boxed(),
+    );
+
+    Ok(())
+  }
+}
+
+/// Registers a wasm loading type which is not built in, the WebAssembly modules are loaded
+/// with `generate_load_binary_code` when `output.wasmLoading` is the registered type.
+#[derive(Debug)]
+pub struct CustomWasmLoadingPlugin {
+  wasm_loading: WasmLoading,
+  generate_load_binary_code: String,
+  supports_streaming: bool,
+  runtime_requirements: RuntimeGlobals,
+}
+
+impl CustomWasmLoadingPlugin {
+  /// `generate_load_binary_code` is an expression which loads the binary of `$PATH` as a
+  /// `Promise` of a `Response` like object, it's passed to `WebAssembly.instantiateStreaming`
+  /// when `supports_streaming`. `runtime_requirements` are the runtime globals it uses.
+  pub fn new(
+    wasm_loading_type: impl Into<String>,
+    generate_load_binary_code: String,
+    supports_streaming: bool,
+    runtime_requirements: RuntimeGlobals,
+  ) -> Self {
+    Self {
+      wasm_loading: WasmLoading::Enable(WasmLoadingType::Custom(wasm_loading_type.into())),
+      generate_load_binary_code,
+      supports_streaming,
+      runtime_requirements,
+    }
+  }
+}
+
+#[async_trait::async_trait]
+impl Plugin for CustomWasmLoadingPlugin {
+  fn name(&self) -> &'static str {
+    "CustomWasmLoadingPlugin"
+  }
+
+  fn runtime_requirements_in_tree(
+    &self,
+    _ctx: PluginContext,
+    args: &mut RuntimeRequirementsInTreeArgs,
+  ) -> PluginRuntimeRequirementsInTreeOutput {
+    if args.compilation.options.output.wasm_loading != self.wasm_loading {
+      return Ok(());
+    }
+
+    if args
+      .runtime_requirements
+      .contains(RuntimeGlobals::INSTANTIATE_WASM)
+    {
+      args
+        .runtime_requirements_mut
+        .insert(self.runtime_requirements);
+      args.compilation.add_runtime_module(
+        args.chunk,
+        AsyncWasmLoadingRuntimeModule::new(
+          self.generate_load_binary_code.clone(),
+          self.supports_streaming,
+          *args.chunk,
+        )
+        .boxed(),
+      );
+    }
+
+    if args
+      .runtime_requirements
+      .contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
+      && has_sync_wasm_module_in_async_chunks(args.chunk, args.compilation)
+    {
+      args
+        .runtime_requirements_mut
+        .insert(RuntimeGlobals::MODULE_CACHE | self.runtime_requirements);
+      args.compilation.add_runtime_module(
+        args.chunk,
+        WasmChunkLoadingRuntimeModule::new(
+          self.generate_load_binary_code.clone(),
+          self.supports_streaming,
+        )
+        .boxed(),
+      );
+    }
+
+    Ok(())
+  }
+}
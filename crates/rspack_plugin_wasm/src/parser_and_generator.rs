@@ -5,9 +5,10 @@ use indexmap::IndexMap;
 use rspack_core::rspack_sources::{BoxSource, RawSource, Source, SourceExt};
 use rspack_core::DependencyType::WasmImport;
 use rspack_core::{
-  AssetInfo, BoxDependency, BuildMetaExportsType, Compilation, Filename, GenerateContext, Module,
-  ModuleDependency, ModuleIdentifier, NormalModule, ParseContext, ParseResult, ParserAndGenerator,
-  PathData, RuntimeGlobals, SourceType, StaticExportsDependency, UsedName,
+  AssetInfo, BoxDependency, BuildMetaExportsType, CodeGenerationDataAssetInfo,
+  CodeGenerationDataFilename, Compilation, Filename, GenerateContext, Module, ModuleDependency,
+  ModuleIdentifier, NormalModule, ParseContext, ParseResult, ParserAndGenerator, PathData,
+  RuntimeGlobals, SourceType, StaticExportsDependency, UsedName,
 };
 use rspack_error::{Diagnostic, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
 use rspack_identifier::Identifier;
@@ -15,12 +16,9 @@ use swc_core::atoms::Atom;
 use wasmparser::{Import, Parser, Payload};
 
 use crate::dependency::WasmImportDependency;
-use crate::ModuleIdToFileName;
 
 #[derive(Debug)]
-pub struct AsyncWasmParserAndGenerator {
-  pub(crate) module_id_to_filename: ModuleIdToFileName,
-}
+pub struct AsyncWasmParserAndGenerator;
 
 pub(crate) static WASM_SOURCE_TYPE: &[SourceType; 2] = &[SourceType::Wasm, SourceType::JavaScript];
 
@@ -62,6 +60,8 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
                     module.into(),
                     name.into(),
                     ty,
+                    None,
+                    None,
                   )));
                 }
                 Err(err) => diagnostic.push(Diagnostic::error(
@@ -129,12 +129,18 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
     let normal_module = module
       .as_normal_module()
       .expect("module should be a NormalModule in AsyncWasmParserAndGenerator::generate");
-    let wasm_path_with_info =
+    let (wasm_filename, wasm_asset_info) =
       render_wasm_name(compilation, normal_module, wasm_filename_template, &hash);
 
-    self
-      .module_id_to_filename
-      .insert(module.identifier(), wasm_path_with_info.clone());
+    generate_context
+      .data
+      .insert(CodeGenerationDataFilename::new(
+        wasm_filename,
+        compilation.options.output.public_path.clone(),
+      ));
+    generate_context
+      .data
+      .insert(CodeGenerationDataAssetInfo::new(wasm_asset_info));
 
     match generate_context.requested_source_type {
       SourceType::JavaScript => {
@@ -281,7 +287,7 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
   }
 }
 
-fn render_wasm_name(
+pub(crate) fn render_wasm_name(
   compilation: &Compilation,
   normal_module: &NormalModule,
   wasm_filename_template: &Filename,
@@ -296,12 +302,12 @@ fn render_wasm_name(
   )
 }
 
-fn render_import_stmt(import_var: &str, module_id: &str) -> String {
+pub(crate) fn render_import_stmt(import_var: &str, module_id: &str) -> String {
   let module_id = serde_json::to_string(&module_id).expect("TODO");
   format!("var {import_var} = __webpack_require__({module_id});\n",)
 }
 
-fn hash_for_source(source: &BoxSource) -> String {
+pub(crate) fn hash_for_source(source: &BoxSource) -> String {
   let mut hasher = DefaultHasher::new();
   source.hash(&mut hasher);
   format!("{:016x}", hasher.finish())
@@ -1,10 +1,16 @@
+use std::collections::BTreeMap;
+
+use indexmap::IndexMap;
 use rspack_core::rspack_sources::{BoxSource, RawSource, SourceExt};
 use rspack_core::{
-  get_filename_without_hash_length, impl_runtime_module, ChunkUkey, Compilation, PathData,
-  RuntimeModule, RuntimeModuleStage,
+  get_filename_without_hash_length, impl_runtime_module, ChunkUkey, CodeGenerationDataFilename,
+  Compilation, Dependency, Module, ModuleDependency, ModuleIdentifier, ModuleType, PathData,
+  RuntimeGlobals, RuntimeModule, RuntimeModuleStage, RuntimeSpec, SourceType, UsedName,
 };
 use rspack_identifier::Identifier;
 
+use crate::dependency::WasmImportDependency;
+
 #[derive(Debug, Eq)]
 pub struct AsyncWasmLoadingRuntimeModule {
   generate_load_binary_code: String,
@@ -101,3 +107,306 @@ fn get_async_wasm_loading(req: &str, supports_streaming: bool) -> String {
     "#
   )
 }
+
+#[derive(Debug, Eq)]
+pub struct WasmChunkLoadingRuntimeModule {
+  generate_load_binary_code: String,
+  id: Identifier,
+  supports_streaming: bool,
+  chunk: Option<ChunkUkey>,
+}
+
+impl WasmChunkLoadingRuntimeModule {
+  pub fn new(generate_load_binary_code: String, supports_streaming: bool) -> Self {
+    Self {
+      generate_load_binary_code,
+      id: Identifier::from("webpack/runtime/wasm_chunk_loading"),
+      supports_streaming,
+      chunk: None,
+    }
+  }
+}
+
+impl RuntimeModule for WasmChunkLoadingRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, compilation: &Compilation) -> BoxSource {
+    let chunk_ukey = self
+      .chunk
+      .expect("should have chunk in <WasmChunkLoadingRuntimeModule as RuntimeModule>::generate");
+    let chunk = compilation.chunk_by_ukey.expect_get(&chunk_ukey);
+
+    let mut declarations = vec![];
+    let mut import_objects = vec![];
+    let mut wasm_module_map = BTreeMap::<&str, Vec<&str>>::new();
+    let mut wasm_module_filenames = BTreeMap::<&str, &str>::new();
+    for async_chunk_ukey in chunk.get_all_async_chunks(&compilation.chunk_group_by_ukey) {
+      let async_chunk = compilation.chunk_by_ukey.expect_get(&async_chunk_ukey);
+      let mut module_ids = vec![];
+      for module in compilation
+        .chunk_graph
+        .get_chunk_modules_iterable_by_source_type(
+          &async_chunk_ukey,
+          SourceType::Wasm,
+          &compilation.module_graph,
+        )
+        .filter(|module| *module.module_type() == ModuleType::WasmSync)
+      {
+        let module_id = get_module_id(compilation, &module.identifier());
+        if wasm_module_filenames.contains_key(module_id) {
+          module_ids.push(module_id);
+          continue;
+        }
+        let Some(filename) = compilation
+          .code_generation_results
+          .get(&module.identifier(), Some(&chunk.runtime))
+          .data
+          .get::<CodeGenerationDataFilename>()
+        else {
+          continue;
+        };
+        wasm_module_filenames.insert(module_id, filename.filename());
+        import_objects.push(generate_import_object(
+          compilation,
+          module,
+          &chunk.runtime,
+          &mut declarations,
+        ));
+        module_ids.push(module_id);
+      }
+      if !module_ids.is_empty()
+        && let Some(chunk_id) = &async_chunk.id
+      {
+        module_ids.sort_unstable();
+        wasm_module_map.insert(chunk_id, module_ids);
+      }
+    }
+
+    let req = self
+      .generate_load_binary_code
+      .replace("$PATH", "wasmModuleFilenames[wasmModuleId]");
+    let instantiate = if self.supports_streaming {
+      format!(
+        r#"if(importObject && typeof importObject.then === 'function' && typeof WebAssembly.compileStreaming === 'function') {{
+          promise = Promise.all([WebAssembly.compileStreaming(req), importObject]).then(function(items) {{
+            return WebAssembly.instantiate(items[0], items[1]);
+          }});
+        }} else if(typeof WebAssembly.instantiateStreaming === 'function') {{
+          promise = WebAssembly.instantiateStreaming(req, importObject);
+        }} else {{
+          {instantiate_bytes}
+        }}"#,
+        instantiate_bytes = INSTANTIATE_BYTES
+      )
+    } else {
+      format!(
+        r#"if(importObject && typeof importObject.then === 'function') {{
+          var bytesPromise = req.then(function(x) {{ return x.arrayBuffer(); }});
+          promise = Promise.all([
+            bytesPromise.then(function(bytes) {{ return WebAssembly.compile(bytes); }}),
+            importObject
+          ]).then(function(items) {{
+            return WebAssembly.instantiate(items[0], items[1]);
+          }});
+        }} else {{
+          {instantiate_bytes}
+        }}"#,
+        instantiate_bytes = INSTANTIATE_BYTES
+      )
+    };
+
+    RawSource::from(format!(
+      r#"
+// object to store loaded and loading wasm modules
+var installedWasmModules = {{}};
+
+// delays reading the installed wasm module promises by a microtask, as the modules
+// importing each other may be split into different chunks
+function promiseResolve() {{ return Promise.resolve(); }}
+
+{declarations}
+var wasmImportObjects = {{
+{import_objects}
+}};
+
+var wasmModuleMap = {wasm_module_map};
+var wasmModuleFilenames = {wasm_module_filenames};
+
+// object with all WebAssembly.instance exports
+{wasm_instances} = {{}};
+
+// Fetch + compile chunk loading for webassembly
+{ensure_chunk_handlers}.wasm = function(chunkId, promises) {{
+  var wasmModules = wasmModuleMap[chunkId] || [];
+  wasmModules.forEach(function(wasmModuleId, idx) {{
+    var installedWasmModuleData = installedWasmModules[wasmModuleId];
+    // a Promise means "currently loading" or "already loaded".
+    if(installedWasmModuleData)
+      promises.push(installedWasmModuleData);
+    else {{
+      var importObject = wasmImportObjects[wasmModuleId]();
+      var req = {req};
+      var promise;
+      {instantiate}
+      promises.push(installedWasmModules[wasmModuleId] = promise.then(function(res) {{
+        return {wasm_instances}[wasmModuleId] = (res.instance || res).exports;
+      }}));
+    }}
+  }});
+}};
+"#,
+      declarations = declarations.join("\n"),
+      import_objects = import_objects.join(",\n"),
+      wasm_module_map = serde_json::to_string(&wasm_module_map).expect("should be valid json"),
+      wasm_module_filenames =
+        serde_json::to_string(&wasm_module_filenames).expect("should be valid json"),
+      wasm_instances = RuntimeGlobals::WASM_INSTANCES,
+      ensure_chunk_handlers = RuntimeGlobals::ENSURE_CHUNK_HANDLERS,
+    ))
+    .boxed()
+  }
+
+  fn attach(&mut self, chunk: ChunkUkey) {
+    self.chunk = Some(chunk);
+  }
+}
+
+impl_runtime_module!(WasmChunkLoadingRuntimeModule);
+
+const INSTANTIATE_BYTES: &str = r#"var bytesPromise = req.then(function(x) { return x.arrayBuffer(); });
+          promise = bytesPromise.then(function(bytes) {
+            return WebAssembly.instantiate(bytes, importObject);
+          });"#;
+
+fn get_module_id<'a>(compilation: &'a Compilation, module: &ModuleIdentifier) -> &'a str {
+  compilation
+    .chunk_graph
+    .get_module_id(*module)
+    .as_deref()
+    .expect("should have module id")
+}
+
+/// Generates the factory of the import object of a synchronous WebAssembly module, the imported
+/// functions read the exports of the JavaScript modules lazily, as they are not executed yet
+/// when the WebAssembly module is instantiated.
+fn generate_import_object(
+  compilation: &Compilation,
+  module: &dyn Module,
+  runtime: &RuntimeSpec,
+  declarations: &mut Vec<String>,
+) -> String {
+  let module_graph = &compilation.module_graph;
+  let mut wait_for_instances = IndexMap::<String, &str>::new();
+  let mut properties_by_request = IndexMap::<&str, Vec<String>>::new();
+
+  for dep in module
+    .get_dependencies()
+    .iter()
+    .filter_map(|id| module_graph.dependency_by_id(id))
+    .filter_map(|dep| dep.downcast_ref::<WasmImportDependency>())
+  {
+    let Some(imported_module) = module_graph.get_module(dep.id()) else {
+      continue;
+    };
+    let used_name = match module_graph
+      .get_exports_info(&imported_module.identifier())
+      .id
+      .get_used_name(
+        module_graph,
+        Some(runtime),
+        UsedName::Str(dep.name().into()),
+      ) {
+      Some(UsedName::Str(used_name)) => used_name.to_string(),
+      _ => dep.name().to_string(),
+    };
+    let used_name = serde_json::to_string(&used_name).expect("should be valid json");
+    let imported_module_id = get_module_id(compilation, &imported_module.identifier());
+
+    let value = if dep.only_direct_import.is_some() {
+      let instance_var = format!("m{}", wait_for_instances.len());
+      let value = format!("{instance_var}[{used_name}]");
+      wait_for_instances.insert(instance_var, imported_module_id);
+      value
+    } else {
+      let params = dep
+        .signature
+        .as_ref()
+        .map(|signature| {
+          (0..signature.params().len())
+            .map(|index| format!("p{index}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+        })
+        .unwrap_or_default();
+      let module_cache = format!(
+        "{}[{}]",
+        RuntimeGlobals::MODULE_CACHE,
+        serde_json::to_string(imported_module_id).expect("should be valid json")
+      );
+      let cache = format!("wasmImportedFuncCache{}", declarations.len());
+      declarations.push(format!("var {cache};"));
+      let wasm_exports = if imported_module.module_type().is_wasm_like() {
+        format!("{module_cache} ? {module_cache}.exports[{used_name}] : ")
+      } else {
+        String::new()
+      };
+      format!(
+        r#"{wasm_exports}function({params}) {{
+      if({cache} === undefined) {cache} = {module_cache}.exports;
+      return {cache}[{used_name}]({params});
+    }}"#
+      )
+    };
+    properties_by_request
+      .entry(dep.request())
+      .or_default()
+      .push(format!(
+        "{}: {value}",
+        serde_json::to_string(dep.name()).expect("should be valid json")
+      ));
+  }
+
+  let import_object = format!(
+    "return {{\n{}\n}};",
+    properties_by_request
+      .into_iter()
+      .map(|(request, properties)| format!(
+        "  {}: {{\n    {}\n  }}",
+        serde_json::to_string(request).expect("should be valid json"),
+        properties.join(",\n    ")
+      ))
+      .collect::<Vec<_>>()
+      .join(",\n")
+  );
+  let module_id = serde_json::to_string(get_module_id(compilation, &module.identifier()))
+    .expect("should be valid json");
+  let instances = wait_for_instances
+    .values()
+    .map(|id| {
+      format!(
+        "installedWasmModules[{}]",
+        serde_json::to_string(id).expect("should be valid json")
+      )
+    })
+    .collect::<Vec<_>>();
+  match instances.len() {
+    0 => format!("{module_id}: function() {{\n{import_object}\n}}"),
+    1 => format!(
+      "{module_id}: function() {{\nreturn promiseResolve().then(function() {{ return {}; }}).then(function({}) {{\n{import_object}\n}});\n}}",
+      instances[0],
+      wait_for_instances.keys().next().expect("should have instance"),
+    ),
+    _ => format!(
+      "{module_id}: function() {{\nreturn promiseResolve().then(function() {{ return Promise.all([{}]); }}).then(function(array) {{\nvar {};\n{import_object}\n}});\n}}",
+      instances.join(", "),
+      wait_for_instances
+        .keys()
+        .enumerate()
+        .map(|(index, name)| format!("{name} = array[{index}]"))
+        .collect::<Vec<_>>()
+        .join(", "),
+    ),
+  }
+}
@@ -0,0 +1,414 @@
+use indexmap::IndexMap;
+use rspack_core::rspack_sources::{BoxSource, RawSource, Source, SourceExt};
+use rspack_core::DependencyType::WasmImport;
+use rspack_core::{
+  BoxDependency, BuildMetaExportsType, CodeGenerationDataAssetInfo, CodeGenerationDataFilename,
+  GenerateContext, Module, ModuleIdentifier, ParseContext, ParseResult, ParserAndGenerator,
+  RuntimeGlobals, SourceType, StaticExportsDependency, UsageState, UsedName,
+};
+use rspack_error::{Diagnostic, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use swc_core::atoms::Atom;
+use wasmparser::{FuncType, Import, Parser, Payload, Type, TypeRef, ValType};
+
+use crate::dependency::WasmImportDependency;
+use crate::parser_and_generator::{
+  hash_for_source, render_import_stmt, render_wasm_name, WASM_SOURCE_TYPE,
+};
+
+/// The id of the export section in the WebAssembly binary format.
+const EXPORT_SECTION_ID: u8 = 7;
+
+#[derive(Debug)]
+pub struct SyncWasmParserAndGenerator;
+
+impl ParserAndGenerator for SyncWasmParserAndGenerator {
+  fn source_types(&self) -> &[SourceType] {
+    WASM_SOURCE_TYPE
+  }
+
+  fn parse(&mut self, parse_context: ParseContext) -> Result<TWithDiagnosticArray<ParseResult>> {
+    parse_context.build_info.strict = true;
+    parse_context.build_meta.exports_type = BuildMetaExportsType::Namespace;
+
+    let source = parse_context.source;
+
+    let mut func_types: Vec<FuncType> = vec![];
+    let mut exports = Vec::with_capacity(1);
+    let mut dependencies: Vec<BoxDependency> = Vec::with_capacity(1);
+    let mut diagnostic = Vec::with_capacity(1);
+
+    for payload in Parser::new(0).parse_all(&source.buffer()) {
+      match payload {
+        Ok(payload) => match payload {
+          Payload::TypeSection(s) => {
+            for ty in s {
+              match ty {
+                Ok(Type::Func(func_type)) => func_types.push(func_type),
+                Err(err) => diagnostic.push(Diagnostic::error(
+                  "Wasm Type Parse Error".into(),
+                  err.to_string(),
+                )),
+              }
+            }
+          }
+          Payload::ExportSection(s) => {
+            for export in s {
+              match export {
+                Ok(export) => exports.push(export.name.to_string()),
+                Err(err) => diagnostic.push(Diagnostic::error(
+                  "Wasm Export Parse Error".into(),
+                  err.to_string(),
+                )),
+              };
+            }
+          }
+          Payload::ImportSection(s) => {
+            for import in s {
+              match import {
+                Ok(Import { module, name, ty }) => {
+                  let signature = match ty {
+                    TypeRef::Func(index) => func_types.get(index as usize).cloned(),
+                    _ => None,
+                  };
+                  let only_direct_import = get_only_direct_import(&ty, signature.as_ref());
+                  dependencies.push(Box::new(WasmImportDependency::new(
+                    module.into(),
+                    name.into(),
+                    ty,
+                    signature,
+                    only_direct_import,
+                  )));
+                }
+                Err(err) => diagnostic.push(Diagnostic::error(
+                  "Wasm Import Parse Error".into(),
+                  err.to_string(),
+                )),
+              }
+            }
+          }
+          _ => {}
+        },
+        Err(err) => {
+          diagnostic.push(Diagnostic::error(
+            "Wasm Parse Error".into(),
+            err.to_string(),
+          ));
+        }
+      }
+    }
+
+    // The export names are rewritten in the binary, so the exports can be mangled.
+    dependencies.push(Box::new(StaticExportsDependency::new(
+      exports.iter().cloned().map(Atom::from).collect::<Vec<_>>(),
+      true,
+    )));
+
+    Ok(
+      ParseResult {
+        dependencies,
+        blocks: vec![],
+        presentational_dependencies: vec![],
+        source,
+        analyze_result: Default::default(),
+      }
+      .with_diagnostic(diagnostic),
+    )
+  }
+
+  fn size(&self, module: &dyn Module, source_type: &SourceType) -> f64 {
+    match source_type {
+      SourceType::JavaScript => {
+        40.0
+          + module
+            .get_presentational_dependencies()
+            .map_or(0.0, |i| i.len() as f64 * 10.0)
+      }
+      SourceType::Wasm => module.original_source().map_or(0, |source| source.size()) as f64,
+      _ => 0.0,
+    }
+  }
+
+  #[allow(clippy::unwrap_in_result)]
+  fn generate(
+    &self,
+    source: &BoxSource,
+    module: &dyn Module,
+    generate_context: &mut GenerateContext,
+  ) -> Result<BoxSource> {
+    let compilation = generate_context.compilation;
+    let runtime = generate_context.runtime;
+    let wasm_filename_template = &compilation.options.output.webassembly_module_filename;
+    let module_graph = &compilation.module_graph;
+    let exports_info = module_graph.get_exports_info(&module.identifier());
+
+    // the emitted binary has its export names rewritten to the used names of this runtime, so
+    // the content hash of the filename has to be computed from the rewritten binary
+    let wasm_source = match rewrite_export_names(&source.buffer(), |name| {
+      match exports_info
+        .id
+        .get_used_name(module_graph, runtime, UsedName::Str(name.into()))
+      {
+        Some(UsedName::Str(used_name)) => Some(used_name.to_string()),
+        _ => None,
+      }
+    }) {
+      Some(binary) => RawSource::from(binary).boxed(),
+      None => source.clone(),
+    };
+    let hash = hash_for_source(&wasm_source);
+    let normal_module = module
+      .as_normal_module()
+      .expect("module should be a NormalModule in SyncWasmParserAndGenerator::generate");
+    let (wasm_filename, wasm_asset_info) =
+      render_wasm_name(compilation, normal_module, wasm_filename_template, &hash);
+
+    generate_context
+      .data
+      .insert(CodeGenerationDataFilename::new(
+        wasm_filename,
+        compilation.options.output.public_path.clone(),
+      ));
+    generate_context
+      .data
+      .insert(CodeGenerationDataAssetInfo::new(wasm_asset_info));
+
+    match generate_context.requested_source_type {
+      SourceType::JavaScript => {
+        let runtime_requirements = &mut generate_context.runtime_requirements;
+        runtime_requirements.insert(RuntimeGlobals::MODULE);
+        runtime_requirements.insert(RuntimeGlobals::MODULE_ID);
+        runtime_requirements.insert(RuntimeGlobals::WASM_INSTANCES);
+
+        let other_exports_used = module_graph
+          .get_export_info_by_id(&exports_info.other_exports_info)
+          .get_used(runtime)
+          != UsageState::Unused;
+        if other_exports_used {
+          runtime_requirements.insert(RuntimeGlobals::MAKE_NAMESPACE_OBJECT);
+          runtime_requirements.insert(RuntimeGlobals::EXPORTS);
+        }
+
+        let mut dep_modules = IndexMap::<ModuleIdentifier, String>::new();
+        for dep in module
+          .get_dependencies()
+          .iter()
+          .filter_map(|id| module_graph.dependency_by_id(id))
+          .filter(|dep| dep.dependency_type() == &WasmImport)
+        {
+          let Some(mgm) = module_graph.module_graph_module_by_dependency_id(dep.id()) else {
+            continue;
+          };
+          if !dep_modules.contains_key(&mgm.module_identifier) {
+            let import_var = format!("WEBPACK_IMPORTED_MODULE_{}", dep_modules.len());
+            let import_stmt = render_import_stmt(&import_var, mgm.id(&compilation.chunk_graph));
+            dep_modules.insert(mgm.module_identifier, import_stmt);
+          }
+        }
+        let imports_code = dep_modules.into_values().collect::<String>();
+
+        let module_argument = module.get_module_argument();
+        let exports_argument = module.get_exports_argument();
+        let make_namespace_object = if other_exports_used {
+          format!(
+            "{}({exports_argument});\n",
+            RuntimeGlobals::MAKE_NAMESPACE_OBJECT
+          )
+        } else {
+          String::new()
+        };
+        // when the exports are statically known, the rewritten names of the WebAssembly exports
+        // already match the used names
+        let export_code = if other_exports_used {
+          format!(
+            "for(var name in wasmExports) if(name) {exports_argument}[name] = wasmExports[name];"
+          )
+        } else {
+          format!("{module_argument}.exports = wasmExports;")
+        };
+
+        Ok(
+          RawSource::from(format!(
+            r#""use strict";
+// Instantiate WebAssembly module
+var wasmExports = {}[{module_argument}.id];
+{make_namespace_object}// export exports from WebAssembly module
+{export_code}
+// exec imports from WebAssembly module (for esm order)
+{imports_code}"#,
+            RuntimeGlobals::WASM_INSTANCES,
+          ))
+          .boxed(),
+        )
+      }
+      SourceType::Wasm => Ok(wasm_source),
+      _ => Ok(source.clone()),
+    }
+  }
+}
+
+fn get_only_direct_import(ty: &TypeRef, signature: Option<&FuncType>) -> Option<String> {
+  match ty {
+    TypeRef::Memory(_) => Some("Memory".to_string()),
+    TypeRef::Table(_) => Some("Table".to_string()),
+    TypeRef::Func(_) => signature
+      .and_then(get_js_incompatible_type)
+      .map(|ty| format!("Non-JS-compatible Func Signature ({ty})")),
+    TypeRef::Global(global) if global.content_type == ValType::I64 => {
+      Some("Non-JS-compatible Global Type (i64)".to_string())
+    }
+    _ => None,
+  }
+}
+
+fn get_js_incompatible_type(signature: &FuncType) -> Option<&'static str> {
+  signature
+    .params()
+    .iter()
+    .chain(signature.results())
+    .any(|ty| *ty == ValType::I64)
+    .then_some("i64")
+}
+
+/// Renames the exports of the binary to their used names and drops the unused exports,
+/// returns `None` when the binary can not be rewritten.
+fn rewrite_export_names(
+  binary: &[u8],
+  get_used_name: impl Fn(&str) -> Option<String>,
+) -> Option<Vec<u8>> {
+  // magic number and version
+  let mut pos = 8;
+  let mut output = binary.get(..pos)?.to_vec();
+  while pos < binary.len() {
+    let id = binary[pos];
+    pos += 1;
+    let size = read_var_u32(binary, &mut pos)? as usize;
+    let content = binary.get(pos..pos + size)?;
+    pos += size;
+
+    let content = if id == EXPORT_SECTION_ID {
+      rewrite_export_section(content, &get_used_name)?
+    } else {
+      content.to_vec()
+    };
+    output.push(id);
+    write_var_u32(content.len() as u32, &mut output);
+    output.extend(content);
+  }
+  Some(output)
+}
+
+fn rewrite_export_section(
+  content: &[u8],
+  get_used_name: &impl Fn(&str) -> Option<String>,
+) -> Option<Vec<u8>> {
+  let mut pos = 0;
+  let count = read_var_u32(content, &mut pos)?;
+  let mut exports = Vec::with_capacity(count as usize);
+  for _ in 0..count {
+    let name_len = read_var_u32(content, &mut pos)? as usize;
+    let name = std::str::from_utf8(content.get(pos..pos + name_len)?).ok()?;
+    pos += name_len;
+    // the kind is a single byte followed by the index
+    let desc_start = pos;
+    pos += 1;
+    read_var_u32(content, &mut pos)?;
+    let desc = content.get(desc_start..pos)?;
+    if let Some(used_name) = get_used_name(name) {
+      exports.push((used_name, desc));
+    }
+  }
+
+  let mut output = vec![];
+  write_var_u32(exports.len() as u32, &mut output);
+  for (name, desc) in exports {
+    write_var_u32(name.len() as u32, &mut output);
+    output.extend(name.as_bytes());
+    output.extend(desc);
+  }
+  Some(output)
+}
+
+fn read_var_u32(bytes: &[u8], pos: &mut usize) -> Option<u32> {
+  let mut result = 0;
+  let mut shift = 0;
+  loop {
+    let byte = *bytes.get(*pos)?;
+    *pos += 1;
+    result |= ((byte & 0x7f) as u32) << shift;
+    if byte & 0x80 == 0 {
+      return Some(result);
+    }
+    shift += 7;
+    if shift > 28 {
+      return None;
+    }
+  }
+}
+
+fn write_var_u32(mut value: u32, output: &mut Vec<u8>) {
+  loop {
+    let byte = (value & 0x7f) as u8;
+    value >>= 7;
+    if value == 0 {
+      output.push(byte);
+      return;
+    }
+    output.push(byte | 0x80);
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  // (module (func) (export "add" (func 0)) (export "sub" (func 0)))
+  const BINARY: &[u8] = &[
+    0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // header
+    0x01, 0x04, 0x01, 0x60, 0x00, 0x00, // type section
+    0x03, 0x02, 0x01, 0x00, // function section
+    0x07, 0x0d, 0x02, 0x03, b'a', b'd', b'd', 0x00, 0x00, 0x03, b's', b'u', b'b', 0x00,
+    0x00, // export section
+    0x0a, 0x04, 0x01, 0x02, 0x00, 0x0b, // code section
+  ];
+
+  fn export_names(binary: &[u8]) -> Vec<String> {
+    Parser::new(0)
+      .parse_all(binary)
+      .filter_map(|payload| match payload.expect("should be valid wasm") {
+        Payload::ExportSection(s) => Some(
+          s.into_iter()
+            .map(|export| export.expect("should be valid export").name.to_string())
+            .collect::<Vec<_>>(),
+        ),
+        _ => None,
+      })
+      .flatten()
+      .collect()
+  }
+
+  #[test]
+  fn rewrite_used_exports() {
+    let binary = rewrite_export_names(BINARY, |name| match name {
+      "add" => Some("a".to_string()),
+      _ => None,
+    })
+    .expect("should rewrite");
+    assert_eq!(export_names(&binary), vec!["a"]);
+  }
+
+  #[test]
+  fn keep_all_exports() {
+    let binary =
+      rewrite_export_names(BINARY, |name| Some(name.to_string())).expect("should rewrite");
+    assert_eq!(binary, BINARY);
+  }
+
+  #[test]
+  fn var_u32() {
+    for value in [0, 1, 127, 128, 624485, u32::MAX] {
+      let mut bytes = vec![];
+      write_var_u32(value, &mut bytes);
+      assert_eq!(read_var_u32(&bytes, &mut 0), Some(value));
+    }
+  }
+}
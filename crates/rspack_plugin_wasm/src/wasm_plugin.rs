@@ -2,26 +2,25 @@ use std::fmt::Debug;
 
 use rayon::prelude::*;
 use rspack_core::{
-  ApplyContext, CompilationArgs, CompilationParams, CompilerOptions, DependencyType, ModuleType,
-  ParserAndGenerator, Plugin, PluginCompilationHookOutput, PluginContext,
-  PluginRenderManifestHookOutput, RenderManifestArgs, RenderManifestEntry, SourceType,
+  ApplyContext, CodeGenerationDataAssetInfo, CodeGenerationDataFilename, Compilation,
+  CompilationArgs, CompilationParams, CompilerOptions, Dependency, DependencyType,
+  ModuleDependency, ModuleType, ParserAndGenerator, Plugin, PluginCompilationHookOutput,
+  PluginContext, PluginRenderManifestHookOutput, RenderManifestArgs, RenderManifestEntry,
+  SourceType,
 };
-use rspack_error::{IntoTWithDiagnosticArray, Result};
+use rspack_error::{Diagnostic, IntoTWithDiagnosticArray, Result};
 
-use crate::{AsyncWasmParserAndGenerator, ModuleIdToFileName};
+use crate::dependency::WasmImportDependency;
+use crate::{AsyncWasmParserAndGenerator, SyncWasmParserAndGenerator};
 
 pub struct EnableWasmLoadingPlugin;
 
 #[derive(Debug, Default)]
-pub struct AsyncWasmPlugin {
-  pub module_id_to_filename_without_ext: ModuleIdToFileName,
-}
+pub struct AsyncWasmPlugin;
 
 impl AsyncWasmPlugin {
   pub fn new() -> AsyncWasmPlugin {
-    Self {
-      module_id_to_filename_without_ext: Default::default(),
-    }
+    Self
   }
 }
 
@@ -52,15 +51,7 @@ impl Plugin for AsyncWasmPlugin {
     ctx: PluginContext<&mut ApplyContext>,
     _options: &mut CompilerOptions,
   ) -> Result<()> {
-    let module_id_to_filename_without_ext = self.module_id_to_filename_without_ext.clone();
-
-    let builder = move || {
-      Box::new({
-        AsyncWasmParserAndGenerator {
-          module_id_to_filename: module_id_to_filename_without_ext.clone(),
-        }
-      }) as Box<dyn ParserAndGenerator>
-    };
+    let builder = || Box::new(AsyncWasmParserAndGenerator) as Box<dyn ParserAndGenerator>;
 
     ctx
       .context
@@ -73,6 +64,93 @@ impl Plugin for AsyncWasmPlugin {
   //
   // }
 
+  async fn render_manifest(
+    &self,
+    _ctx: PluginContext,
+    args: RenderManifestArgs<'_>,
+  ) -> PluginRenderManifestHookOutput {
+    let files = render_wasm_manifest(&args, ModuleType::WasmAsync)?;
+    Ok(files.with_empty_diagnostic())
+  }
+}
+
+#[derive(Debug, Default)]
+pub struct SyncWasmPlugin;
+
+impl SyncWasmPlugin {
+  pub fn new() -> SyncWasmPlugin {
+    Self
+  }
+}
+
+#[async_trait::async_trait]
+impl Plugin for SyncWasmPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.WebAssemblyModulesPlugin"
+  }
+
+  async fn compilation(
+    &self,
+    args: CompilationArgs<'_>,
+    params: &CompilationParams,
+  ) -> PluginCompilationHookOutput {
+    args.compilation.set_dependency_factory(
+      DependencyType::WasmImport,
+      params.normal_module_factory.clone(),
+    );
+    Ok(())
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    let builder = || Box::new(SyncWasmParserAndGenerator) as Box<dyn ParserAndGenerator>;
+
+    ctx
+      .context
+      .register_parser_and_generator_builder(ModuleType::WasmSync, Box::new(builder));
+
+    Ok(())
+  }
+
+  async fn finish_modules(&self, compilation: &mut Compilation) -> Result<()> {
+    let module_graph = &compilation.module_graph;
+    let mut diagnostics = vec![];
+    for module in module_graph
+      .modules()
+      .values()
+      .filter(|module| *module.module_type() == ModuleType::WasmSync)
+    {
+      for dep in module
+        .get_dependencies()
+        .iter()
+        .filter_map(|id| module_graph.dependency_by_id(id))
+        .filter_map(|dep| dep.downcast_ref::<WasmImportDependency>())
+      {
+        let Some(only_direct_import) = &dep.only_direct_import else {
+          continue;
+        };
+        if let Some(imported_module) = module_graph.get_module(dep.id())
+          && !imported_module.module_type().is_wasm_like()
+        {
+          diagnostics.push(Diagnostic::error(
+            "UnsupportedWebAssemblyFeatureError".into(),
+            format!(
+              "Import \"{}\" from \"{}\" with {only_direct_import} can only be used for direct wasm to wasm dependencies\nIt's used from {}",
+              dep.name(),
+              dep.request(),
+              module.readable_identifier(&compilation.options.context),
+            ),
+          ));
+        }
+      }
+    }
+    compilation.push_batch_diagnostic(diagnostics);
+    Ok(())
+  }
+
   async fn render_manifest(
     &self,
     _ctx: PluginContext,
@@ -80,27 +158,74 @@ impl Plugin for AsyncWasmPlugin {
   ) -> PluginRenderManifestHookOutput {
     let compilation = args.compilation;
     let chunk = args.chunk();
-    let module_graph = &compilation.module_graph;
+    // the wasm modules are instantiated by the chunk loading before the chunk is evaluated,
+    // which only happens for the async chunks
+    let diagnostics = if chunk.can_be_initial(&compilation.chunk_group_by_ukey) {
+      compilation
+        .chunk_graph
+        .get_chunk_modules_iterable_by_source_type(
+          &args.chunk_ukey,
+          SourceType::Wasm,
+          &compilation.module_graph,
+        )
+        .filter(|module| *module.module_type() == ModuleType::WasmSync)
+        .map(|module| {
+          Diagnostic::error(
+            "WebAssemblyInInitialChunkError".into(),
+            format!(
+              "WebAssembly module {} is included in initial chunk.\nThis is not allowed, because WebAssembly download and compilation must happen asynchronous.\nAdd an async split point (i. e. import()) somewhere between your entrypoint and the WebAssembly module.",
+              module.readable_identifier(&compilation.options.context),
+            ),
+          )
+        })
+        .collect()
+    } else {
+      vec![]
+    };
+    let files = render_wasm_manifest(&args, ModuleType::WasmSync)?;
+    Ok(files.with_diagnostic(diagnostics))
+  }
+}
+
+fn render_wasm_manifest(
+  args: &RenderManifestArgs<'_>,
+  module_type: ModuleType,
+) -> Result<Vec<RenderManifestEntry>> {
+  let compilation = args.compilation;
+  let chunk = args.chunk();
+  let module_graph = &compilation.module_graph;
 
-    let ordered_modules = compilation
-      .chunk_graph
-      .get_chunk_modules(&args.chunk_ukey, module_graph);
+  let ordered_modules = compilation
+    .chunk_graph
+    .get_chunk_modules(&args.chunk_ukey, module_graph);
 
-    let files = ordered_modules
+  Ok(
+    ordered_modules
       .par_iter()
-      .filter(|m| *m.module_type() == ModuleType::WasmAsync)
+      .filter(|m| *m.module_type() == module_type)
       .map(|m| {
         let code_gen_result = compilation
           .code_generation_results
           .get(&m.identifier(), Some(&chunk.runtime));
 
         let result = code_gen_result.get(&SourceType::Wasm).map(|source| {
-          let (output_path, asset_info) = self
-            .module_id_to_filename_without_ext
-            .get(&m.identifier())
-            .map(|s| s.clone())
-            .expect("should have wasm_filename");
-          RenderManifestEntry::new(source.clone(), output_path, asset_info, false, false)
+          let wasm_filename = code_gen_result
+            .data
+            .get::<CodeGenerationDataFilename>()
+            .expect("should have wasm_filename")
+            .filename();
+          let asset_info = code_gen_result
+            .data
+            .get::<CodeGenerationDataAssetInfo>()
+            .expect("should have asset_info")
+            .inner();
+          RenderManifestEntry::new(
+            source.clone(),
+            wasm_filename.to_owned(),
+            asset_info.to_owned(),
+            false,
+            false,
+          )
         });
 
         Ok(result)
@@ -108,8 +233,6 @@ impl Plugin for AsyncWasmPlugin {
       .collect::<Result<Vec<Option<RenderManifestEntry>>>>()?
       .into_iter()
       .flatten()
-      .collect::<Vec<RenderManifestEntry>>();
-
-    Ok(files.with_empty_diagnostic())
-  }
+      .collect::<Vec<RenderManifestEntry>>(),
+  )
 }
@@ -1,6 +1,7 @@
 #![feature(let_chains)]
 
 use std::fmt::Debug;
+use std::ops::Range;
 
 use rspack_error::Error;
 use swc_core::ecma::ast::Regex as SwcRegex;
@@ -27,6 +28,11 @@ impl RspackRegex {
     self.algo.test(text)
   }
 
+  /// Byte range of the leftmost match in `text`.
+  pub fn find(&self, text: &str) -> Option<Range<usize>> {
+    self.algo.find(text)
+  }
+
   pub fn global(&self) -> bool {
     self.algo.global()
   }
@@ -43,7 +43,7 @@ async fn main() {
     .await
     .unwrap_or_else(|e| panic!("failed to compile in fixture {config:?}, {e:#?}"));
   let stats = compiler.compilation.get_stats();
-  let errors = stats.get_errors();
+  let errors = stats.get_errors(&compiler.compilation.options.stats);
   if !errors.is_empty() {
     eprintln!(
       "Failed to compile in fixture {:?}, errors: {:?}",
@@ -146,8 +146,8 @@ pub async fn test_fixture_share(
     assert_snapshot!(snapshot_name.as_str(), content);
   });
   let stats = compiler.compilation.get_stats();
-  let warnings = stats.get_warnings();
-  let errors = stats.get_errors();
+  let warnings = stats.get_warnings(&compiler.compilation.options.stats);
+  let errors = stats.get_errors(&compiler.compilation.options.stats);
   if !warnings.is_empty() && errors.is_empty() {
     println!(
       "Warning to compile in fixture {:?}, warnings: {:?}",
@@ -286,7 +286,7 @@ pub async fn test_rebuild_fixture(
     .build()
     .expect("TODO:");
 
-  let errors = stats.get_errors();
+  let errors = stats.get_errors(&compiler.compilation.options.stats);
   if !errors.is_empty() {
     panic!(
       "Failed to compile in fixture {:?}, errors: {:?}",
@@ -28,6 +28,7 @@ fn default_entry() -> HashMap<String, EntryItem> {
     EntryItem {
       import: vec!["./index".to_string()],
       runtime: None,
+      depend_on: None,
     },
   );
   map
@@ -99,6 +100,9 @@ pub struct TestConfig {
   pub devtool: String,
   #[serde(default)]
   pub experiments: Experiments,
+  /// Relative to the test directory
+  #[serde(default)]
+  pub records_input_path: Option<String>,
 }
 
 #[derive(Debug, Default, JsonSchema, Deserialize)]
@@ -107,6 +111,8 @@ pub struct Experiments {
   // True by default to reduce code in snapshots.
   #[serde(default = "true_by_default")]
   pub async_web_assembly: bool,
+  #[serde(default)]
+  pub sync_web_assembly: bool,
 }
 
 #[derive(Debug, JsonSchema, Deserialize)]
@@ -131,6 +137,8 @@ pub struct Optimization {
   pub mangle_exports: String,
   #[serde(default = "default_optimization_false_string_lit")]
   pub used_exports: String,
+  #[serde(default)]
+  pub concatenate_modules: bool,
 }
 
 #[derive(Debug, JsonSchema, Deserialize)]
@@ -139,6 +147,8 @@ pub struct EntryItem {
   pub import: Vec<String>,
   #[serde(default = "enable_runtime_by_default")]
   pub runtime: Option<String>,
+  #[serde(default)]
+  pub depend_on: Option<Vec<String>>,
 }
 
 #[derive(Debug, Default, JsonSchema, Deserialize)]
@@ -194,6 +204,8 @@ pub struct Builtins {
 pub struct Css {
   #[serde(default)]
   pub modules: ModulesConfig,
+  #[serde(default)]
+  pub targets: Vec<String>,
 }
 
 #[derive(Debug, JsonSchema, Deserialize)]
@@ -420,6 +432,8 @@ impl TestConfig {
         extensions: Some(vec![".js".to_string()]),
         ..Default::default()
       },
+      records_input_path: self.records_input_path.map(|p| context.join(p)),
+      records_output_path: None,
       builtins: c::Builtins {
         define: self.builtins.define,
         provide: self.builtins.provide,
@@ -447,6 +461,7 @@ impl TestConfig {
         inner_graph: self.optimization.inner_graph,
         used_exports: c::UsedExportsOption::from(self.optimization.used_exports.as_str()),
         mangle_exports: c::MangleExportsOption::from(self.optimization.mangle_exports.as_str()),
+        concatenate_modules: self.optimization.concatenate_modules,
       },
       profile: false,
     };
@@ -459,7 +474,9 @@ impl TestConfig {
             request.to_owned(),
             rspack_core::EntryOptions {
               name: Some(name.clone()),
-              runtime: Some("runtime".to_string()),
+              // entries with `dependOn` use the runtime of the entries they depend on
+              runtime: desc.depend_on.is_none().then(|| "runtime".to_string()),
+              depend_on: desc.depend_on.clone(),
               chunk_loading: None,
               async_chunks: Some(true),
               public_path: None,
@@ -489,6 +506,7 @@ impl TestConfig {
           ),
           exports_only: self.builtins.css.modules.exports_only,
         },
+        targets: self.builtins.css.targets,
       })
       .boxed(),
     );
@@ -535,10 +553,17 @@ impl TestConfig {
     plugins.push(rspack_plugin_warn_sensitive_module::WarnCaseSensitiveModulesPlugin.boxed());
 
     plugins.push(rspack_plugin_javascript::InferAsyncModulesPlugin {}.boxed());
+    if self.optimization.concatenate_modules {
+      plugins.push(rspack_plugin_javascript::ModuleConcatenationPlugin.boxed());
+    }
     if self.experiments.async_web_assembly {
       plugins.push(rspack_plugin_wasm::FetchCompileAsyncWasmPlugin {}.boxed());
       plugins.push(rspack_plugin_wasm::AsyncWasmPlugin::new().boxed());
     }
+    if self.experiments.sync_web_assembly {
+      plugins.push(rspack_plugin_wasm::FetchCompileWasmPlugin {}.boxed());
+      plugins.push(rspack_plugin_wasm::SyncWasmPlugin::new().boxed());
+    }
     plugins.push(rspack_plugin_externals::http_externals_rspack_plugin(
       true, false,
     ));
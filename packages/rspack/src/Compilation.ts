@@ -16,6 +16,7 @@ import type {
 	JsChunk,
 	JsCompatSource,
 	JsCompilation,
+	JsImportModuleResult,
 	JsModule,
 	JsStatsChunk,
 	JsStatsError,
@@ -304,6 +305,7 @@ export class Compilation {
 			options.chunkRelations,
 			!context.forToString
 		);
+		options.chunkOrigins = optionOrLocalFallback(options.chunkOrigins, false);
 		options.modules = optionOrLocalFallback(options.modules, true);
 		options.runtimeModules = optionOrLocalFallback(
 			options.runtimeModules,
@@ -318,6 +320,10 @@ export class Compilation {
 			options.chunkGroups,
 			!context.forToString
 		);
+		options.shared = optionOrLocalFallback(
+			options.shared,
+			!context.forToString
+		);
 		options.errors = optionOrLocalFallback(options.errors, true);
 		options.errorsCount = optionOrLocalFallback(options.errorsCount, true);
 		options.warnings = optionOrLocalFallback(options.warnings, true);
@@ -339,6 +345,10 @@ export class Compilation {
 			options.nestedModules,
 			!context.forToString
 		);
+		options.optimizationBailout = optionOrLocalFallback(
+			options.optimizationBailout,
+			!context.forToString
+		);
 		options.source = optionOrLocalFallback(options.source, false);
 		options.logging = optionOrLocalFallback(
 			options.logging,
@@ -354,6 +364,13 @@ export class Compilation {
 		options.modulesSpace =
 			options.modulesSpace || (context.forToString ? 15 : Infinity);
 		options.ids = optionOrLocalFallback(options.ids, !context.forToString);
+		options.usedExports = optionOrLocalFallback(options.usedExports, false);
+		options.providedExports = optionOrLocalFallback(
+			options.providedExports,
+			false
+		);
+		options.depth = optionOrLocalFallback(options.depth, false);
+		options.moduleTrace = optionOrLocalFallback(options.moduleTrace, false);
 		options.children = optionOrLocalFallback(
 			options.children,
 			!context.forToString
@@ -855,6 +872,46 @@ export class Compilation {
 		this._rebuildModuleCaller.push([m.moduleIdentifier, f]);
 	}
 
+	/**
+	 * Exports of the modules executed at build time, keyed by the id returned from the executor.
+	 *
+	 * @internal
+	 */
+	__internal__moduleExecutionResults = new Map<string, any>();
+
+	/**
+	 * Build the module of `request` with its dependencies and execute it.
+	 * `importer` is the module whose loader imports the request.
+	 *
+	 * Note: This is not a webpack public API, use `loaderContext.importModule` instead.
+	 *
+	 * @internal
+	 */
+	__internal__importModule(
+		request: string,
+		context: string,
+		importer: string,
+		callback: (
+			err: Error | null,
+			exports?: any,
+			result?: JsImportModuleResult
+		) => void
+	) {
+		this.#inner.importModule(request, context, importer, (err, result) => {
+			if (err) {
+				return callback(err);
+			}
+			if (result.id === undefined) {
+				return callback(
+					new Error(`Failed to import module ${request}, no executor available`)
+				);
+			}
+			const exports = this.__internal__moduleExecutionResults.get(result.id);
+			this.__internal__moduleExecutionResults.delete(result.id);
+			callback(null, exports, result);
+		});
+	}
+
 	/**
 	 * Get the `Source` of a given asset filename.
 	 *
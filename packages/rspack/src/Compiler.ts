@@ -10,6 +10,7 @@
 import type * as binding from "@rspack/binding";
 import { rspack } from "./index";
 import fs from "fs";
+import vm from "vm";
 import * as tapable from "tapable";
 import { Callback, SyncBailHook, SyncHook } from "tapable";
 import type { WatchOptions } from "watchpack";
@@ -119,6 +120,7 @@ class Compiler {
 	};
 	options: RspackOptionsNormalized;
 	#disabledHooks: string[];
+	#moduleExecutionId = 0;
 	parentCompilation?: Compilation;
 
 	constructor(context: string, options: RspackOptionsNormalized) {
@@ -900,11 +902,35 @@ class Compiler {
 				handler(execOptions)
 			);
 
-			const result = codegenResults.map[id]["build time"];
+			const result = codegenResults.map[id]?.["build time"];
+			if (result === undefined) {
+				throw new Error(`Module ${id} has not been code generated for build time`);
+			}
 			const moduleObject = execOptions.module;
 
 			if (id) moduleCache[id] = moduleObject;
 
+			const source = result.sources["javascript"];
+			if (source !== undefined) {
+				const fn = vm.runInThisContext(
+					`(function(module, exports, ${RuntimeGlobals.require}, __webpack_module__, ${RuntimeGlobals.exports}) {\n${source}\n/**/})`,
+					{ filename: id, lineOffset: -1 }
+				);
+				try {
+					fn.call(
+						moduleObject.exports,
+						moduleObject,
+						moduleObject.exports,
+						__webpack_require__,
+						moduleObject,
+						moduleObject.exports
+					);
+				} catch (e: any) {
+					moduleObject.error = e;
+					throw e;
+				}
+			}
+
 			tryRunOrWebpackError(
 				() =>
 					this.compilation.hooks.executeModule.call(
@@ -931,9 +957,13 @@ class Compiler {
 			__webpack_require__(runtimeModule);
 		}
 
-		exports = __webpack_require__(entry);
-
-		return JSON.stringify(exports);
+		const exports = __webpack_require__(entry);
+		const executionId = String(this.#moduleExecutionId++);
+		this.compilation.__internal__moduleExecutionResults.set(
+			executionId,
+			exports
+		);
+		return executionId;
 	}
 
 	#compilation(native: binding.JsCompilation) {
@@ -131,8 +131,8 @@ function presetToOptions(name?: boolean | string): StatsOptions {
 				all: false,
 				errors: true,
 				errorsCount: true,
-				logging: "error"
-				// TODO: moduleTrace: true,
+				logging: "error",
+				moduleTrace: true
 			};
 		case "errors-warnings":
 			return {
@@ -141,8 +141,45 @@ function presetToOptions(name?: boolean | string): StatsOptions {
 				errorsCount: true,
 				warnings: true,
 				warningsCount: true,
+				logging: "warn",
+				moduleTrace: true
+			};
+		case "minimal":
+			return {
+				all: false,
+				version: true,
+				timings: true,
+				modules: true,
+				modulesSpace: 0,
+				assets: true,
+				errors: true,
+				errorsCount: true,
+				warnings: true,
+				warningsCount: true,
 				logging: "warn"
 			};
+		case "detailed":
+			return {
+				entrypoints: true,
+				chunkGroups: true,
+				ids: true,
+				children: true,
+				chunks: true,
+				chunkModules: false,
+				chunkOrigins: true,
+				chunkRelations: true,
+				depth: true,
+				usedExports: true,
+				providedExports: true,
+				optimizationBailout: true,
+				errors: true,
+				errorsCount: true,
+				warnings: true,
+				warningsCount: true,
+				moduleTrace: true,
+				logging: true,
+				modulesSpace: 1000
+			};
 		default:
 			return {};
 	}
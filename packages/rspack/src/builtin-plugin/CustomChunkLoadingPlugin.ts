@@ -0,0 +1,46 @@
+import {
+	BuiltinPlugin,
+	RawCustomChunkLoadingPluginOptions
+} from "@rspack/binding";
+import {
+	BuiltinPluginName,
+	RspackBuiltinPlugin,
+	createBuiltinPlugin
+} from "./base";
+import { Compiler } from "../Compiler";
+import { EnableChunkLoadingPlugin } from "./EnableChunkLoadingPlugin";
+
+export type CustomChunkLoadingPluginOptions = {
+	/**
+	 * The chunk loading type to register, which can be used by `output.chunkLoading`
+	 * and `entry.chunkLoading` afterwards.
+	 */
+	type: string;
+	/**
+	 * The runtime globals used by `source`, e.g. `__webpack_require__.p`.
+	 */
+	runtimeRequirements?: string[];
+	/**
+	 * The source of the runtime module which loads the async chunks,
+	 * it should add a handler to `__webpack_require__.f`.
+	 */
+	source: string;
+};
+
+export class CustomChunkLoadingPlugin extends RspackBuiltinPlugin {
+	name = BuiltinPluginName.CustomChunkLoadingPlugin;
+
+	constructor(private options: CustomChunkLoadingPluginOptions) {
+		super();
+	}
+
+	raw(compiler: Compiler): BuiltinPlugin {
+		EnableChunkLoadingPlugin.setEnabled(compiler, this.options.type);
+		const options: RawCustomChunkLoadingPluginOptions = {
+			type: this.options.type,
+			runtimeRequirements: this.options.runtimeRequirements ?? [],
+			source: this.options.source
+		};
+		return createBuiltinPlugin(this.name, options);
+	}
+}
@@ -0,0 +1,50 @@
+import {
+	BuiltinPlugin,
+	RawCustomWasmLoadingPluginOptions
+} from "@rspack/binding";
+import {
+	BuiltinPluginName,
+	RspackBuiltinPlugin,
+	createBuiltinPlugin
+} from "./base";
+import { Compiler } from "../Compiler";
+import { EnableWasmLoadingPlugin } from "./EnableWasmLoadingPlugin";
+
+export type CustomWasmLoadingPluginOptions = {
+	/**
+	 * The wasm loading type to register, which can be used by `output.wasmLoading` afterwards.
+	 */
+	type: string;
+	/**
+	 * The runtime globals used by `generateLoadBinaryCode`, e.g. `__webpack_require__.p`.
+	 */
+	runtimeRequirements?: string[];
+	/**
+	 * The expression which loads the binary of the WebAssembly module at `$PATH`,
+	 * it evaluates to a `Promise` of a `Response` like object with `arrayBuffer()`.
+	 */
+	generateLoadBinaryCode: string;
+	/**
+	 * Whether the `Response` can be passed to `WebAssembly.instantiateStreaming`.
+	 */
+	supportsStreaming?: boolean;
+};
+
+export class CustomWasmLoadingPlugin extends RspackBuiltinPlugin {
+	name = BuiltinPluginName.CustomWasmLoadingPlugin;
+
+	constructor(private options: CustomWasmLoadingPluginOptions) {
+		super();
+	}
+
+	raw(compiler: Compiler): BuiltinPlugin {
+		EnableWasmLoadingPlugin.setEnabled(compiler, this.options.type);
+		const options: RawCustomWasmLoadingPluginOptions = {
+			type: this.options.type,
+			runtimeRequirements: this.options.runtimeRequirements ?? [],
+			generateLoadBinaryCode: this.options.generateLoadBinaryCode,
+			supportsStreaming: this.options.supportsStreaming ?? false
+		};
+		return createBuiltinPlugin(this.name, options);
+	}
+}
@@ -1,6 +1,78 @@
-import { BuiltinPluginName, create } from "./base";
+/**
+ * The following code is modified based on
+ * https://github.com/webpack/webpack/blob/4b4ca3b/lib/javascript/EnableChunkLoadingPlugin.js
+ *
+ * MIT Licensed
+ * Author Tobias Koppers @sokra
+ * Copyright (c) JS Foundation and other contributors
+ * https://github.com/webpack/webpack/blob/main/LICENSE
+ */
 
-export const EnableChunkLoadingPlugin = create(
-	BuiltinPluginName.EnableChunkLoadingPlugin,
-	type => type
-);
+import { BuiltinPlugin } from "@rspack/binding";
+import {
+	BuiltinPluginName,
+	RspackBuiltinPlugin,
+	createBuiltinPlugin
+} from "./base";
+import { ChunkLoadingType } from "../config";
+import { Compiler } from "../Compiler";
+
+const BUILTIN_TYPES: ChunkLoadingType[] = [
+	"jsonp",
+	"import-scripts",
+	"require",
+	"async-node",
+	"import"
+];
+
+const enabledTypes = new WeakMap<Compiler, Set<ChunkLoadingType>>();
+
+const getEnabledTypes = (compiler: Compiler) => {
+	let set = enabledTypes.get(compiler);
+	if (set === undefined) {
+		set = new Set();
+		enabledTypes.set(compiler, set);
+	}
+	return set;
+};
+
+export class EnableChunkLoadingPlugin extends RspackBuiltinPlugin {
+	name = BuiltinPluginName.EnableChunkLoadingPlugin;
+
+	constructor(private type: ChunkLoadingType) {
+		super();
+	}
+
+	static setEnabled(compiler: Compiler, type: ChunkLoadingType) {
+		getEnabledTypes(compiler).add(type);
+	}
+
+	static checkEnabled(compiler: Compiler, type: ChunkLoadingType) {
+		if (!getEnabledTypes(compiler).has(type)) {
+			throw new Error(
+				`Chunk loading type "${type}" is not enabled. ` +
+					"EnableChunkLoadingPlugin need to be used to enable this type of chunk loading. " +
+					'This usually happens through the "output.enabledChunkLoadingTypes" option. ' +
+					'If you are using a function as entry which sets "chunkLoading", you need to add all potential chunk loading types to "output.enabledChunkLoadingTypes". ' +
+					"These types are enabled: " +
+					Array.from(getEnabledTypes(compiler)).join(", ")
+			);
+		}
+	}
+
+	raw(compiler: Compiler): BuiltinPlugin | null {
+		const enabled = getEnabledTypes(compiler);
+		if (enabled.has(this.type)) return null;
+		// TODO: universal chunk loading is not supported yet
+		if (this.type === "universal") return null;
+		if (!BUILTIN_TYPES.includes(this.type)) {
+			throw new Error(
+				`Unsupported chunk loading type ${this.type}.\n` +
+					"Plugins which provide custom chunk loading types must call EnableChunkLoadingPlugin.setEnabled(compiler, type) to disable this error, " +
+					"CustomChunkLoadingPlugin does it when registering the type."
+			);
+		}
+		enabled.add(this.type);
+		return createBuiltinPlugin(this.name, this.type);
+	}
+}
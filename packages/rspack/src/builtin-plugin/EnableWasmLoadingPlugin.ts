@@ -1,6 +1,76 @@
-import { BuiltinPluginName, create } from "./base";
+/**
+ * The following code is modified based on
+ * https://github.com/webpack/webpack/blob/4b4ca3b/lib/wasm/EnableWasmLoadingPlugin.js
+ *
+ * MIT Licensed
+ * Author Tobias Koppers @sokra
+ * Copyright (c) JS Foundation and other contributors
+ * https://github.com/webpack/webpack/blob/main/LICENSE
+ */
 
-export const EnableWasmLoadingPlugin = create(
-	BuiltinPluginName.EnableWasmLoadingPlugin,
-	type => type
-);
+import { BuiltinPlugin } from "@rspack/binding";
+import {
+	BuiltinPluginName,
+	RspackBuiltinPlugin,
+	createBuiltinPlugin
+} from "./base";
+import { WasmLoadingType } from "../config";
+import { Compiler } from "../Compiler";
+
+const BUILTIN_TYPES: WasmLoadingType[] = [
+	"fetch",
+	"async-node",
+	"async-node-module"
+];
+
+const enabledTypes = new WeakMap<Compiler, Set<WasmLoadingType>>();
+
+const getEnabledTypes = (compiler: Compiler) => {
+	let set = enabledTypes.get(compiler);
+	if (set === undefined) {
+		set = new Set();
+		enabledTypes.set(compiler, set);
+	}
+	return set;
+};
+
+export class EnableWasmLoadingPlugin extends RspackBuiltinPlugin {
+	name = BuiltinPluginName.EnableWasmLoadingPlugin;
+
+	constructor(private type: WasmLoadingType) {
+		super();
+	}
+
+	static setEnabled(compiler: Compiler, type: WasmLoadingType) {
+		getEnabledTypes(compiler).add(type);
+	}
+
+	static checkEnabled(compiler: Compiler, type: WasmLoadingType) {
+		if (!getEnabledTypes(compiler).has(type)) {
+			throw new Error(
+				`WebAssembly loading type "${type}" is not enabled. ` +
+					"EnableWasmLoadingPlugin need to be used to enable this type of wasm loading. " +
+					'This usually happens through the "output.enabledWasmLoadingTypes" option. ' +
+					'If you are using a function as entry which sets "wasmLoading", you need to add all potential wasm loading types to "output.enabledWasmLoadingTypes". ' +
+					"These types are enabled: " +
+					Array.from(getEnabledTypes(compiler)).join(", ")
+			);
+		}
+	}
+
+	raw(compiler: Compiler): BuiltinPlugin | null {
+		const enabled = getEnabledTypes(compiler);
+		if (enabled.has(this.type)) return null;
+		// TODO: universal wasm loading is not supported yet
+		if (this.type === "universal") return null;
+		if (!BUILTIN_TYPES.includes(this.type)) {
+			throw new Error(
+				`Unsupported wasm loading type ${this.type}.\n` +
+					"Plugins which provide custom wasm loading types must call EnableWasmLoadingPlugin.setEnabled(compiler, type) to disable this error, " +
+					"CustomWasmLoadingPlugin does it when registering the type."
+			);
+		}
+		enabled.add(this.type);
+		return createBuiltinPlugin(this.name, this.type);
+	}
+}
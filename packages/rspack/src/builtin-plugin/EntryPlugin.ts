@@ -15,6 +15,7 @@ import { isNil } from "../util";
 export type EntryOptions = {
 	name?: string;
 	runtime?: EntryRuntime;
+	dependOn?: string[];
 	chunkLoading?: ChunkLoading;
 	asyncChunks?: boolean;
 	publicPath?: PublicPath;
@@ -47,6 +48,7 @@ function getRawEntryOptions(entry: EntryOptions): RawEntryOptions {
 		publicPath: entry.publicPath,
 		baseUri: entry.baseUri,
 		runtime: !isNil(runtime) ? getRawEntryRuntime(runtime) : undefined,
+		dependOn: entry.dependOn,
 		chunkLoading: !isNil(chunkLoading)
 			? getRawChunkLoading(chunkLoading)
 			: undefined,
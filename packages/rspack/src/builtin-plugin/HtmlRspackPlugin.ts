@@ -3,11 +3,18 @@ import { RawHtmlRspackPluginOptions } from "@rspack/binding";
 import { BuiltinPluginName, create } from "./base";
 import { validate } from "../util/validate";
 
+const htmlResourceHintsOptions = z.boolean().or(
+	z.strictObject({
+		include: z.string().array().optional(),
+		exclude: z.string().array().optional()
+	})
+);
+
 const htmlRspackPluginOptions = z.strictObject({
 	filename: z.string().optional(),
 	template: z.string().optional(),
 	templateContent: z.string().optional(),
-	templateParameters: z.record(z.string()).optional(),
+	templateParameters: z.record(z.any()).optional(),
 	inject: z.enum(["head", "body"]).or(z.boolean()).optional(),
 	publicPath: z.string().optional(),
 	scriptLoading: z.enum(["blocking", "defer", "module"]).optional(),
@@ -17,7 +24,9 @@ const htmlRspackPluginOptions = z.strictObject({
 	minify: z.boolean().optional(),
 	title: z.string().optional(),
 	favicon: z.string().optional(),
-	meta: z.record(z.string().or(z.record(z.string()))).optional()
+	meta: z.record(z.string().or(z.record(z.string()))).optional(),
+	preload: htmlResourceHintsOptions.optional(),
+	prefetch: htmlResourceHintsOptions.optional()
 });
 export type HtmlRspackPluginOptions = z.infer<typeof htmlRspackPluginOptions>;
 export const HtmlRspackPlugin = create(
@@ -49,11 +58,17 @@ export const HtmlRspackPlugin = create(
 				: configInject === false
 				? "false"
 				: configInject;
+		const resourceHints = (
+			options: HtmlRspackPluginOptions["preload"]
+		): RawHtmlRspackPluginOptions["preload"] =>
+			options === true ? {} : options === false ? undefined : options;
 		return {
 			...c,
 			meta,
 			scriptLoading,
-			inject
+			inject,
+			preload: resourceHints(c.preload),
+			prefetch: resourceHints(c.prefetch)
 		};
 	}
 );
@@ -49,13 +49,17 @@ export enum BuiltinPluginName {
 	InferAsyncModulesPlugin = "InferAsyncModulesPlugin",
 	JavascriptModulesPlugin = "JavascriptModulesPlugin",
 	AsyncWebAssemblyModulesPlugin = "AsyncWebAssemblyModulesPlugin",
+	WebAssemblyModulesPlugin = "WebAssemblyModulesPlugin",
 	AssetModulesPlugin = "AssetModulesPlugin",
 	SourceMapDevToolPlugin = "SourceMapDevToolPlugin",
 	EvalSourceMapDevToolPlugin = "EvalSourceMapDevToolPlugin",
 	SideEffectsFlagPlugin = "SideEffectsFlagPlugin",
 	FlagDependencyExportsPlugin = "FlagDependencyExportsPlugin",
 	FlagDependencyUsagePlugin = "FlagDependencyUsagePlugin",
-	MangleExportsPlugin = "MangleExportsPlugin"
+	MangleExportsPlugin = "MangleExportsPlugin",
+	ModuleConcatenationPlugin = "ModuleConcatenationPlugin",
+	CustomChunkLoadingPlugin = "CustomChunkLoadingPlugin",
+	CustomWasmLoadingPlugin = "CustomWasmLoadingPlugin"
 }
 
 type AffectedHooks = keyof Compiler["hooks"];
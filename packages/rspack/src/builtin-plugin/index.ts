@@ -12,6 +12,8 @@ export * from "./HttpExternalsRspackPlugin";
 export * from "./EnableChunkLoadingPlugin";
 export * from "./EnableLibraryPlugin";
 export * from "./EnableWasmLoadingPlugin";
+export * from "./CustomChunkLoadingPlugin";
+export * from "./CustomWasmLoadingPlugin";
 export * from "./ChunkPrefetchPreloadPlugin";
 export * from "./ArrayPushCallbackChunkFormatPlugin";
 export * from "./CommonJsChunkFormatPlugin";
@@ -37,6 +39,7 @@ export * from "./JsonModulesPlugin";
 export * from "./InferAsyncModulesPlugin";
 export * from "./JavascriptModulesPlugin";
 export * from "./AsyncWebAssemblyModulesPlugin";
+export * from "./WebAssemblyModulesPlugin";
 export * from "./AssetModulesPlugin";
 export * from "./SourceMapDevToolPlugin";
 export * from "./EvalSourceMapDevToolPlugin";
@@ -44,6 +47,7 @@ export * from "./SideEffectsFlagPlugin";
 export * from "./FlagDependencyExportsPlugin";
 export * from "./FlagDependencyUsagePlugin";
 export * from "./MangleExportsPlugin";
+export * from "./ModuleConcatenationPlugin";
 
 export * from "./HtmlRspackPlugin";
 export * from "./CopyRspackPlugin";
@@ -53,9 +57,15 @@ export * from "./SwcCssMinimizerPlugin";
 ///// DEPRECATED /////
 import { RawBuiltins, RawCssModulesConfig } from "@rspack/binding";
 import { Compiler, RspackOptionsNormalized } from "..";
+import { getBrowserslistTargets } from "../config/target";
 
 type BuiltinsCssConfig = {
 	modules?: Partial<RawCssModulesConfig>;
+	/**
+	 * Browserslist queries used to add vendor prefixes and lower newer syntax,
+	 * defaults to the browsers of the `browserslist` target.
+	 */
+	targets?: string[];
 };
 
 function resolveTreeShaking(
@@ -94,7 +104,10 @@ export function deprecated_resolveBuiltins(
 							: "[path][name][ext]__[local]",
 						exportsOnly: false,
 						...builtins.css?.modules
-					}
+					},
+					targets:
+						builtins.css?.targets ??
+						getBrowserslistTargets(options.target, contextPath)
 			  }
 			: undefined,
 		treeShaking: resolveTreeShaking(builtins.treeShaking, production)
@@ -112,6 +112,8 @@ export const getRawOptions = (
 		profile: options.profile!,
 		// SAFETY: applied default value in `applyRspackOptionsDefaults`.
 		bail: options.bail!,
+		recordsInputPath: options.recordsInputPath || undefined,
+		recordsOutputPath: options.recordsOutputPath || undefined,
 		// TODO: remove this
 		builtins: options.builtins as any
 	};
@@ -695,7 +697,8 @@ function getRawOptimization(
 		usedExports: String(optimization.usedExports),
 		providedExports: optimization.providedExports,
 		innerGraph: optimization.innerGraph,
-		mangleExports: String(optimization.mangleExports)
+		mangleExports: String(optimization.mangleExports),
+		concatenateModules: optimization.concatenateModules
 	};
 }
 
@@ -58,6 +58,12 @@ export interface LoaderObject {
 	normalExecuted: boolean;
 }
 
+export interface ImportModuleOptions {
+	layer?: string;
+	publicPath?: string;
+	baseUri?: string;
+}
+
 export interface LoaderContext<OptionsType = {}> {
 	version: 2;
 	resource: string;
@@ -123,6 +129,12 @@ export interface LoaderContext<OptionsType = {}> {
 		options: Resolve
 	): (context: any, request: any, callback: any) => Promise<any>;
 	getLogger(name: string): Logger;
+	importModule(
+		request: string,
+		options: ImportModuleOptions | undefined,
+		callback: (err?: null | Error, exports?: any) => any
+	): void;
+	importModule(request: string, options?: ImportModuleOptions): Promise<any>;
 	emitError(error: Error): void;
 	emitWarning(warning: Error): void;
 	emitFile(
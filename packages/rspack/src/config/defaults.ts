@@ -88,7 +88,7 @@ export const applyRspackOptionsDefaults = (
 	applySnapshotDefaults(options.snapshot, { production });
 
 	applyModuleDefaults(options.module, {
-		// syncWebAssembly: options.experiments.syncWebAssembly,
+		syncWebAssembly: options.experiments.syncWebAssembly!,
 		asyncWebAssembly: options.experiments.asyncWebAssembly!,
 		css: options.experiments.css!
 	});
@@ -162,6 +162,7 @@ const applyExperimentsDefaults = (
 ) => {
 	D(experiments, "lazyCompilation", false);
 	D(experiments, "asyncWebAssembly", false);
+	D(experiments, "syncWebAssembly", false);
 	D(experiments, "newSplitChunks", true);
 	D(experiments, "css", true); // we not align with webpack about the default value for better DX
 	D(experiments, "topLevelAwait", true);
@@ -198,9 +199,11 @@ const applyJavascriptParserOptionsDefaults = (
 const applyModuleDefaults = (
 	module: ModuleOptions,
 	{
+		syncWebAssembly,
 		asyncWebAssembly,
 		css
 	}: {
+		syncWebAssembly: boolean;
 		asyncWebAssembly: boolean;
 		css: boolean;
 	}
@@ -298,6 +301,28 @@ const applyModuleDefaults = (
 				mimetype: "application/wasm",
 				...wasm
 			});
+		} else if (syncWebAssembly) {
+			const wasm = {
+				type: "webassembly/sync",
+				rules: [
+					{
+						descriptionData: {
+							type: "module"
+						},
+						resolve: {
+							fullySpecified: true
+						}
+					}
+				]
+			};
+			rules.push({
+				test: /\.wasm$/i,
+				...wasm
+			});
+			rules.push({
+				mimetype: "application/wasm",
+				...wasm
+			});
 		}
 
 		if (css) {
@@ -713,6 +738,7 @@ const applyOptimizationDefaults = (
 	});
 	F(optimization, "sideEffects", () => (production ? true : "flag"));
 	D(optimization, "mangleExports", production);
+	D(optimization, "concatenateModules", production);
 	D(optimization, "providedExports", true);
 	D(optimization, "usedExports", production);
 	D(optimization, "innerGraph", production);
@@ -765,6 +791,7 @@ const getResolveLoaderDefaults = () => {
 	const resolveOptions: ResolveOptions = {
 		conditionNames: ["loader", "require", "node"],
 		exportsFields: ["exports"],
+		importsFields: ["imports"],
 		mainFields: ["loader", "main"],
 		extensions: [".js"],
 		mainFiles: ["index"]
@@ -818,6 +845,7 @@ const getResolveDefaults = ({
 		browserField,
 		mainFields: ["main"].filter(Boolean),
 		exportsFields: ["exports"],
+		importsFields: ["imports"],
 		byDependency: {
 			wasm: esmDeps(),
 			esm: esmDeps(),
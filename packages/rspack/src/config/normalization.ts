@@ -37,6 +37,8 @@ import type {
 	DevServer,
 	Profile,
 	Bail,
+	RecordsInputPath,
+	RecordsOutputPath,
 	Builtins,
 	EntryRuntime,
 	ChunkLoading,
@@ -286,6 +288,14 @@ export const getNormalizedRspackOptions = (
 		devServer: config.devServer,
 		profile: config.profile,
 		bail: config.bail,
+		recordsInputPath:
+			config.recordsInputPath !== undefined
+				? config.recordsInputPath
+				: config.recordsPath,
+		recordsOutputPath:
+			config.recordsOutputPath !== undefined
+				? config.recordsOutputPath
+				: config.recordsPath,
 		builtins: nestedConfig(config.builtins, builtins => ({
 			...builtins
 		}))
@@ -322,6 +332,9 @@ const getNormalizedEntryStatic = (entry: EntryStatic) => {
 			result[key] = {
 				import: Array.isArray(value.import) ? value.import : [value.import],
 				runtime: value.runtime,
+				dependOn:
+					value.dependOn &&
+					(Array.isArray(value.dependOn) ? value.dependOn : [value.dependOn]),
 				publicPath: value.publicPath,
 				baseUri: value.baseUri,
 				chunkLoading: value.chunkLoading,
@@ -408,6 +421,7 @@ export interface EntryStaticNormalized {
 export interface EntryDescriptionNormalized {
 	import?: string[];
 	runtime?: EntryRuntime;
+	dependOn?: string[];
 	chunkLoading?: ChunkLoading;
 	asyncChunks?: boolean;
 	publicPath?: PublicPath;
@@ -468,6 +482,7 @@ export interface ModuleOptionsNormalized {
 export interface ExperimentsNormalized {
 	lazyCompilation?: boolean;
 	asyncWebAssembly?: boolean;
+	syncWebAssembly?: boolean;
 	outputModule?: boolean;
 	newSplitChunks?: boolean;
 	topLevelAwait?: boolean;
@@ -516,5 +531,7 @@ export interface RspackOptionsNormalized {
 	ignoreWarnings?: IgnoreWarningsNormalized;
 	profile?: Profile;
 	bail?: Bail;
+	recordsInputPath?: RecordsInputPath;
+	recordsOutputPath?: RecordsOutputPath;
 	builtins: Builtins;
 }
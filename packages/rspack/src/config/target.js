@@ -355,6 +355,28 @@ const getTargetsProperties = (targets, context) => {
 	);
 };
 
+/**
+ * @param {string | string[] | false | undefined} target the target option
+ * @param {string} context the context directory
+ * @returns {string[]} browsers selected by the browserslist targets
+ */
+const getBrowserslistTargets = (target, context) => {
+	const targets = Array.isArray(target) ? target : target ? [target] : [];
+	return targets.flatMap(target => {
+		const match = /^browserslist(?::(.+))?$/.exec(target);
+		if (!match) return [];
+		const [, rest] = match;
+		return (
+			getBrowserslistTargetHandler().load(
+				// @ts-expect-error
+				rest ? rest.trim() : null,
+				context
+			) || []
+		);
+	});
+};
+
 exports.getDefaultTarget = getDefaultTarget;
+exports.getBrowserslistTargets = getBrowserslistTargets;
 exports.getTargetsProperties = getTargetsProperties;
 exports.getTargetProperties = getTargetProperties;
@@ -158,9 +158,13 @@ export type EntryRuntime = z.infer<typeof entryRuntime>;
 const entryItem = z.string().or(z.array(z.string()));
 export type EntryItem = z.infer<typeof entryItem>;
 
+const entryDependOn = z.string().or(z.array(z.string()));
+export type EntryDependOn = z.infer<typeof entryDependOn>;
+
 const entryDescription = z.strictObject({
 	import: entryItem,
 	runtime: entryRuntime.optional(),
+	dependOn: entryDependOn.optional(),
 	publicPath: publicPath.optional(),
 	baseUri: baseUri.optional(),
 	chunkLoading: chunkLoading.optional(),
@@ -270,13 +274,24 @@ const trustedTypes = z.strictObject({
 });
 export type TrustedTypes = z.infer<typeof trustedTypes>;
 
-const hashDigest = z.string();
+const hashDigest = z.union([
+	z.enum(["hex", "base64", "base64url"]),
+	z.string().regex(/^base(26|32|36|49|52|58|62)$/)
+]);
 export type HashDigest = z.infer<typeof hashDigest>;
 
 const hashDigestLength = z.number();
 export type HashDigestLength = z.infer<typeof hashDigestLength>;
 
-const hashFunction = z.enum(["md4", "xxhash64"]);
+const hashFunction = z.enum([
+	"md4",
+	"md5",
+	"xxhash64",
+	"xxhash128",
+	"sha256",
+	"sha384",
+	"sha512"
+]);
 export type HashFunction = z.infer<typeof hashFunction>;
 
 const hashSalt = z.string();
@@ -357,8 +372,8 @@ export type ResolveTsconfig = z.infer<typeof resolveTsconfig>;
 const baseResolveOptions = z.strictObject({
 	alias: resolveAlias.optional(),
 	/**
-	 * This is `aliasField: ["browser"]` in webpack, because no one
-	 * uses aliasField other than "browser". ---@bvanjoi
+	 * This is `aliasFields: ["browser"]` in webpack, it is ignored
+	 * when `aliasFields` is specified.
 	 */
 	browserField: z.boolean().optional(),
 	conditionNames: z.array(z.string()).optional(),
@@ -373,7 +388,12 @@ const baseResolveOptions = z.strictObject({
 	tsConfig: resolveTsconfig.optional(),
 	fullySpecified: z.boolean().optional(),
 	exportsFields: z.array(z.string()).optional(),
-	extensionAlias: z.record(z.string().or(z.array(z.string()))).optional()
+	extensionAlias: z.record(z.string().or(z.array(z.string()))).optional(),
+	importsFields: z.array(z.string()).optional(),
+	aliasFields: z.array(z.string()).optional(),
+	restrictions: z.array(z.string()).optional(),
+	roots: z.array(z.string()).optional(),
+	descriptionFiles: z.array(z.string()).optional()
 });
 
 export type ResolveOptions = z.infer<typeof baseResolveOptions> & {
@@ -860,13 +880,22 @@ export type CacheOptions = z.infer<typeof cacheOptions>;
 const statsOptions = z.strictObject({
 	all: z.boolean().optional(),
 	preset: z
-		.enum(["normal", "none", "verbose", "errors-only", "errors-warnings"])
+		.enum([
+			"normal",
+			"none",
+			"verbose",
+			"errors-only",
+			"errors-warnings",
+			"minimal",
+			"detailed"
+		])
 		.optional(),
 	assets: z.boolean().optional(),
 	chunks: z.boolean().optional(),
 	modules: z.boolean().optional(),
 	entrypoints: z.boolean().optional(),
 	chunkGroups: z.boolean().optional(),
+	shared: z.boolean().optional(),
 	warnings: z.boolean().optional(),
 	warningsCount: z.boolean().optional(),
 	errors: z.boolean().optional(),
@@ -879,12 +908,18 @@ const statsOptions = z.strictObject({
 	outputPath: z.boolean().optional(),
 	chunkModules: z.boolean().optional(),
 	chunkRelations: z.boolean().optional(),
+	chunkOrigins: z.boolean().optional(),
 	ids: z.boolean().optional(),
+	usedExports: z.boolean().optional(),
+	providedExports: z.boolean().optional(),
+	depth: z.boolean().optional(),
+	moduleTrace: z.boolean().optional(),
 	timings: z.boolean().optional(),
 	builtAt: z.boolean().optional(),
 	moduleAssets: z.boolean().optional(),
 	modulesSpace: z.number().optional(),
 	nestedModules: z.boolean().optional(),
+	optimizationBailout: z.boolean().optional(),
 	source: z.boolean().optional(),
 	logging: z
 		.enum(["none", "error", "warn", "info", "log", "verbose"])
@@ -898,7 +933,15 @@ const statsOptions = z.strictObject({
 export type StatsOptions = z.infer<typeof statsOptions>;
 
 const statsValue = z
-	.enum(["none", "errors-only", "errors-warnings", "normal", "verbose"])
+	.enum([
+		"none",
+		"errors-only",
+		"errors-warnings",
+		"minimal",
+		"normal",
+		"detailed",
+		"verbose"
+	])
 	.or(z.boolean())
 	.or(statsOptions);
 export type StatsValue = z.infer<typeof statsValue>;
@@ -1024,6 +1067,7 @@ const optimization = z.strictObject({
 	innerGraph: z.boolean().optional(),
 	usedExports: z.enum(["global"]).or(z.boolean()).optional(),
 	mangleExports: z.enum(["size", "deterministic"]).or(z.boolean()).optional(),
+	concatenateModules: z.boolean().optional(),
 	nodeEnv: z.union([z.string(), z.literal(false)]).optional()
 });
 export type Optimization = z.infer<typeof optimization>;
@@ -1039,6 +1083,7 @@ export type RspackFutureOptions = z.infer<typeof rspackFutureOptions>;
 const experiments = z.strictObject({
 	lazyCompilation: z.boolean().optional(),
 	asyncWebAssembly: z.boolean().optional(),
+	syncWebAssembly: z.boolean().optional(),
 	outputModule: z.boolean().optional(),
 	topLevelAwait: z.boolean().optional(),
 	newSplitChunks: z
@@ -1114,6 +1159,17 @@ const bail = z.boolean();
 export type Bail = z.infer<typeof bail>;
 //#endregion
 
+//#region Records
+const recordsPath = z.literal(false).or(z.string());
+export type RecordsPath = z.infer<typeof recordsPath>;
+
+const recordsInputPath = z.literal(false).or(z.string());
+export type RecordsInputPath = z.infer<typeof recordsInputPath>;
+
+const recordsOutputPath = z.literal(false).or(z.string());
+export type RecordsOutputPath = z.infer<typeof recordsOutputPath>;
+//#endregion
+
 //#region Builtins (deprecated)
 const builtins = z.custom<oldBuiltins.Builtins>();
 export type Builtins = z.infer<typeof builtins>;
@@ -1148,7 +1204,10 @@ export const rspackOptions = z.strictObject({
 	builtins: builtins.optional(),
 	module: moduleOptions.optional(),
 	profile: profile.optional(),
-	bail: bail.optional()
+	bail: bail.optional(),
+	recordsPath: recordsPath.optional(),
+	recordsInputPath: recordsInputPath.optional(),
+	recordsOutputPath: recordsOutputPath.optional()
 });
 export type RspackOptions = z.infer<typeof rspackOptions>;
 export type Configuration = RspackOptions;
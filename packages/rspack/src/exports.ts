@@ -110,11 +110,22 @@ export const electron = { ElectronTargetPlugin };
 import { EnableLibraryPlugin } from "./builtin-plugin";
 export const library = { EnableLibraryPlugin };
 
-import { EnableWasmLoadingPlugin } from "./builtin-plugin";
-export const wasm = { EnableWasmLoadingPlugin };
+import {
+	EnableWasmLoadingPlugin,
+	CustomWasmLoadingPlugin
+} from "./builtin-plugin";
+export const wasm = { EnableWasmLoadingPlugin, CustomWasmLoadingPlugin };
+export type { CustomWasmLoadingPluginOptions } from "./builtin-plugin";
 
-import { EnableChunkLoadingPlugin } from "./builtin-plugin";
-export const javascript = { EnableChunkLoadingPlugin };
+import {
+	EnableChunkLoadingPlugin,
+	CustomChunkLoadingPlugin
+} from "./builtin-plugin";
+export const javascript = {
+	EnableChunkLoadingPlugin,
+	CustomChunkLoadingPlugin
+};
+export type { CustomChunkLoadingPluginOptions } from "./builtin-plugin";
 
 import { WebWorkerTemplatePlugin } from "./builtin-plugin";
 export const webworker = { WebWorkerTemplatePlugin };
@@ -10,7 +10,11 @@
 
 import assert from "assert";
 import { Compiler, EntryDescriptionNormalized, EntryNormalized } from "..";
-import { EntryOptions, EntryPlugin } from "../builtin-plugin";
+import {
+	EnableChunkLoadingPlugin,
+	EntryOptions,
+	EntryPlugin
+} from "../builtin-plugin";
 
 export default class EntryOptionPlugin {
 	apply(compiler: Compiler) {
@@ -58,7 +62,7 @@ export default class EntryOptionPlugin {
 			filename: desc.filename,
 			runtime: desc.runtime,
 			// layer: desc.layer,
-			// dependOn: desc.dependOn,
+			dependOn: desc.dependOn,
 			baseUri: desc.baseUri,
 			publicPath: desc.publicPath,
 			chunkLoading: desc.chunkLoading,
@@ -71,10 +75,9 @@ export default class EntryOptionPlugin {
 		// 		"'entryOptions.layer' is only allowed when 'experiments.layers' is enabled"
 		// 	);
 		// }
-		// if (desc.chunkLoading) {
-		// 	const EnableChunkLoadingPlugin = require("./javascript/EnableChunkLoadingPlugin");
-		// 	EnableChunkLoadingPlugin.checkEnabled(compiler, desc.chunkLoading);
-		// }
+		if (desc.chunkLoading) {
+			EnableChunkLoadingPlugin.checkEnabled(compiler, desc.chunkLoading);
+		}
 		// if (desc.wasmLoading) {
 		// 	const EnableWasmLoadingPlugin = require("./wasm/EnableWasmLoadingPlugin");
 		// 	EnableWasmLoadingPlugin.checkEnabled(compiler, desc.wasmLoading);
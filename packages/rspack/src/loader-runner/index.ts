@@ -390,6 +390,34 @@ export async function runLoaders(
 			[name, resource].filter(Boolean).join("|")
 		);
 	};
+	loaderContext.importModule = function importModule(
+		request,
+		options,
+		callback?
+	) {
+		// `layer`, `publicPath` and `baseUri` are not supported yet
+		const promise = new Promise((resolve, reject) => {
+			compiler.compilation.__internal__importModule(
+				request,
+				contextDirectory,
+				rawContext.moduleIdentifier,
+				(err, exports, result) => {
+					if (err) {
+						return reject(err);
+					}
+					fileDependencies.push(...result!.fileDependencies);
+					contextDependencies.push(...result!.contextDependencies);
+					missingDependencies.push(...result!.missingDependencies);
+					buildDependencies.push(...result!.buildDependencies);
+					resolve(exports);
+				}
+			);
+		});
+		if (!callback) {
+			return promise;
+		}
+		promise.then(exports => callback(null, exports), callback);
+	} as LoaderContext["importModule"];
 	loaderContext.rootContext = compiler.context;
 	loaderContext.emitError = function emitError(error) {
 		const title = "Module Error";
@@ -49,6 +49,7 @@ import {
 	JavascriptModulesPlugin,
 	JsonModulesPlugin,
 	AsyncWebAssemblyModulesPlugin,
+	WebAssemblyModulesPlugin,
 	RuntimePlugin,
 	InferAsyncModulesPlugin,
 	WorkerPlugin,
@@ -56,6 +57,7 @@ import {
 	SourceMapDevToolPlugin,
 	AssetModulesPlugin,
 	MangleExportsPlugin,
+	ModuleConcatenationPlugin,
 	FlagDependencyExportsPlugin,
 	FlagDependencyUsagePlugin,
 	SideEffectsFlagPlugin
@@ -223,6 +225,9 @@ export class RspackOptionsApply {
 		new JavascriptModulesPlugin().apply(compiler);
 		new JsonModulesPlugin().apply(compiler);
 		new AssetModulesPlugin().apply(compiler);
+		if (options.experiments.syncWebAssembly) {
+			new WebAssemblyModulesPlugin().apply(compiler);
+		}
 		if (options.experiments.asyncWebAssembly) {
 			new AsyncWebAssemblyModulesPlugin().apply(compiler);
 		}
@@ -268,6 +273,9 @@ export class RspackOptionsApply {
 				options.optimization.mangleExports !== "size"
 			).apply(compiler);
 		}
+		if (options.optimization.concatenateModules) {
+			new ModuleConcatenationPlugin().apply(compiler);
+		}
 		if (options.optimization.splitChunks) {
 			new SplitChunksPlugin(options.optimization.splitChunks).apply(compiler);
 		}
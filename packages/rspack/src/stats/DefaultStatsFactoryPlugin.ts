@@ -10,6 +10,11 @@
 import { compareSelect, compareIds as _compareIds } from "../util/comparators";
 import { makePathsRelative } from "../util/identifier";
 import * as util from "node:util";
+import type {
+	JsStatsError,
+	JsStatsOptions,
+	JsStatsWarning
+} from "@rspack/binding";
 import type { Compiler } from "../Compiler";
 import type { StatsOptions } from "../config";
 import type { GroupConfig } from "../util/smartGrouping";
@@ -33,6 +38,7 @@ import type {
 	StatsChunk,
 	NormalizedStatsOptions,
 	KnownStatsLoggingEntry,
+	StatsModuleTraceItem,
 	StatsProfile
 } from "./statsFactoryUtils";
 import {
@@ -361,6 +367,42 @@ const SORTERS: Record<
 	// not support chunk.origins (missing compilation.chunkGraph)
 };
 
+const toJsStatsOptions = (options: StatsOptions): JsStatsOptions => ({
+	modules: !!options.modules,
+	reasons: !!options.reasons,
+	moduleAssets: !!options.moduleAssets,
+	nestedModules: !!options.nestedModules,
+	source: !!options.source,
+	chunkModules: !!options.chunkModules,
+	chunkRelations: !!options.chunkRelations,
+	chunkOrigins: !!options.chunkOrigins,
+	ids: !!options.ids,
+	usedExports: !!options.usedExports,
+	providedExports: !!options.providedExports,
+	optimizationBailout: !!options.optimizationBailout,
+	depth: !!options.depth,
+	moduleTrace: !!options.moduleTrace
+});
+
+const formatModuleTrace = <T extends JsStatsError | JsStatsWarning>(
+	item: T,
+	options: StatsOptions
+): Omit<T, "moduleTrace"> & { moduleTrace?: StatsModuleTraceItem[] } => {
+	const { moduleTrace, ...rest } = item;
+	if (!options.moduleTrace) return rest;
+	return {
+		...rest,
+		moduleTrace: moduleTrace.map(({ origin, module }) => ({
+			originIdentifier: origin.identifier,
+			originName: origin.name,
+			originId: origin.id,
+			moduleIdentifier: module.identifier,
+			moduleName: module.name,
+			moduleId: module.id
+		}))
+	};
+};
+
 const SIMPLE_EXTRACTORS: SimpleExtractors = {
 	compilation: {
 		_: (
@@ -377,12 +419,14 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 			}
 			if (!context.cachedGetErrors) {
 				context.cachedGetErrors = _compilation => {
-					return context._inner.getErrors();
+					return context._inner.getErrors(toJsStatsOptions(options));
 				};
 			}
 			if (!context.cachedGetWarnings) {
 				context.cachedGetWarnings = _compilation => {
-					const warnings = context._inner.getWarnings();
+					const warnings = context._inner.getWarnings(
+						toJsStatsOptions(options)
+					);
 
 					return compilation.hooks.processWarnings.call(
 						warnings as any
@@ -590,14 +634,7 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 			factory
 		) => {
 			const { type } = context;
-			const chunks = context._inner.getChunks(
-				options.chunkModules!,
-				options.chunkRelations!,
-				options.reasons!,
-				options.moduleAssets!,
-				options.nestedModules!,
-				options.source!
-			);
+			const chunks = context._inner.getChunks(toJsStatsOptions(options));
 			object.chunks = factory.create(`${type}.chunks`, chunks, context);
 		},
 		modules: (
@@ -608,12 +645,7 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 			factory
 		) => {
 			const { type } = context;
-			const array = context._inner.getModules(
-				options.reasons!,
-				options.moduleAssets!,
-				options.nestedModules!,
-				options.source!
-			);
+			const array = context._inner.getModules(toJsStatsOptions(options));
 			const groupedModules = factory.create(`${type}.modules`, array, context);
 			const limited = spaceLimited(groupedModules, options.modulesSpace!);
 			object.modules = limited.children;
@@ -664,15 +696,30 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 				return acc;
 			}, {});
 		},
+		shared: (
+			object,
+			_compilation,
+			context: KnownStatsFactoryContext,
+			_options,
+			_factory
+		) => {
+			// only available with module federation
+			const shared = context._inner.getShared();
+			if (shared.length > 0) {
+				object.shared = shared;
+			}
+		},
 		errors: (
 			object,
 			compilation,
 			context: KnownStatsFactoryContext,
-			_options,
+			options,
 			_factory
 		) => {
 			const { cachedGetErrors } = context;
-			object.errors = cachedGetErrors!(compilation);
+			object.errors = cachedGetErrors!(compilation).map(error =>
+				formatModuleTrace(error, options)
+			);
 		},
 		errorsCount: (
 			object,
@@ -687,11 +734,13 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 			object,
 			compilation,
 			context: KnownStatsFactoryContext,
-			_options,
+			options,
 			_factory
 		) => {
 			const { cachedGetWarnings } = context;
-			object.warnings = cachedGetWarnings!(compilation);
+			object.warnings = cachedGetWarnings!(compilation).map(warning =>
+				formatModuleTrace(warning, options)
+			);
 		},
 		warningsCount: (object, compilation, context: KnownStatsFactoryContext) => {
 			const { cachedGetWarnings } = context;
@@ -779,6 +828,36 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 				context
 			);
 		},
+		optimizationBailout: (object, module) => {
+			if (module.optimizationBailout.length > 0) {
+				object.optimizationBailout = module.optimizationBailout;
+			}
+		},
+		depth: (object, module) => {
+			if (typeof module.depth === "number") {
+				object.depth = module.depth;
+			}
+		},
+		usedExports: (object, module) => {
+			if (module.usedExports !== undefined) {
+				object.usedExports = module.usedExports;
+			}
+		},
+		providedExports: (object, module) => {
+			if (module.providedExports !== undefined) {
+				object.providedExports = module.providedExports;
+			}
+		},
+		nestedModules: (object, module, context, options, factory) => {
+			const { type } = context;
+			if (module.modules) {
+				object.modules = factory.create(
+					`${type.slice(0, -8)}.modules`,
+					module.modules,
+					context
+				);
+			}
+		},
 		source: (object, module) => {
 			object.source = module.source;
 		}
@@ -835,6 +914,9 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 			object.parents = chunk.parents;
 			object.children = chunk.children;
 		},
+		chunkOrigins: (object, chunk) => {
+			object.origins = chunk.origins;
+		},
 		chunkModules: (object, chunk, context, options, factory) => {
 			const { type } = context;
 			object.modules = factory.create(
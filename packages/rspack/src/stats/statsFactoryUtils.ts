@@ -15,6 +15,8 @@ export type KnownStatsChunk = binding.JsStatsChunk;
 
 export type StatsChunkGroup = binding.JsStatsChunkGroup & Record<string, any>;
 
+export type StatsShared = binding.JsStatsShared & Record<string, any>;
+
 export type KnownStatsAsset = binding.JsStatsAsset;
 
 export type StatsAsset = KnownStatsAsset & Record<string, any>;
@@ -39,9 +41,22 @@ export type StatsModule = KnownStatsModule & Record<string, any>;
 export type StatsModuleIssuer = binding.JsStatsModuleIssuer &
 	Record<string, any>;
 
-export type StatsError = binding.JsStatsError & Record<string, any>;
+export type StatsModuleTraceItem = {
+	originIdentifier: string;
+	originName: string;
+	originId?: string;
+	moduleIdentifier: string;
+	moduleName: string;
+	moduleId?: string;
+};
+
+export type StatsError = Omit<binding.JsStatsError, "moduleTrace"> & {
+	moduleTrace?: StatsModuleTraceItem[];
+} & Record<string, any>;
 
-export type StatsWarnings = binding.JsStatsWarning & Record<string, any>;
+export type StatsWarnings = Omit<binding.JsStatsWarning, "moduleTrace"> & {
+	moduleTrace?: StatsModuleTraceItem[];
+} & Record<string, any>;
 
 export type StatsModuleReason = binding.JsStatsModuleReason &
 	Record<string, any>;
@@ -66,6 +81,7 @@ export type KnownStatsCompilation = {
 	modules?: StatsModule[];
 	entrypoints?: Record<string, StatsChunkGroup>;
 	namedChunkGroups?: Record<string, StatsChunkGroup>;
+	shared?: StatsShared[];
 	errors?: StatsError[];
 	errorsCount?: number;
 	warnings?: StatsWarnings[];
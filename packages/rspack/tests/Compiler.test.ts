@@ -1392,4 +1392,56 @@ describe("Compiler", () => {
 			});
 		});
 	});
+
+	describe("chunk loading and wasm loading types", () => {
+		it("should throw for chunk loading types not registered by plugins", () => {
+			expect(() =>
+				rspack({
+					entry: "./a",
+					context: path.join(__dirname, "fixtures"),
+					output: { chunkLoading: "custom" }
+				})
+			).toThrow(/Unsupported chunk loading type custom/);
+		});
+
+		it("should throw for wasm loading types not registered by plugins", () => {
+			expect(() =>
+				rspack({
+					entry: "./a",
+					context: path.join(__dirname, "fixtures"),
+					output: { wasmLoading: "custom" }
+				})
+			).toThrow(/Unsupported wasm loading type custom/);
+		});
+
+		it("should throw when the chunk loading type of entry is not enabled", () => {
+			expect(() =>
+				rspack({
+					entry: { main: { import: "./a", chunkLoading: "import-scripts" } },
+					context: path.join(__dirname, "fixtures"),
+					output: { enabledChunkLoadingTypes: ["jsonp"] }
+				})
+			).toThrow(/Chunk loading type "import-scripts" is not enabled/);
+		});
+
+		it("should accept chunk loading and wasm loading types registered by plugins", () => {
+			const compiler = rspack({
+				entry: "./a",
+				context: path.join(__dirname, "fixtures"),
+				output: { chunkLoading: "custom", wasmLoading: "custom" },
+				plugins: [
+					new rspack.javascript.CustomChunkLoadingPlugin({
+						type: "custom",
+						source: "__webpack_require__.f.custom = function () {};"
+					}),
+					new rspack.wasm.CustomWasmLoadingPlugin({
+						type: "custom",
+						generateLoadBinaryCode: "fetch($PATH)",
+						supportsStreaming: true
+					})
+				]
+			});
+			expect(compiler).toBeInstanceOf(Compiler);
+		});
+	});
 });
@@ -109,9 +109,11 @@ describe("snapshots", () => {
 		+   "mode": undefined,
 		@@ ... @@
 		-     "chunkIds": "named",
+		-     "concatenateModules": false,
 		-     "innerGraph": false,
 		-     "mangleExports": false,
 		+     "chunkIds": "deterministic",
+		+     "concatenateModules": true,
 		+     "innerGraph": true,
 		+     "mangleExports": true,
 		@@ ... @@
@@ -159,9 +161,11 @@ describe("snapshots", () => {
 		+   "mode": "production",
 		@@ ... @@
 		-     "chunkIds": "named",
+		-     "concatenateModules": false,
 		-     "innerGraph": false,
 		-     "mangleExports": false,
 		+     "chunkIds": "deterministic",
+		+     "concatenateModules": true,
 		+     "innerGraph": true,
 		+     "mangleExports": true,
 		@@ ... @@
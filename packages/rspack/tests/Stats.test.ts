@@ -30,9 +30,11 @@ describe("Stats", () => {
 		asset main.js 211 bytes {909} [emitted] (name: main)
 		Entrypoint main 211 bytes = main.js
 		chunk {909} main.js (main) [entry]
-		  ./fixtures/a.js [585] {909}
+		  ./fixtures/a.js [585] {909} [depth 0]
+		    Module is not an ECMAScript module
 		    entry ./fixtures/a
-		./fixtures/a.js [585] {909}
+		./fixtures/a.js [585] {909} [depth 0]
+		  Module is not an ECMAScript module
 		  entry ./fixtures/a
 		  
 		Rspack compiled successfully (57e46af248a1c1fe076f)"
@@ -310,6 +312,75 @@ describe("Stats", () => {
 	`);
 	});
 
+	it("should have chunk origins of nested blocks", async () => {
+		const stats = await compile({
+			context: __dirname,
+			entry: "./fixtures/nested-blocks/index"
+		});
+		const { chunks } = stats!.toJson({
+			all: false,
+			chunks: true,
+			chunkOrigins: true
+		});
+		const origins = (name: string) =>
+			chunks!.find(chunk => chunk.names.includes(name))!.origins;
+		expect(origins("ensure")).toMatchObject([
+			{ moduleName: "./fixtures/nested-blocks/index.js", request: "" }
+		]);
+		// the import() inside the require.ensure callback is a nested block
+		expect(origins("nested")).toMatchObject([
+			{ moduleName: "./fixtures/nested-blocks/index.js", request: "./b" }
+		]);
+	});
+
+	it("should have module trace of errors when moduleTrace is true", async () => {
+		const stats = await compile({
+			context: __dirname,
+			entry: "./fixtures/module-trace/index"
+		});
+		const withTrace = stats!.toJson({
+			all: false,
+			errors: true,
+			moduleTrace: true
+		});
+		expect(withTrace.errors![0].moduleTrace).toMatchObject([
+			{
+				originName: "./fixtures/module-trace/index.js",
+				moduleName: "./fixtures/module-trace/a.js"
+			}
+		]);
+		expect(
+			stats!.toString({ all: false, errors: true, moduleTrace: true })
+		).toContain(" @ ./fixtures/module-trace/index.js");
+		const withoutTrace = stats!.toJson({ all: false, errors: true });
+		expect(withoutTrace.errors![0]).not.toHaveProperty("moduleTrace");
+	});
+
+	it("should apply the toggles of stats presets", async () => {
+		const stats = await compile({
+			context: __dirname,
+			entry: "./fixtures/module-trace/index"
+		});
+		const errorsOnly = stats!.toJson("errors-only");
+		expect(errorsOnly).not.toHaveProperty("modules");
+		expect(errorsOnly).not.toHaveProperty("chunks");
+		expect(errorsOnly.errors![0].moduleTrace).toHaveLength(1);
+
+		const detailed = stats!.toJson("detailed");
+		expect(detailed.chunks![0]).not.toHaveProperty("modules");
+		expect(detailed.chunks![0]).toHaveProperty("origins");
+		expect(detailed.modules![0]).toHaveProperty("depth");
+		expect(detailed.errors![0].moduleTrace).toHaveLength(1);
+
+		const normal = stats!.toJson("normal");
+		expect(normal.errors![0]).not.toHaveProperty("moduleTrace");
+		expect(normal.modules![0]).not.toHaveProperty("depth");
+
+		const none = stats!.toJson("none");
+		expect(none).not.toHaveProperty("errors");
+		expect(none).not.toHaveProperty("modules");
+	});
+
 	it("should have null as placeholders in stats before chunkIds", async () => {
 		let stats;
 
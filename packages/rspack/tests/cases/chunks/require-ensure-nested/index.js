@@ -0,0 +1,35 @@
+it("should load the modules of require.ensure in an async chunk", function (done) {
+	require.ensure(["./a"], function () {
+		expect(require("./a")).toBe("a");
+		done();
+	});
+});
+
+it("should load import() inside require.ensure as a nested chunk", function (done) {
+	require.ensure(
+		[],
+		function () {
+			import("./b")
+				.then(function (b) {
+					expect(b.default).toBe("b");
+					done();
+				})
+				.catch(done);
+		},
+		"ensure-nested"
+	);
+});
+
+it("should call the error callback of require.ensure", function (done) {
+	require.ensure(
+		["./a"],
+		function () {
+			expect(require("./a")).toBe("a");
+			done();
+		},
+		function (err) {
+			done(err);
+		},
+		"ensure-error-callback"
+	);
+});
@@ -0,0 +1,28 @@
+import styles from "./style.module.css";
+const fs = require("fs");
+const path = require("path");
+
+it("should export css modules values", function () {
+	expect(styles.button).toBeTruthy();
+	expect(styles.primary).toBe("#ff0000");
+	expect(styles.accent).toBe("#00ff00");
+	expect(styles.border).toBe("1px solid #ff0000");
+	expect(styles.spacing).toBe("8px");
+	expect(styles.separator).toBe('"a;b"');
+});
+
+it("should compose global classes from other css modules", function () {
+	expect(styles.label).toMatch(/ theme$/);
+});
+
+it("should replace css modules values in the stylesheet", function () {
+	const css = fs.readFileSync(path.resolve(__dirname, "main.css"), "utf-8");
+	expect(css).toMatch(/color: #ff0000/);
+	expect(css).toMatch(/border: 1px solid #ff0000/);
+	expect(css).toMatch(/@media \(max-width: 600px\)/);
+	expect(css).toMatch(/background: #00ff00/);
+	expect(css).toMatch(/content: "primary"/);
+	expect(css).toMatch(/font-family: primary-font, serif/);
+	expect(css).not.toMatch(/@value/);
+	expect(css).not.toMatch(/:export/);
+});
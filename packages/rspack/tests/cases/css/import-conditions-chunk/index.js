@@ -0,0 +1,12 @@
+require("./index.css");
+const fs = require("fs");
+const path = require("path");
+
+// print.css is also loaded on demand, in a chunk of its own
+const loadPrint = () => import("./print.css");
+
+it("should keep the conditions of @import in the chunk of the importer", function () {
+	expect(typeof loadPrint).toBe("function");
+	const css = fs.readFileSync(path.resolve(__dirname, "main.css"), "utf-8");
+	expect(css).toMatch(/@media print {\s*\.print/);
+});
@@ -0,0 +1,10 @@
+require("./index.css");
+const fs = require("fs");
+const path = require("path");
+
+it("should ignore the conditions of a css module imported with different conditions", function () {
+	const css = fs.readFileSync(path.resolve(__dirname, "main.css"), "utf-8");
+	expect(css).toMatch(/^\.shared/m);
+	expect(css).toMatch(/@media screen {\s*\.screen/);
+	expect(css).toMatch(/@media print {\s*\.print/);
+});
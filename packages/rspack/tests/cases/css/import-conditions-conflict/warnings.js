@@ -0,0 +1,3 @@
+module.exports = [
+	[/shared\.css is imported with different layer, supports or media conditions/]
+];
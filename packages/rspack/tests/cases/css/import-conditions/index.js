@@ -0,0 +1,13 @@
+require("./index.css");
+const fs = require("fs");
+const path = require("path");
+
+it("should wrap the imported css in the conditions of @import", function () {
+	const css = fs.readFileSync(path.resolve(__dirname, "main.css"), "utf-8");
+	expect(css).toMatch(
+		/@layer vendor {\s*@supports \(display: ?grid\) {\s*@media screen {\s*\.layered/
+	);
+	expect(css).toMatch(/@layer {\s*\.anonymous/);
+	expect(css).toMatch(/@media print {\s*@layer base {\s*\.nested/);
+	expect(css).not.toMatch(/@import/);
+});
@@ -0,0 +1,16 @@
+require("./index.css");
+const fs = require("fs");
+const path = require("path");
+
+it("should lower css syntax and add vendor prefixes for the targets", function () {
+	const css = fs.readFileSync(path.resolve(__dirname, "main.css"), "utf-8");
+	expect(css).toMatch(/\.parent \.child\s*{/);
+	expect(css).toMatch(/-webkit-user-select: none/);
+	expect(css).toMatch(/\.a > \.c,\s*\.b > \.c\s*{/);
+	expect(css).not.toMatch(/:is\(\.a/);
+	// expanding would change the specificity of `.f > .g`
+	expect(css).not.toMatch(/#e > \.g/);
+	expect(css).not.toMatch(/\.f > \.g/);
+	expect(css).toMatch(/@media \(max-width: 30em\)/);
+	expect(css).not.toMatch(/@custom-media/);
+});
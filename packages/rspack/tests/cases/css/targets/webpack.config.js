@@ -0,0 +1,7 @@
+module.exports = {
+	builtins: {
+		css: {
+			targets: ["chrome 60", "safari 10"]
+		}
+	}
+};
@@ -0,0 +1,15 @@
+import styles from "./style.module.css";
+const fs = require("fs");
+const path = require("path");
+
+it("should drop unused css modules classes", function () {
+	expect(styles.used).toBeTruthy();
+	expect(styles.composer.split(" ")).toHaveLength(2);
+
+	const css = fs.readFileSync(path.resolve(__dirname, "main.css"), "utf-8");
+	expect(css).not.toMatch(/color: red/);
+	expect(css).toMatch(/background: green/);
+	expect(css).toMatch(/font-weight: bold/);
+	expect(css).toMatch(/color: blue/);
+	expect(css).toMatch(/\.global/);
+});
@@ -0,0 +1,11 @@
+module.exports = {
+	mode: "production",
+	experiments: {
+		rspackFuture: {
+			newTreeshaking: true
+		}
+	},
+	optimization: {
+		minimize: false
+	}
+};
@@ -0,0 +1,25 @@
+const fs = require("fs");
+const path = require("path");
+
+export function load() {
+	return Promise.all([
+		import(/* webpackPreload: true, webpackChunkName: "preloaded" */ "./preloaded"),
+		import(/* webpackPrefetch: true, webpackChunkName: "prefetched" */ "./prefetched")
+	]);
+}
+
+it("should inject preload and prefetch links for async chunks", () => {
+	const htmlPath = path.join(__dirname, "./index.html");
+	const htmlContent = fs.readFileSync(htmlPath, "utf-8");
+	expect(htmlContent).toContain(
+		'<link href="preloaded.js" rel="preload" as="script">'
+	);
+	expect(htmlContent).toContain('<link href="prefetched.js" rel="prefetch">');
+});
+
+it("should exclude resource hints by file type", () => {
+	const htmlPath = path.join(__dirname, "./excluded.html");
+	const htmlContent = fs.readFileSync(htmlPath, "utf-8");
+	expect(htmlContent).not.toContain('rel="preload"');
+	expect(htmlContent).toContain('<link href="prefetched.js" rel="prefetch">');
+});
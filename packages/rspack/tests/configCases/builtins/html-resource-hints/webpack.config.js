@@ -0,0 +1,19 @@
+const { HtmlRspackPlugin } = require("../../../../");
+
+module.exports = {
+	output: {
+		chunkFilename: "[name].js"
+	},
+	plugins: [
+		new HtmlRspackPlugin({
+			filename: "index.html",
+			preload: true,
+			prefetch: true
+		}),
+		new HtmlRspackPlugin({
+			filename: "excluded.html",
+			preload: { exclude: ["js"] },
+			prefetch: { include: ["js"] }
+		})
+	]
+};
@@ -0,0 +1,5 @@
+it("should load async chunks with the registered chunk loading type", async () => {
+	const { value } = await import("./async");
+	expect(value).toBe("async");
+	expect(globalThis.customRequiredChunks).toHaveLength(1);
+});
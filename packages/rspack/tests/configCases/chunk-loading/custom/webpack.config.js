@@ -0,0 +1,32 @@
+const { javascript } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	output: {
+		chunkLoading: "custom-require"
+	},
+	plugins: [
+		new javascript.CustomChunkLoadingPlugin({
+			type: "custom-require",
+			runtimeRequirements: [
+				"__webpack_require__.u",
+				"__webpack_require__.m (add only)",
+				"__webpack_require__.o"
+			],
+			source: `
+__webpack_require__.f.customRequire = function (chunkId) {
+	(globalThis.customRequiredChunks = globalThis.customRequiredChunks || []).push(chunkId);
+	var chunk = require("./" + __webpack_require__.u(chunkId));
+	var moreModules = chunk.modules;
+	for (var moduleId in moreModules) {
+		if (__webpack_require__.o(moreModules, moduleId)) {
+			__webpack_require__.m[moduleId] = moreModules[moduleId];
+		}
+	}
+	if (chunk.runtime) chunk.runtime(__webpack_require__);
+};
+`
+		})
+	]
+};
@@ -0,0 +1,5 @@
+module.exports = [
+	[
+		/No satisfying version \(\^2\.0\.0\) of shared module strict-lib found in shared scope default/
+	]
+];
@@ -0,0 +1,24 @@
+it("should use the provided singleton with a warning at build time", async () => {
+	const { version } = await import("singleton-lib");
+	expect(version).toBe("1.0.0");
+});
+
+it("should report the provided versions in stats", () => {
+	const shared = __STATS__.shared;
+	expect(shared.map(({ shareKey }) => shareKey)).toEqual([
+		"singleton-lib",
+		"strict-lib"
+	]);
+	for (const { shareKey, shareScope, versions } of shared) {
+		expect(shareScope).toBe("default");
+		expect(versions).toHaveLength(1);
+		expect(versions[0].version).toBe("1.0.0");
+		expect(versions[0].request).toContain(`node_modules/${shareKey}`);
+		expect(versions[0].chunks).toHaveLength(1);
+	}
+});
+
+// Only built to check the strict version, it throws when loaded
+export function loadStrictLib() {
+	return import("strict-lib");
+}
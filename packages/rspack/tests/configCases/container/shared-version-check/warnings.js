@@ -0,0 +1,5 @@
+module.exports = [
+	[
+		/Unsatisfied version 1\.0\.0 from .*singleton-lib.* of shared singleton module singleton-lib \(required \^2\.0\.0/
+	]
+];
@@ -0,0 +1,20 @@
+// eslint-disable-next-line node/no-unpublished-require
+const { SharePlugin } = require("../../../../").sharing;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	plugins: [
+		new SharePlugin({
+			shared: {
+				"singleton-lib": {
+					singleton: true,
+					requiredVersion: "^2.0.0"
+				},
+				"strict-lib": {
+					strictVersion: true,
+					requiredVersion: "^2.0.0"
+				}
+			}
+		})
+	]
+};
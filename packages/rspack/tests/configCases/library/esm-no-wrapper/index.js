@@ -0,0 +1,6 @@
+import { double } from "./math";
+
+export const value = double(21);
+export function getValue() {
+	return value;
+}
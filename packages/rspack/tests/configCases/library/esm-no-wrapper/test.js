@@ -0,0 +1,3 @@
+it("should never run this module", () => {
+	expect(1).toBe(2);
+});
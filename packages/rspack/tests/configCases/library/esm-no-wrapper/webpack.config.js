@@ -0,0 +1,51 @@
+const path = require("path");
+const fs = require("fs");
+const assert = require("assert");
+
+/** @type {import("../../../../").Configuration} */
+module.exports = {
+	entry: {
+		main: "./index.js",
+		test: "./test.js"
+	},
+	output: {
+		library: {
+			type: "module"
+		},
+		iife: false,
+		chunkFormat: "module"
+	},
+	experiments: {
+		outputModule: true
+	},
+	optimization: {
+		minimize: false,
+		concatenateModules: true
+	},
+	plugins: [
+		{
+			apply(compiler) {
+				compiler.hooks.afterEmit.tap("test", () => {
+					const source = fs.readFileSync(
+						path.resolve(__dirname, "./dist/main.mjs"),
+						"utf-8"
+					);
+					assert(!source.includes("__webpack_require__"));
+					assert(!source.includes("__webpack_exports__"));
+					assert(!source.includes("__webpack_modules__"));
+					assert(/export \{ .*getValue.* \};/.test(source));
+					const testRaw = `
+import { getValue, value } from "./main.mjs";
+it("should export the bindings of the entry module without the wrapper", () => {
+	expect(value).toBe(42);
+	expect(getValue()).toBe(42);
+});`;
+					fs.writeFileSync(
+						path.resolve(__dirname, "./dist/test.mjs"),
+						testRaw
+					);
+				});
+			}
+		}
+	]
+};
@@ -0,0 +1,28 @@
+import fs from "node:fs";
+import url from "node:url";
+import path from "node:path";
+import { shared } from "./shared";
+
+export * from "node:os";
+export const value = shared;
+
+it("should import shared chunks statically", function () {
+	expect(value).toBe("shared");
+	const __filename = url.fileURLToPath(import.meta.url);
+	const source = fs.readFileSync(
+		path.join(path.dirname(__filename), "main.js"),
+		"utf-8"
+	);
+	expect(source).toContain("from './shared.js'");
+	expect(source).not.toContain("export default __webpack_require__");
+});
+
+it("should re-export module externals", function () {
+	const __filename = url.fileURLToPath(import.meta.url);
+	const source = fs.readFileSync(
+		path.join(path.dirname(__filename), "main.js"),
+		"utf-8"
+	);
+	expect(source).toContain('export * from "node:os";');
+	expect(source).toMatch(/export \{.* as value.*\}/);
+});
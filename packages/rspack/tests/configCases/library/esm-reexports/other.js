@@ -0,0 +1 @@
+export { shared } from "./shared";
@@ -0,0 +1,37 @@
+/** @type {import("../../../../").Configuration} */
+module.exports = {
+	entry: {
+		main: "./index.js",
+		other: "./other.js"
+	},
+	output: {
+		library: {
+			type: "module"
+		},
+		iife: false,
+		chunkFormat: "module",
+		filename: "[name].js",
+		chunkFilename: "[name].js"
+	},
+	externals: {
+		"node:os": "module node:os"
+	},
+	experiments: {
+		outputModule: true
+	},
+	target: "node",
+	optimization: {
+		minimize: false,
+		splitChunks: {
+			minSize: 0,
+			cacheGroups: {
+				shared: {
+					test: /shared/,
+					name: "shared",
+					chunks: "all",
+					enforce: true
+				}
+			}
+		}
+	}
+};
@@ -0,0 +1 @@
+module.exports = [[/Jsonp library name must be a simple string/]];
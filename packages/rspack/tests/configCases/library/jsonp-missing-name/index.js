@@ -0,0 +1,3 @@
+it("should error", function () {
+	throw new Error("should not be executed");
+});
@@ -0,0 +1,8 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		library: {
+			type: "jsonp"
+		}
+	}
+};
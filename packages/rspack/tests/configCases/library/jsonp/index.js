@@ -0,0 +1,9 @@
+export const value = 42;
+
+it("should wrap the library in the jsonp callback", function () {
+	var fs = require("fs");
+	var source = fs.readFileSync(__filename, "utf-8");
+
+	expect(source).toMatch("mainLoaded(");
+	expect(source.includes("return __webpack_exports__")).toBe(true);
+});
@@ -0,0 +1,18 @@
+const { BannerPlugin } = require("../../../../");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		library: {
+			type: "jsonp",
+			name: "[name]Loaded"
+		}
+	},
+	plugins: [
+		// The callback must exist when the bundle is executed
+		new BannerPlugin({
+			banner: "function mainLoaded(exports) { return exports; }",
+			raw: true
+		})
+	]
+};
@@ -0,0 +1 @@
+module.exports = [/circular build dependency/];
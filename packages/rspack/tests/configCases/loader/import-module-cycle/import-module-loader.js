@@ -0,0 +1,8 @@
+module.exports = function () {
+	const callback = this.async();
+	this.importModule("./theme.js").then(
+		theme =>
+			callback(null, `export default ${JSON.stringify(theme.default)};`),
+		callback
+	);
+};
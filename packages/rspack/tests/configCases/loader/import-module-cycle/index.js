@@ -0,0 +1,10 @@
+it("should report a circular build dependency of modules imported by loaders", () => {
+	let errored = false;
+	try {
+		require("./generated.js");
+	} catch (e) {
+		errored = true;
+		expect(e.message).toContain("circular build dependency");
+	}
+	expect(errored).toBeTruthy();
+});
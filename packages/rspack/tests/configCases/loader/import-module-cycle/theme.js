@@ -0,0 +1,3 @@
+import generated from "./generated.js";
+
+export default generated;
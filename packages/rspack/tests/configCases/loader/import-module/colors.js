@@ -0,0 +1 @@
+export const primary = "#ff0000";
@@ -0,0 +1,18 @@
+module.exports = function () {
+	const callback = this.async();
+	Promise.all([
+		this.importModule("./theme.js"),
+		new Promise((resolve, reject) =>
+			this.importModule("./palette.js", {}, (err, exports) =>
+				err ? reject(err) : resolve(exports)
+			)
+		)
+	]).then(([theme, palette]) => {
+		callback(
+			null,
+			`export const color = ${JSON.stringify(theme.default)};
+export const size = ${JSON.stringify(theme.size)};
+export const palette = ${JSON.stringify(palette)};`
+		);
+	}, callback);
+};
@@ -0,0 +1,13 @@
+import { color, size, palette } from "./generated.js";
+
+it("should execute modules imported by loaders at build time", () => {
+	expect(color).toBe("#ff0000");
+	expect(size).toBe(16);
+	expect(palette).toEqual(["#ff0000", "#00ff00"]);
+});
+
+it("should not bundle modules imported by loaders", () => {
+	const fs = require("fs");
+	const source = fs.readFileSync(__filename, "utf-8");
+	expect(source).not.toContain(["2", "*", "8"].join(" "));
+});
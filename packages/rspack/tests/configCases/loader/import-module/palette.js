@@ -0,0 +1 @@
+module.exports = [require("./colors.js").primary, "#00ff00"];
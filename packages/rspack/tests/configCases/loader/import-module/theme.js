@@ -0,0 +1,5 @@
+import { primary } from "./colors.js";
+
+export const size = 2 * 8;
+
+export default primary;
@@ -0,0 +1,12 @@
+const path = require("path");
+
+module.exports = {
+	module: {
+		rules: [
+			{
+				test: path.join(__dirname, "generated.js"),
+				use: [{ loader: path.join(__dirname, "import-module-loader.js") }]
+			}
+		]
+	}
+};
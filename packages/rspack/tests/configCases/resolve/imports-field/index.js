@@ -0,0 +1,9 @@
+it("should resolve the package internal specifiers with importsFields", () => {
+	const { value } = require("pkg");
+	expect(value).toBe("internal");
+});
+
+it("should alias the files with aliasFields", () => {
+	const { aliased } = require("pkg");
+	expect(aliased).toBe("aliased");
+});
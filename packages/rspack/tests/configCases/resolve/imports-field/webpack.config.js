@@ -0,0 +1,8 @@
+module.exports = {
+	entry: "./index.js",
+	resolve: {
+		importsFields: ["internal", "imports"],
+		aliasFields: ["alias"],
+		descriptionFiles: ["package.json"]
+	}
+};
@@ -0,0 +1,6 @@
+it("should load WebAssembly with the registered wasm loading type", async () => {
+	const wasm = await import("./fact.wasm");
+	expect(wasm._Z4facti(11)).toEqual(39916800);
+	expect(globalThis.customLoadedWasm).toHaveLength(1);
+	expect(globalThis.customLoadedWasm[0]).toMatch(/\.wasm$/);
+});
@@ -0,0 +1,24 @@
+const { wasm } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	output: {
+		wasmLoading: "custom-read-file"
+	},
+	experiments: {
+		asyncWebAssembly: true
+	},
+	plugins: [
+		new wasm.CustomWasmLoadingPlugin({
+			type: "custom-read-file",
+			generateLoadBinaryCode: `new Promise(function (resolve, reject) {
+	(globalThis.customLoadedWasm = globalThis.customLoadedWasm || []).push($PATH);
+	require("fs").readFile(require("path").join(__dirname, $PATH), function (err, buffer) {
+		if (err) return reject(err);
+		resolve({ arrayBuffer() { return buffer; } });
+	});
+})`
+		})
+	]
+};
@@ -0,0 +1,12 @@
+module.exports = {
+	entry: {
+		a: {
+			import: "./a",
+			dependOn: "b"
+		},
+		b: {
+			import: "./b",
+			dependOn: "a"
+		}
+	}
+};
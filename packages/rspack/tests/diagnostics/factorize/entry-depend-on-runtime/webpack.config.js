@@ -0,0 +1,10 @@
+module.exports = {
+	entry: {
+		a: "./a",
+		b: {
+			import: "./b",
+			dependOn: "a",
+			runtime: "runtime"
+		}
+	}
+};
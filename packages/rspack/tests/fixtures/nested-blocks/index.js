@@ -0,0 +1,7 @@
+require.ensure(
+	["./a"],
+	function () {
+		import(/* webpackChunkName: "nested" */ "./b");
+	},
+	"ensure"
+);
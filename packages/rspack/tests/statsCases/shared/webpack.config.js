@@ -0,0 +1,23 @@
+// eslint-disable-next-line node/no-unpublished-require
+const { SharePlugin } = require("../../../").sharing;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	optimization: {
+		chunkIds: "named"
+	},
+	plugins: [
+		new SharePlugin({
+			shared: {
+				lib: {},
+				"unversioned-lib": {
+					version: false
+				}
+			}
+		})
+	],
+	stats: {
+		all: false,
+		shared: true
+	}
+};
@@ -0,0 +1,28 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should minify the changed chunks and reuse the unchanged ones", function () {
+	expect(require("./changing-module")).toBe(WATCH_STEP);
+	return import(/* webpackChunkName: "unchanged" */ "./unchanged").then(
+		({ default: value }) => {
+			expect(value).toBe("unchanged");
+		}
+	);
+});
+
+it("should restore the unchanged chunks from the minify cache", function () {
+	const stats = fs.readFileSync(
+		path.join(__dirname, `stats.${WATCH_STEP}.txt`),
+		"utf-8"
+	);
+	const [, cached, minified] = stats.match(
+		/(\d+) of (\d+) assets are restored from the minify cache/
+	);
+	expect(Number(minified)).toBeGreaterThan(1);
+	if (WATCH_STEP === "0") {
+		expect(Number(cached)).toBe(0);
+	} else {
+		// only the chunk of the changing module is minified again
+		expect(Number(cached)).toBe(Number(minified) - 1);
+	}
+});
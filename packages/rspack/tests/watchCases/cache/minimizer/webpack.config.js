@@ -0,0 +1,8 @@
+/** @type {import("../../../../dist").Configuration} */
+module.exports = {
+	mode: "production",
+	cache: true,
+	output: {
+		chunkFilename: "[name].js"
+	}
+};
@@ -0,0 +1,5 @@
+import value from "./value";
+
+it("should resolve the file created next to the directory", function () {
+	expect(value).toBe(WATCH_STEP === "0" ? "directory" : "file");
+});